@@ -9,8 +9,8 @@ macro_rules! setter {
             paste::paste! {
                 #[allow(clippy::wrong_self_convention)]
                 $(#[$meta $($args)*])*
-                pub fn [<$field>](mut self, x:$t) -> Self {
-                    self.args.$field = Some(x);
+                pub fn [<$field>](mut self, x: impl Into<$t>) -> Self {
+                    self.args.$field = Some(x.into());
                     self
                 }
             }
@@ -26,6 +26,35 @@ macro_rules! setter {
     };
 }
 
+/// Lets a builder be awaited directly by running its terminal method
+macro_rules! into_future {
+  ($t:ty, $f:ident, $o:ty) => {
+    // Builders can be stored, cloned and moved to spawned tasks
+    const _: fn() = || {
+      fn assert_builder<T: Send + Clone + 'static>() {}
+      assert_builder::<$t>();
+    };
+
+    impl std::future::IntoFuture for $t {
+      type IntoFuture = futures::future::BoxFuture<'static, Self::Output>;
+      type Output = $o;
+
+      #[track_caller]
+      fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.$f())
+      }
+    }
+  };
+}
+
+/// Runs `$body` as the public API method `$name` so that the requests it sends
+/// carry the method name and the caller's location
+macro_rules! api_call {
+  ($name:expr, $body:block) => {
+    crate::imp::core::ApiCall::new($name).scope(async move { $body })
+  };
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! subscribe_event {
@@ -36,7 +65,7 @@ macro_rules! subscribe_event {
     ) -> Result<
       impl futures::stream::Stream<
         Item = Result<Event, tokio_stream::wrappers::errors::BroadcastStreamRecvError>,
-      >,
+      > + Send,
       Error,
     > {
       use futures::stream::StreamExt;
@@ -44,6 +73,60 @@ macro_rules! subscribe_event {
       let stream = BroadcastStream::new(upgrade(&self.inner)?.subscribe_event());
       Ok(stream.map(|e| e.map(Event::from)))
     }
+
+    /// Sets the capacity of the channel behind `subscribe_event` of this
+    /// object. Streams that fall further behind than this receive `Lagged`
+    /// errors. The channel is created by the first subscription, so this has
+    /// to be called before it. Defaults to 64.
+    pub fn set_event_channel_capacity(&self, capacity: usize) -> Result<(), Error> {
+      upgrade(&self.inner)?.listeners().set_capacity(capacity);
+      Ok(())
+    }
+  };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! event_handlers {
+  () => {
+    /// Calls `handler` for every event of type `event` until it is removed
+    /// with `off`. Handlers are awaited one at a time in the order they were
+    /// registered and are never lagged, unlike `subscribe_event`.
+    ///
+    /// Handlers run on the current runtime, or on the one the connection was
+    /// opened in when called from blocking code.
+    pub fn on<F, Fut>(&self, event: EventType, handler: F) -> Result<$crate::api::ListenerId, Error>
+    where
+      F: Fn(Event) -> Fut + Send + Sync + 'static,
+      Fut: std::future::Future<Output = ()> + Send + 'static,
+    {
+      let handler = $crate::imp::core::handler(handler);
+      let inner = upgrade(&self.inner)?;
+      let rt = inner.runtime()?;
+      Ok(inner.listeners().add(&rt, event, false, handler))
+    }
+
+    /// Calls `handler` for the next event of type `event` only
+    pub fn once<F, Fut>(
+      &self,
+      event: EventType,
+      handler: F,
+    ) -> Result<$crate::api::ListenerId, Error>
+    where
+      F: FnOnce(Event) -> Fut + Send + 'static,
+      Fut: std::future::Future<Output = ()> + Send + 'static,
+    {
+      let handler = $crate::imp::core::once_handler(handler);
+      let inner = upgrade(&self.inner)?;
+      let rt = inner.runtime()?;
+      Ok(inner.listeners().add(&rt, event, true, handler))
+    }
+
+    /// Removes a handler registered with `on` or `once`
+    pub fn off(&self, id: $crate::api::ListenerId) -> Result<(), Error> {
+      upgrade(&self.inner)?.listeners().remove(id);
+      Ok(())
+    }
   };
 }
 
@@ -57,6 +140,8 @@ pub mod browser_type;
 pub mod console_message;
 pub mod dialog;
 pub mod download;
+pub mod electron;
+pub mod electron_application;
 pub mod element_handle;
 pub mod file_chooser;
 pub mod frame;
@@ -77,12 +162,16 @@ pub use browser_type::BrowserType;
 pub use console_message::ConsoleMessage;
 pub use dialog::Dialog;
 pub use download::Download;
+pub use download::ReadStream;
+pub use electron::Electron;
+pub use electron_application::ElectronApplication;
 pub use element_handle::ElementHandle;
 pub use file_chooser::FileChooser;
 pub use frame::Frame;
 pub use input_device::Keyboard;
 pub use input_device::Mouse;
 pub use input_device::TouchScreen;
+pub use js_handle::with_handles;
 pub use js_handle::JsHandle;
 pub use page::Page;
 pub use request::Request;
@@ -94,7 +183,12 @@ pub use websocket::WebSocket;
 pub use worker::Worker;
 
 pub use self::playwright::Playwright;
+pub use crate::imp::core::js_serde;
 pub use crate::imp::core::DateTime;
+pub use crate::imp::core::JsValue;
+pub use crate::imp::core::ListenerId;
+pub use crate::imp::trace_summary::LongTask;
+pub use crate::imp::trace_summary::TraceSummary;
 pub use crate::imp::utils::*;
 
 // Artifact
@@ -90,9 +90,12 @@ impl SnapshotBuilder {
     Self { inner, args }
   }
 
-  pub async fn snapshot(self) -> ArcResult<Option<SnapshotResponse>> {
-    let Self { inner, args } = self;
-    upgrade(&inner)?.accessibility_snapshot(args).await
+  #[track_caller]
+  pub fn snapshot(self) -> impl Future<Output = ArcResult<Option<SnapshotResponse>>> {
+    api_call!("accessibility.snapshot", {
+      let Self { inner, args } = self;
+      upgrade(&inner)?.accessibility_snapshot(args).await
+    })
   }
 
   /// The root DOM element for the snapshot. Defaults to the whole page.
@@ -1,5 +1,9 @@
 use crate::api::BrowserContext;
+use crate::api::Page;
+pub use crate::imp::browser::EventType;
+use crate::imp::browser::Evt;
 use crate::imp::browser::NewContextArgs;
+use crate::imp::browser::StartTracingArgs;
 pub use crate::imp::browser_type::RecordHar;
 pub use crate::imp::browser_type::RecordVideo;
 use crate::imp::core::*;
@@ -14,7 +18,7 @@ use crate::imp::utils::Viewport;
 use crate::imp::{self};
 use crate::Error;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Browser {
   inner: Weak<imp::browser::Browser>,
 }
@@ -30,6 +34,10 @@ impl PartialEq for Browser {
 }
 
 impl Browser {
+  subscribe_event! {}
+
+  event_handlers! {}
+
   pub(crate) fn new(inner: Weak<imp::browser::Browser>) -> Self {
     Self { inner }
   }
@@ -67,34 +75,99 @@ impl Browser {
   /// new_context [`BrowserContext`]
   /// Creates a new browser context. It won't share cookies/cache with other
   /// browser contexts.
-  pub fn context_builder(&self) -> ContextBuilder<'_, '_, '_, '_, '_, '_, '_> {
+  pub fn context_builder(&self) -> ContextBuilder {
     ContextBuilder::new(self.inner.clone())
   }
 
   /// All temporary browsers will be closed when the connection is terminated,
   /// but it needs to be called explicitly to close it at any given time.
-  pub async fn close(&self) -> Result<(), Arc<Error>> {
-    let inner = match self.inner.upgrade() {
-      None => return Ok(()),
-      Some(inner) => inner,
-    };
-    inner.close().await
+  #[track_caller]
+  pub fn close<'a>(&'a self) -> impl Future<Output = Result<(), Arc<Error>>> + 'a {
+    api_call!("browser.close", {
+      let inner = match self.inner.upgrade() {
+        None => return Ok(()),
+        Some(inner) => inner,
+      };
+      inner.close().await
+    })
+  }
+
+  /// > NOTE: This API controls [Chromium Tracing](https://www.chromium.org/developers/how-tos/trace-event-profiling-tool)
+  /// which is a low-level chromium-specific debugging tool.
+  ///
+  /// Only one trace can be active at a time per browser. `page` restricts the
+  /// trace to that page, `screenshots` captures screenshots in the trace and
+  /// `categories` replaces the default trace categories.
+  ///
+  /// ```js
+  /// await browser.startTracing(page, {path: 'trace.json'});
+  /// await page.goto('https://www.google.com');
+  /// await browser.stopTracing();
+  /// ```
+  #[track_caller]
+  pub fn start_tracing<'a>(
+    &'a self,
+    page: Option<&'a Page>,
+    screenshots: Option<bool>,
+    categories: Option<Vec<String>>,
+  ) -> impl Future<Output = ArcResult<()>> + 'a {
+    api_call!("browser.start_tracing", {
+      let page = match page {
+        Some(p) => {
+          Some(OnlyGuid {
+            guid: upgrade(&p.inner())?.guid().to_owned(),
+          })
+        }
+        None => None,
+      };
+      let args = StartTracingArgs {
+        page,
+        screenshots,
+        categories,
+      };
+      upgrade(&self.inner)?.start_tracing(args).await
+    })
+  }
+
+  /// Returns the trace JSON, which
+  /// [`TraceSummary::parse`](crate::api::TraceSummary::parse) can summarize.
+  #[track_caller]
+  pub fn stop_tracing<'a>(&'a self) -> impl Future<Output = ArcResult<Vec<u8>>> + 'a {
+    api_call!("browser.stop_tracing", {
+      upgrade(&self.inner)?.stop_tracing().await
+    })
   }
 
   // new_browser_cdp_session
-  // start_tracing
-  // stop_tracing
+}
+
+#[derive(Debug, Clone)]
+pub enum Event {
+  /// Emitted when Browser gets disconnected from the browser application. This
+  /// might happen because of one of the following:
+  /// - Browser application is closed or crashed.
+  /// - The [`method: Browser.close`] method was called.
+  Disconnected,
+}
+
+impl From<Evt> for Event {
+  fn from(e: Evt) -> Self {
+    match e {
+      Evt::Disconnected => Self::Disconnected,
+    }
+  }
 }
 
 // TODO: async drop
 
 /// [`Browser::context_builder`]
-pub struct ContextBuilder<'e, 'f, 'g, 'h, 'i, 'j, 'k> {
+#[derive(Clone)]
+pub struct ContextBuilder {
   inner: Weak<imp::browser::Browser>,
-  args: NewContextArgs<'e, 'f, 'g, 'h, 'i, 'j, 'k>,
+  args: NewContextArgs,
 }
 
-impl<'e, 'f, 'g, 'h, 'i, 'j, 'k> ContextBuilder<'e, 'f, 'g, 'h, 'i, 'j, 'k> {
+impl ContextBuilder {
   setter! {
       /// Whether to automatically download all the attachments. Defaults to `false` where all the downloads are canceled.
       accept_downloads: Option<bool>,
@@ -110,7 +183,7 @@ impl<'e, 'f, 'g, 'h, 'i, 'j, 'k> ContextBuilder<'e, 'f, 'g, 'h, 'i, 'j, 'k> {
       geolocation: Option<Geolocation>,
       has_touch: Option<bool>,
       /// Credentials for [HTTP authentication](https://developer.mozilla.org/en-US/docs/Web/HTTP/Authentication).
-      http_credentials: Option<&'i HttpCredentials>,
+      http_credentials: Option<HttpCredentials>,
       /// Whether to ignore HTTPS errors during navigation. Defaults to `false`.
       ignore_https_errors: Option<bool>,
       /// Whether the `meta viewport` tag is taken into account and touch events are enabled. Defaults to `false`. Not supported
@@ -120,23 +193,26 @@ impl<'e, 'f, 'g, 'h, 'i, 'j, 'k> ContextBuilder<'e, 'f, 'g, 'h, 'i, 'j, 'k> {
       js_enabled: Option<bool>,
       /// Specify user locale, for example `en-GB`, `de-DE`, etc. Locale will affect `navigator.language` value, `Accept-Language`
       /// request header value as well as number and date formatting rules.
-      locale: Option<&'f str>,
+      locale: Option<String>,
       /// Does not enforce fixed viewport, allows resizing window in the headed mode.
       no_viewport: Option<bool>,
       /// Whether to emulate network being offline. Defaults to `false`.
       offline: Option<bool>,
       /// A list of permissions to grant to all pages in this context. See [BrowserContext::grant_permissions] for more details.
-      permissions: Option<&'h [String]>,
+      permissions: Option<Vec<String>>,
       /// Network proxy settings to use with this context. Note that browser needs to be launched with the global proxy for this
       /// option to work. If all contexts override the proxy, global proxy will be never used and can be any string, for example
       /// `launch({ proxy: { server: 'per-context' } })`.
       proxy: Option<ProxySettings>,
       /// Enables [HAR](http://www.softwareishard.com/blog/har-12-spec) recording for all pages into `recordHar.path` file. If not
       /// specified, the HAR is not recorded. Make sure to await [`method: BrowserContext.close`] for the HAR to be saved.
-      record_har: Option<RecordHar<'k>>,
+      record_har: Option<RecordHar>,
       /// Enables video recording for all pages into `recordVideo.dir` directory. If not specified videos are not recorded. Make
       /// sure to await [`method: BrowserContext.close`] for videos to be saved.
-      record_video: Option<RecordVideo<'j>>,
+      record_video: Option<RecordVideo>,
+      /// Keeps the recorded videos only of pages flagged with [`Page::keep_video`](crate::api::Page::keep_video). The others
+      /// are deleted as soon as their page closes.
+      keep_flagged_videos_only: Option<bool>,
       /// Emulates consistent window screen size available inside web page via `window.screen`. Is only used when the `viewport`
       /// is set.
       screen: Option<Viewport>,
@@ -144,20 +220,27 @@ impl<'e, 'f, 'g, 'h, 'i, 'j, 'k> ContextBuilder<'e, 'f, 'g, 'h, 'i, 'j, 'k> {
       /// obtained via [`method: BrowserContext.storageState`]. Either a path to the file with saved storage, or an object with
       /// the following fields:
       storage_state: Option<StorageState>,
+      /// Populates context with the storage state saved to this file by
+      /// [`BrowserContext::storage_state_to_path`](crate::api::BrowserContext::storage_state_to_path)
+      /// or `storageState({ path })` of the other Playwright languages. Takes priority over `storage_state`.
+      storage_state_path: Option<PathBuf>,
       /// Changes the timezone of the context. See
       /// [ICU's metaZones.txt](https://cs.chromium.org/chromium/src/third_party/icu/source/data/misc/metaZones.txt?rcl=faee8bc70570192d82d2978a71e2a615788597d1)
       /// for a list of supported timezone IDs.
-      timezone_id: Option<&'g str>,
+      timezone_id: Option<String>,
       /// Specific user agent to use in this context.
-      user_agent: Option<&'e str>,
+      user_agent: Option<String>,
       /// Emulates consistent viewport for each page. Defaults to an 1280x720 viewport. `null` disables the default viewport.
       viewport: Option<Option<Viewport>>
   }
 
-  pub async fn build(self) -> Result<BrowserContext, Arc<Error>> {
-    let Self { inner, args } = self;
-    let r = upgrade(&inner)?.new_context(args).await?;
-    Ok(BrowserContext::new(r))
+  #[track_caller]
+  pub fn build(self) -> impl Future<Output = Result<BrowserContext, Arc<Error>>> {
+    api_call!("browser.new_context", {
+      let Self { inner, args } = self;
+      let r = upgrade(&inner)?.new_context(args).await?;
+      Ok(BrowserContext::new(r))
+    })
   }
 
   fn new(inner: Weak<imp::browser::Browser>) -> Self {
@@ -167,9 +250,11 @@ impl<'e, 'f, 'g, 'h, 'i, 'j, 'k> ContextBuilder<'e, 'f, 'g, 'h, 'i, 'j, 'k> {
     }
   }
 
-  pub fn set_device(self, device: &'e DeviceDescriptor) -> Self {
+  pub fn set_device(self, device: &DeviceDescriptor) -> Self {
     DeviceDescriptor::set_context(device, self)
   }
   ///// Logger sink for Playwright logging.
   // logger: Option<Logger>,
 }
+
+into_future!(ContextBuilder, build, Result<BrowserContext, Arc<Error>>);
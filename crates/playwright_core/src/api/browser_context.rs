@@ -1,5 +1,6 @@
 use crate::api::Browser;
 use crate::api::Page;
+use crate::api::Worker;
 use crate::imp::browser_context::BrowserContext as Impl;
 pub use crate::imp::browser_context::EventType;
 use crate::imp::browser_context::Evt;
@@ -7,6 +8,7 @@ use crate::imp::core::*;
 use crate::imp::prelude::*;
 use crate::imp::utils::Cookie;
 use crate::imp::utils::Geolocation;
+use crate::imp::utils::JsError;
 use crate::imp::utils::StorageState;
 use crate::Error;
 
@@ -19,7 +21,7 @@ use crate::Error;
 /// Playwright allows creation of "incognito" browser contexts with
 /// `browser.newContext()` method. "Incognito" browser contexts don't write any
 /// browsing data to disk.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct BrowserContext {
   inner: Weak<Impl>,
 }
@@ -37,6 +39,8 @@ impl PartialEq for BrowserContext {
 impl BrowserContext {
   subscribe_event! {}
 
+  event_handlers! {}
+
   pub(crate) fn new(inner: Weak<Impl>) -> Self {
     Self { inner }
   }
@@ -53,6 +57,34 @@ impl BrowserContext {
     )
   }
 
+  /// > NOTE: Background pages are only supported on Chromium-based browsers.
+  ///
+  /// All existing background pages in the context.
+  pub fn background_pages(&self) -> Result<Vec<Page>, Error> {
+    Ok(
+      upgrade(&self.inner)?
+        .background_pages()
+        .iter()
+        .cloned()
+        .map(Page::new)
+        .collect(),
+    )
+  }
+
+  /// > NOTE: Service workers are only supported on Chromium-based browsers.
+  ///
+  /// All existing service workers in the context.
+  pub fn service_workers(&self) -> Result<Vec<Worker>, Error> {
+    Ok(
+      upgrade(&self.inner)?
+        .service_workers()
+        .iter()
+        .cloned()
+        .map(Worker::new)
+        .collect(),
+    )
+  }
+
   /// Returns the browser instance of the context. If it was launched as a
   /// persistent context None gets returned.
   pub fn browser(&self) -> Result<Option<Browser>, Error> {
@@ -60,36 +92,66 @@ impl BrowserContext {
   }
 
   /// Creates a new page in the browser context.
-  pub async fn new_page(&self) -> Result<Page, Arc<Error>> {
-    let inner = upgrade(&self.inner)?;
-    Ok(Page::new(inner.new_page().await?))
+  #[track_caller]
+  pub fn new_page<'a>(&'a self) -> impl Future<Output = Result<Page, Arc<Error>>> + 'a {
+    api_call!("browser_context.new_page", {
+      let inner = upgrade(&self.inner)?;
+      Ok(Page::new(inner.new_page().await?))
+    })
   }
 
-  pub async fn set_default_navigation_timeout(&self, timeout: u32) -> ArcResult<()> {
-    upgrade(&self.inner)?
-      .set_default_navigation_timeout(timeout)
-      .await
+  #[track_caller]
+  pub fn set_default_navigation_timeout<'a>(
+    &'a self,
+    timeout: u32,
+  ) -> impl Future<Output = ArcResult<()>> + 'a {
+    api_call!("browser_context.set_default_navigation_timeout", {
+      upgrade(&self.inner)?
+        .set_default_navigation_timeout(timeout)
+        .await
+    })
   }
 
-  pub async fn set_default_timeout(&self, timeout: u32) -> ArcResult<()> {
-    upgrade(&self.inner)?.set_default_timeout(timeout).await
+  #[track_caller]
+  pub fn set_default_timeout<'a>(
+    &'a self,
+    timeout: u32,
+  ) -> impl Future<Output = ArcResult<()>> + 'a {
+    api_call!("browser_context.set_default_timeout", {
+      upgrade(&self.inner)?.set_default_timeout(timeout).await
+    })
   }
 
   /// If no URLs are specified, this method returns all cookies. If URLs are
   /// specified, only cookies that affect those URLs are returned.
-  pub async fn cookies(&self, urls: &[String]) -> ArcResult<Vec<Cookie>> {
-    upgrade(&self.inner)?.cookies(urls).await
+  #[track_caller]
+  pub fn cookies<'a>(
+    &'a self,
+    urls: &'a [String],
+  ) -> impl Future<Output = ArcResult<Vec<Cookie>>> + 'a {
+    api_call!("browser_context.cookies", {
+      upgrade(&self.inner)?.cookies(urls).await
+    })
   }
 
   /// Adds cookies into this browser context. All pages within this context will
   /// have these cookies installed.
-  pub async fn add_cookies(&self, cookies: &[Cookie]) -> ArcResult<()> {
-    upgrade(&self.inner)?.add_cookies(cookies).await
+  #[track_caller]
+  pub fn add_cookies<'a>(
+    &'a self,
+    cookies: &'a [Cookie],
+  ) -> impl Future<Output = ArcResult<()>> + 'a {
+    api_call!("browser_context.add_cookies", {
+      upgrade(&self.inner)?.add_cookies(cookies).await
+    })
   }
 
   /// Clears context cookies.
-  pub async fn clear_cookies(&self) -> ArcResult<()> {
-    upgrade(&self.inner)?.clear_cookies().await
+  #[track_caller]
+  pub fn clear_cookies<'a>(&'a self) -> impl Future<Output = ArcResult<()>> + 'a {
+    api_call!("browser_context.clear_cookies", {
+      upgrade(&self.inner)?.clear_cookies().await
+    })
   }
 
   /// Grants specified permissions to the browser context. Only grants
@@ -122,19 +184,25 @@ impl BrowserContext {
   /// - `'payment-handler'`
   /// ## origin
   /// The origin to grant permissions to, e.g. `"https://example.com"`.
-  pub async fn grant_permissions(
-    &self,
-    permissions: &[String],
-    origin: Option<&str>,
-  ) -> ArcResult<()> {
-    upgrade(&self.inner)?
-      .grant_permissions(permissions, origin)
-      .await
+  #[track_caller]
+  pub fn grant_permissions<'a>(
+    &'a self,
+    permissions: &'a [String],
+    origin: Option<&'a str>,
+  ) -> impl Future<Output = ArcResult<()>> + 'a {
+    api_call!("browser_context.grant_permissions", {
+      upgrade(&self.inner)?
+        .grant_permissions(permissions, origin)
+        .await
+    })
   }
 
   /// Clears all permission overrides for the browser context.
-  pub async fn clear_permissions(&self) -> ArcResult<()> {
-    upgrade(&self.inner)?.clear_permissions().await
+  #[track_caller]
+  pub fn clear_permissions<'a>(&'a self) -> impl Future<Output = ArcResult<()>> + 'a {
+    api_call!("browser_context.clear_permissions", {
+      upgrade(&self.inner)?.clear_permissions().await
+    })
   }
 
   /// Sets the context's geolocation. Passing `null` or `undefined` emulates
@@ -145,13 +213,22 @@ impl BrowserContext {
   /// ```
   /// > NOTE: Consider using [`method: BrowserContext.grantPermissions`] to
   /// grant permissions for the browser context pages to read its geolocation.
-  pub async fn set_geolocation(&self, geolocation: Option<&Geolocation>) -> ArcResult<()> {
-    upgrade(&self.inner)?.set_geolocation(geolocation).await
+  #[track_caller]
+  pub fn set_geolocation<'a>(
+    &'a self,
+    geolocation: Option<&'a Geolocation>,
+  ) -> impl Future<Output = ArcResult<()>> + 'a {
+    api_call!("browser_context.set_geolocation", {
+      upgrade(&self.inner)?.set_geolocation(geolocation).await
+    })
   }
 
   /// Sets whether to emulate network being offline for the browser context.
-  pub async fn set_offline(&self, offline: bool) -> ArcResult<()> {
-    upgrade(&self.inner)?.set_offline(offline).await
+  #[track_caller]
+  pub fn set_offline<'a>(&'a self, offline: bool) -> impl Future<Output = ArcResult<()>> + 'a {
+    api_call!("browser_context.set_offline", {
+      upgrade(&self.inner)?.set_offline(offline).await
+    })
   }
 
   /// Adds a script which would be evaluated in one of the following scenarios:
@@ -178,9 +255,15 @@ impl BrowserContext {
   /// ```
   /// > NOTE: The order of evaluation of multiple scripts installed via [`method: BrowserContext.addInitScript`] and
   /// [`method: Page.addInitScript`] is not defined.
-  pub async fn add_init_script(&self, script: &str) -> ArcResult<()> {
-    // arg not supported
-    upgrade(&self.inner)?.add_init_script(script).await
+  #[track_caller]
+  pub fn add_init_script<'a>(
+    &'a self,
+    script: &'a str,
+  ) -> impl Future<Output = ArcResult<()>> + 'a {
+    api_call!("browser_context.add_init_script", {
+      // arg not supported
+      upgrade(&self.inner)?.add_init_script(script).await
+    })
   }
 
   /// The extra HTTP headers will be sent with every request initiated by any
@@ -191,11 +274,18 @@ impl BrowserContext {
   ///
   /// > NOTE: [`method: BrowserContext.setExtraHTTPHeaders`] does not guarantee
   /// the order of headers in the outgoing requests.
-  pub async fn set_extra_http_headers<T>(&self, headers: T) -> ArcResult<()>
+  #[track_caller]
+  pub fn set_extra_http_headers<'a, T>(
+    &'a self,
+    headers: T,
+  ) -> impl Future<Output = ArcResult<()>> + 'a
   where
     T: IntoIterator<Item = (String, String)>,
+    T: 'a,
   {
-    upgrade(&self.inner)?.set_extra_http_headers(headers).await
+    api_call!("browser_context.set_extra_http_headers", {
+      upgrade(&self.inner)?.set_extra_http_headers(headers).await
+    })
   }
 
   // async fn expose_binding(&mut self) -> Result<(), Error> { unimplemented!() }
@@ -206,40 +296,70 @@ impl BrowserContext {
 
   // async fn unroute(&mut self) -> Result<(), Error> { unimplemented!() }
 
-  pub async fn expect_event(&self, evt: EventType) -> Result<Event, Error> {
-    let stream = upgrade(&self.inner)?.subscribe_event();
-    let timeout = upgrade(&self.inner)?.default_timeout();
-    expect_event(stream, evt, timeout).await.map(Event::from)
+  #[track_caller]
+  pub fn expect_event<'a>(
+    &'a self,
+    evt: EventType,
+  ) -> impl Future<Output = Result<Event, Error>> + 'a {
+    api_call!("browser_context.expect_event", {
+      let stream = upgrade(&self.inner)?.subscribe_event();
+      let timeout = upgrade(&self.inner)?.default_timeout();
+      expect_event(stream, evt, timeout).await.map(Event::from)
+    })
+  }
+
+  /// Pauses until resumed from the inspector. See [`Page::pause`].
+  #[track_caller]
+  pub fn pause<'a>(&'a self) -> impl Future<Output = ArcResult<()>> + 'a {
+    api_call!("browser_context.pause", {
+      upgrade(&self.inner)?.pause().await
+    })
   }
 
   /// Returns storage state for this browser context, contains current cookies
   /// and local storage snapshot.
-  pub async fn storage_state(&self) -> ArcResult<StorageState> {
-    // path no supported
-    upgrade(&self.inner)?.storage_state().await
+  #[track_caller]
+  pub fn storage_state<'a>(&'a self) -> impl Future<Output = ArcResult<StorageState>> + 'a {
+    api_call!("browser_context.storage_state", {
+      upgrade(&self.inner)?.storage_state().await
+    })
+  }
+
+  /// Same as [`BrowserContext::storage_state`] and also saves it to `path` as
+  /// JSON, which
+  /// [`ContextBuilder::storage_state_path`](crate::api::browser::ContextBuilder::storage_state_path)
+  /// and the other Playwright languages can load. If `path` is a relative
+  /// path, then it is resolved relative to the current working directory.
+  #[track_caller]
+  pub fn storage_state_to_path<'a>(
+    &'a self,
+    path: &'a Path,
+  ) -> impl Future<Output = ArcResult<StorageState>> + 'a {
+    api_call!("browser_context.storage_state_to_path", {
+      upgrade(&self.inner)?.storage_state_to_path(path).await
+    })
   }
 
   /// All temporary browsers will be closed when the connection is terminated,
   /// but this struct has no Drop. it needs to be called explicitly to close
   /// it at any given time. > NOTE: The default browser context cannot be
   /// closed.
-  pub async fn close(&self) -> ArcResult<()> {
-    let inner = match self.inner.upgrade() {
-      None => return Ok(()),
-      Some(inner) => inner,
-    };
-    inner.close().await
+  #[track_caller]
+  pub fn close<'a>(&'a self) -> impl Future<Output = ArcResult<()>> + 'a {
+    api_call!("browser_context.close", {
+      let inner = match self.inner.upgrade() {
+        None => return Ok(()),
+        Some(inner) => inner,
+      };
+      inner.close().await
+    })
   }
 
-  // background_page for chromium
   // new_cdp_session
-  // service_workers
 }
 
 #[derive(Debug, PartialEq)]
 pub enum Event {
-  // BackgroundPage for chromium persistent
-  // ServiceWorker
   /// Emitted when Browser context gets closed. This might happen because of one
   /// of the following:
   /// - Browser context is closed.
@@ -263,6 +383,23 @@ pub enum Event {
   /// console.log(await newPage.evaluate('location.href'));
   /// ```
   Page(Page),
+  /// Emitted when exception is unhandled in any of the pages in this context.
+  /// To listen for errors from a particular page, use [`event: Page.pageError`]
+  /// instead.
+  WebError(WebError),
+  /// > NOTE: Only works with Chromium browser's persistent context.
+  ///
+  /// Emitted when new background page is created in the context.
+  ///
+  /// ```js
+  /// const backgroundPage = await context.waitForEvent('backgroundpage');
+  /// ```
+  BackgroundPage(Page),
+  /// > NOTE: Service workers are only supported on Chromium-based browsers.
+  ///
+  /// Emitted when new service worker is created in the context. The worker
+  /// emits `close` when it is gone.
+  ServiceWorker(Worker),
 }
 
 impl From<Evt> for Event {
@@ -270,6 +407,32 @@ impl From<Evt> for Event {
     match e {
       Evt::Close => Event::Close,
       Evt::Page(w) => Event::Page(Page::new(w)),
+      Evt::WebError(page, error) => {
+        Event::WebError(WebError {
+          page: Page::new(page),
+          error,
+        })
+      }
+      Evt::BackgroundPage(w) => Event::BackgroundPage(Page::new(w)),
+      Evt::ServiceWorker(w) => Event::ServiceWorker(Worker::new(w)),
     }
   }
 }
+
+/// An unhandled exception thrown in a page of the context
+#[derive(Debug, PartialEq)]
+pub struct WebError {
+  page: Page,
+  error: JsError,
+}
+
+impl WebError {
+  /// The page that produced this unhandled exception
+  pub fn page(&self) -> &Page {
+    &self.page
+  }
+
+  pub fn error(&self) -> &JsError {
+    &self.error
+  }
+}
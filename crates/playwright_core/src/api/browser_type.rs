@@ -2,6 +2,7 @@ use crate::api::browser::Browser;
 use crate::api::browser_context::BrowserContext;
 use crate::api::playwright::DeviceDescriptor;
 use crate::imp::browser_type::BrowserType as Impl;
+use crate::imp::browser_type::ConnectArgs;
 use crate::imp::browser_type::ConnectOverCdpArgs;
 use crate::imp::browser_type::LaunchArgs;
 use crate::imp::browser_type::LaunchPersistentContextArgs;
@@ -68,7 +69,7 @@ impl BrowserType {
   /// differences between Chromium and Chrome.
   /// [This article](https://chromium.googlesource.com/chromium/src/+/lkgr/docs/chromium_browser_vs_google_chrome.md)
   /// describes some differences for Linux users.
-  pub fn launcher(&self) -> Launcher<'_, '_, '_> {
+  pub fn launcher(&self) -> Launcher {
     Launcher::new(self.inner.clone())
   }
 
@@ -82,10 +83,7 @@ impl BrowserType {
   /// [Firefox](https://developer.mozilla.org/en-US/docs/Mozilla/Command_Line_Options#User_Profile). Note that Chromium's user
   /// data directory is the **parent** directory of the "Profile Path" seen at
   /// `chrome://version`.
-  pub fn persistent_context_launcher<'a>(
-    &self,
-    user_data_dir: &'a Path,
-  ) -> PersistentContextLauncher<'a, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_> {
+  pub fn persistent_context_launcher(&self, user_data_dir: &Path) -> PersistentContextLauncher {
     PersistentContextLauncher::new(self.inner.clone(), user_data_dir)
   }
 
@@ -99,29 +97,38 @@ impl BrowserType {
   /// Chromium-based browsers. A CDP websocket endpoint or http url to connect to. For example `http://localhost:9222/` or
   /// `ws://127.0.0.1:9222/devtools/browser/
   /// 387adf4c-243f-4051-a181-46798f4a46f4`.
-  pub fn connect_over_cdp_builder<'a>(&self, endpoint_url: &'a str) -> ConnectOverCdpBuilder<'a> {
+  pub fn connect_over_cdp_builder(&self, endpoint_url: &str) -> ConnectOverCdpBuilder {
     ConnectOverCdpBuilder::new(self.inner.clone(), endpoint_url)
   }
 
-  // connect
+  /// This methods attaches Playwright to an existing browser instance launched
+  /// by `browserType.launchServer` of a playwright server.
+  ///
+  /// A browser websocket endpoint to connect to, for example
+  /// `ws://127.0.0.1:3000/1b3a4c2e`.
+  pub fn connect_builder(&self, ws_endpoint: &str) -> ConnectBuilder {
+    ConnectBuilder::new(self.inner.clone(), ws_endpoint)
+  }
+
   // launch_server
 }
 
 /// [`BrowserType::launcher`]
-pub struct Launcher<'a, 'b, 'c> {
+#[derive(Clone)]
+pub struct Launcher {
   inner: Weak<Impl>,
-  args: LaunchArgs<'a, 'b, 'c>,
+  args: LaunchArgs,
 }
 
-impl<'a, 'b, 'c> Launcher<'a, 'b, 'c> {
+impl Launcher {
   setter! {
       /// Path to a browser executable to run instead of the bundled one. If `executablePath` is a relative path, then it is
       /// resolved relative to the current working directory. Note that Playwright only works with the bundled Chromium, Firefox
       /// or WebKit, use at your own risk.
-      executable: Option<&'a Path>,
+      executable: Option<PathBuf>,
       /// Additional arguments to pass to the browser instance. The list of Chromium flags can be found
       /// [here](http://peter.sh/experiments/chromium-command-line-switches/).
-      args: Option<&'b [String]>,
+      args: Option<Vec<String>>,
       /// If `true`, Playwright does not pass its own configurations args and only uses the ones from `args`. Dangerous option;
       /// use with care. Defaults to `false`.
       ignore_all_default_args: Option<bool>,
@@ -141,7 +148,7 @@ impl<'a, 'b, 'c> Launcher<'a, 'b, 'c> {
       proxy: Option<ProxySettings>,
       /// If specified, accepted downloads are downloaded into this directory. Otherwise, temporary directory is created and is
       /// deleted when browser is closed.
-      downloads: Option<&'c Path>,
+      downloads: Option<PathBuf>,
       /// Slows down Playwright operations by the specified amount of milliseconds. Useful so that you can see what is going on.
       slowmo: Option<f64>,
       /// Specify environment variables that will be visible to the browser. Defaults to `process.env`.
@@ -159,10 +166,17 @@ impl<'a, 'b, 'c> Launcher<'a, 'b, 'c> {
       channel: Option<BrowserChannel>
   }
 
-  pub async fn launch(self) -> Result<Browser, Arc<Error>> {
-    let Self { inner, args } = self;
-    let r = upgrade(&inner)?.launch(args).await?;
-    Ok(Browser::new(r))
+  /// When the `PWDEBUG` environment variable is set to other than `0`, the
+  /// browser is launched headed and slowed down by 100 milliseconds unless
+  /// `slowmo` is given. With `PWDEBUG=1` the driver also opens the inspector
+  /// and pauses before each action.
+  #[track_caller]
+  pub fn launch(self) -> impl Future<Output = Result<Browser, Arc<Error>>> {
+    api_call!("browser_type.launch", {
+      let Self { inner, args } = self;
+      let r = upgrade(&inner)?.launch(args).await?;
+      Ok(Browser::new(r))
+    })
   }
 
   fn new(inner: Weak<Impl>) -> Self {
@@ -180,25 +194,26 @@ impl<'a, 'b, 'c> Launcher<'a, 'b, 'c> {
   // logger: Option<Logger>,
 }
 
+into_future!(Launcher, launch, Result<Browser, Arc<Error>>);
+
 /// [`BrowserType::persistent_context_launcher`]
 ///
 /// Has launch args and context args
-pub struct PersistentContextLauncher<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k> {
+#[derive(Clone)]
+pub struct PersistentContextLauncher {
   inner: Weak<Impl>,
-  args: LaunchPersistentContextArgs<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k>,
+  args: LaunchPersistentContextArgs,
 }
 
-impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k>
-  PersistentContextLauncher<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k>
-{
+impl PersistentContextLauncher {
   setter! {
       /// Path to a browser executable to run instead of the bundled one. If `executablePath` is a relative path, then it is
       /// resolved relative to the current working directory. **BEWARE**: Playwright is only guaranteed to work with the bundled
       /// Chromium, Firefox or WebKit, use at your own risk.
-      executable: Option<&'b Path>,
+      executable: Option<PathBuf>,
       /// Additional arguments to pass to the browser instance. The list of Chromium flags can be found
       /// [here](http://peter.sh/experiments/chromium-command-line-switches/).
-      args: Option<&'c [String]>,
+      args: Option<Vec<String>>,
       /// If `true`, Playwright does not pass its own configurations args and only uses the ones from `args`. Dangerous option;
       /// use with care. Defaults to `false`.
       ignore_all_default_args: Option<bool>,
@@ -225,7 +240,7 @@ impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k>
       proxy: Option<ProxySettings>,
       /// If specified, accepted downloads are downloaded into this directory. Otherwise, temporary directory is created and is
       /// deleted when browser is closed.
-      downloads: Option<&'d Path>,
+      downloads: Option<PathBuf>,
       /// Slows down Playwright operations by the specified amount of milliseconds. Useful so that you can see what is going on.
       /// Defaults to 0.
       slowmo: Option<f64>,
@@ -243,24 +258,24 @@ impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k>
       /// Toggles bypassing page's Content-Security-Policy.
       bypass_csp: Option<bool>,
       /// Specific user agent to use in this context.
-      user_agent: Option<&'e str>,
+      user_agent: Option<String>,
       /// Specify user locale, for example `en-GB`, `de-DE`, etc. Locale will affect `navigator.language` value, `Accept-Language`
       /// request header value as well as number and date formatting rules.
-      locale: Option<&'f str>,
+      locale: Option<String>,
       /// Changes the timezone of the context. See
       /// [ICU's metaZones.txt](https://cs.chromium.org/chromium/src/third_party/icu/source/data/misc/metaZones.txt?rcl=faee8bc70570192d82d2978a71e2a615788597d1)
       /// for a list of supported timezone IDs.
-      timezone_id: Option<&'g str>,
+      timezone_id: Option<String>,
       geolocation: Option<Geolocation>,
       /// A list of permissions to grant to all pages in this context. See [`method: BrowserContext.grantPermissions`] for more
       /// details.
-      permissions: Option<&'h [String]>,
+      permissions: Option<Vec<String>>,
       /// An object containing additional HTTP headers to be sent with every request. All header values must be strings.
       extra_http_headers: Option<HashMap<String, String>>,
       /// Whether to emulate network being offline. Defaults to `false`.
       offline: Option<bool>,
       /// Credentials for [HTTP authentication](https://developer.mozilla.org/en-US/docs/Web/HTTP/Authentication).
-      http_credentials: Option<&'i HttpCredentials>,
+      http_credentials: Option<HttpCredentials>,
       /// Specify device scale factor (can be thought of as dpr). Defaults to `1`.
       device_scale_factor: Option<f64>,
       /// Whether the `meta viewport` tag is taken into account and touch events are enabled. Defaults to `false`. Not supported
@@ -277,28 +292,40 @@ impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k>
       chromium_sandbox: Option<bool>,
       /// Enables video recording for all pages into `recordVideo.dir` directory. If not specified videos are not recorded. Make
       /// sure to await [`method: BrowserContext.close`] for videos to be saved.
-      record_video: Option<RecordVideo<'j>>,
+      record_video: Option<RecordVideo>,
+      /// Keeps the recorded videos only of pages flagged with [`Page::keep_video`](crate::api::Page::keep_video). The others
+      /// are deleted as soon as their page closes.
+      keep_flagged_videos_only: Option<bool>,
       /// Enables [HAR](http://www.softwareishard.com/blog/har-12-spec) recording for all pages into `recordHar.path` file. If not
       /// specified, the HAR is not recorded. Make sure to await [`method: BrowserContext.close`] for the HAR to be saved.
-      record_har: Option<RecordHar<'k>>,
+      record_har: Option<RecordHar>,
+      /// Populates the context with the storage state saved to this file by
+      /// [`BrowserContext::storage_state_to_path`](crate::api::BrowserContext::storage_state_to_path)
+      /// or `storageState({ path })` of the other Playwright languages. Cookies are added on launch and missing local storage
+      /// entries are restored before the scripts of each document run.
+      storage_state_path: Option<PathBuf>,
 
       channel: Option<BrowserChannel>
   }
 
-  pub async fn launch(self) -> Result<BrowserContext, Arc<Error>> {
-    let Self { inner, args } = self;
-    let r = upgrade(&inner)?.launch_persistent_context(args).await?;
-    Ok(BrowserContext::new(r))
+  /// Honors `PWDEBUG` the same as [`Launcher::launch`].
+  #[track_caller]
+  pub fn launch(self) -> impl Future<Output = Result<BrowserContext, Arc<Error>>> {
+    api_call!("browser_type.launch_persistent_context", {
+      let Self { inner, args } = self;
+      let r = upgrade(&inner)?.launch_persistent_context(args).await?;
+      Ok(BrowserContext::new(r))
+    })
   }
 
-  fn new(inner: Weak<Impl>, user_data_dir: &'a Path) -> Self {
+  fn new(inner: Weak<Impl>, user_data_dir: &Path) -> Self {
     Self {
       inner,
       args: LaunchPersistentContextArgs::new(user_data_dir),
     }
   }
 
-  pub fn set_device(self, device: &'e DeviceDescriptor) -> Self {
+  pub fn set_device(self, device: &DeviceDescriptor) -> Self {
     DeviceDescriptor::set_persistent_context(device, self)
   }
   //#[doc = "If `true`, Playwright does not pass its own configurations args and only uses the ones from `args`. Dangerous option;\nuse with care."]
@@ -324,12 +351,15 @@ impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k>
   //#[doc instead."] videos_path: Option<path>,
 }
 
-pub struct ConnectOverCdpBuilder<'a> {
+into_future!(PersistentContextLauncher, launch, Result<BrowserContext, Arc<Error>>);
+
+#[derive(Clone)]
+pub struct ConnectOverCdpBuilder {
   inner: Weak<Impl>,
-  args: ConnectOverCdpArgs<'a>,
+  args: ConnectOverCdpArgs,
 }
 
-impl<'a> ConnectOverCdpBuilder<'a> {
+impl ConnectOverCdpBuilder {
   setter! {
       /// Additional HTTP headers to be sent with web socket connect request. Optional.
       headers: Option<HashMap<String, String>>,
@@ -341,16 +371,64 @@ impl<'a> ConnectOverCdpBuilder<'a> {
       slowmo: Option<f64>
   }
 
-  pub async fn connect_over_cdp(self) -> ArcResult<Browser> {
-    let Self { inner, args } = self;
-    let r = upgrade(&inner)?.connect_over_cdp(args).await?;
-    Ok(Browser::new(r))
+  #[track_caller]
+  pub fn connect_over_cdp(self) -> impl Future<Output = ArcResult<Browser>> {
+    api_call!("browser_type.connect_over_cdp", {
+      let Self { inner, args } = self;
+      let r = upgrade(&inner)?.connect_over_cdp(args).await?;
+      Ok(Browser::new(r))
+    })
   }
 
-  fn new(inner: Weak<Impl>, endpoint_url: &'a str) -> Self {
+  fn new(inner: Weak<Impl>, endpoint_url: &str) -> Self {
     Self {
       inner,
       args: ConnectOverCdpArgs::new(endpoint_url),
     }
   }
 }
+
+into_future!(ConnectOverCdpBuilder, connect_over_cdp, ArcResult<Browser>);
+
+#[derive(Clone)]
+pub struct ConnectBuilder {
+  inner: Weak<Impl>,
+  args: ConnectArgs,
+}
+
+impl ConnectBuilder {
+  setter! {
+      /// Additional HTTP headers to be sent with web socket connect request. Optional.
+      headers: Option<HashMap<String, String>>,
+      /// Maximum time in milliseconds to wait for the connection to be established. Defaults to `30000` (30 seconds). Pass `0`
+      /// to disable timeout.
+      timeout: Option<f64>,
+      /// Slows down Playwright operations by the specified amount of milliseconds. Useful so that you can see what is going on.
+      /// Defaults to 0.
+      slowmo: Option<f64>,
+      /// Makes the hosts matched by the rules reachable from the remote browser, by tunneling its connections to them through
+      /// this process. Rules are separated by commas and are either `*` to expose everything, `<loopback>` for `localhost`
+      /// and the loopback addresses, a `*.example.com` wildcard or a host name.
+      ///
+      /// For example `"<loopback>"` lets the remote browser open test servers started on `localhost` of this machine.
+      expose_network: Option<String>
+  }
+
+  #[track_caller]
+  pub fn connect(self) -> impl Future<Output = ArcResult<Browser>> {
+    api_call!("browser_type.connect", {
+      let Self { inner, args } = self;
+      let r = upgrade(&inner)?.connect(args).await?;
+      Ok(Browser::new(r))
+    })
+  }
+
+  fn new(inner: Weak<Impl>, ws_endpoint: &str) -> Self {
+    Self {
+      inner,
+      args: ConnectArgs::new(ws_endpoint),
+    }
+  }
+}
+
+into_future!(ConnectBuilder, connect, ArcResult<Browser>);
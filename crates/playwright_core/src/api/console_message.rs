@@ -41,7 +41,8 @@ impl ConsoleMessage {
       upgrade(&self.inner)?
         .args()
         .iter()
-        .map(|x| JsHandle::new(x.clone()))
+        // owned by the message
+        .map(|x| JsHandle::borrowed(x.clone()))
         .collect(),
     )
   }
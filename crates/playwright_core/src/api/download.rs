@@ -1,6 +1,12 @@
+use std::io;
+
+use futures::future::BoxFuture;
+use futures::io::AsyncRead;
+
 use crate::imp::core::*;
 use crate::imp::download::Download as Impl;
 use crate::imp::prelude::*;
+use crate::imp::stream::Stream as StreamImpl;
 
 /// `Download` objects are dispatched by page via the [`event: Page.download`]
 /// event.
@@ -49,30 +55,168 @@ impl Download {
 
   /// Returns path to the downloaded file in case of successful download. The
   /// method will wait for the download to finish if necessary.
-  pub async fn path(&self) -> ArcResult<Option<PathBuf>> {
-    self.inner.path().await
+  #[track_caller]
+  pub fn path<'a>(&'a self) -> impl Future<Output = ArcResult<Option<PathBuf>>> + 'a {
+    api_call!("download.path", { self.inner.path().await })
   }
 
   /// Deletes the downloaded file. Will wait for the download to finish if
   /// necessary.
-  pub async fn delete(&self) -> ArcResult<()> {
-    self.inner.delete().await
+  #[track_caller]
+  pub fn delete<'a>(&'a self) -> impl Future<Output = ArcResult<()>> + 'a {
+    api_call!("download.delete", { self.inner.delete().await })
   }
 
   /// Saves the download to a user-specified path. It is safe to call this
   /// method while the download is still in progress. Path where the download
   /// should be saved.
-  pub async fn save_as<P: AsRef<Path>>(&self, path: P) -> Result<(), Arc<Error>> {
-    self.inner.save_as(path).await
+  #[track_caller]
+  pub fn save_as<'a, P: AsRef<Path>>(
+    &'a self,
+    path: P,
+  ) -> impl Future<Output = Result<(), Arc<Error>>> + 'a
+  where
+    P: 'a,
+  {
+    api_call!("download.save_as", { self.inner.save_as(path).await })
+  }
+
+  /// Returns readable stream for current download or `None` if download
+  /// failed. The content is fetched chunk by chunk as the stream is polled.
+  #[track_caller]
+  pub fn create_read_stream<'a>(
+    &'a self,
+  ) -> impl Future<Output = ArcResult<Option<ReadStream>>> + 'a {
+    api_call!("download.create_read_stream", {
+      Ok(self.inner.create_read_stream().await?.map(ReadStream::new))
+    })
   }
 
-  ///// Returns readable stream for current download or `null` if download failed.
-  // fn create_read_stream(&self) -> Result<Option<Readable>, Arc<Error>> {
-  // todo!() }
+  /// Cancels a download. Will not fail if the download is already finished
+  /// or canceled. Upon successful cancellations, `download.failure()` would
+  /// resolve to `"canceled"`.
+  #[track_caller]
+  pub fn cancel<'a>(&'a self) -> impl Future<Output = ArcResult<()>> + 'a {
+    api_call!("download.cancel", { self.inner.cancel().await })
+  }
 
   /// Returns download error if any. Will wait for the download to finish if
   /// necessary.
-  pub async fn failure(&self) -> Result<Option<String>, Arc<Error>> {
-    self.inner.failure().await
+  #[track_caller]
+  pub fn failure<'a>(&'a self) -> impl Future<Output = Result<Option<String>, Arc<Error>>> + 'a {
+    api_call!("download.failure", { self.inner.failure().await })
+  }
+}
+
+/// Content of a [`Download`], read from the driver as it is consumed.
+///
+/// Usable both as a `Stream` of chunks and as an `AsyncRead`. The remote
+/// stream is closed when this is dropped.
+pub struct ReadStream {
+  inner: Weak<StreamImpl>,
+  /// Runtime the stream was opened in. Closing is sent from a task of it
+  /// since the stream may be dropped anywhere, even with the connection locked.
+  runtime: Option<tokio::runtime::Handle>,
+  pending: Option<BoxFuture<'static, ArcResult<Vec<u8>>>>,
+  buf: Vec<u8>,
+  pos: usize,
+  done: bool,
+}
+
+impl ReadStream {
+  fn new(inner: Weak<StreamImpl>) -> Self {
+    let runtime = inner.upgrade().and_then(|s| s.runtime().ok());
+    Self {
+      inner,
+      runtime,
+      pending: None,
+      buf: Vec::new(),
+      pos: 0,
+      done: false,
+    }
+  }
+
+  fn poll_chunk(&mut self, cx: &mut std::task::Context<'_>) -> Poll<Option<ArcResult<Vec<u8>>>> {
+    if self.done {
+      return Poll::Ready(None);
+    }
+    let inner = self.inner.clone();
+    let pending = self
+      .pending
+      .get_or_insert_with(|| Box::pin(async move { upgrade(&inner)?.read(None).await }));
+    let res = match pending.as_mut().poll(cx) {
+      Poll::Ready(x) => x,
+      Poll::Pending => return Poll::Pending,
+    };
+    self.pending = None;
+    match res {
+      Ok(bytes) if bytes.is_empty() => {
+        self.done = true;
+        Poll::Ready(None)
+      }
+      Ok(bytes) => Poll::Ready(Some(Ok(bytes))),
+      Err(e) => {
+        self.done = true;
+        Poll::Ready(Some(Err(e)))
+      }
+    }
+  }
+}
+
+impl futures::stream::Stream for ReadStream {
+  type Item = ArcResult<Vec<u8>>;
+
+  fn poll_next(self: Pin<&mut Self>, cx: &mut std::task::Context<'_>) -> Poll<Option<Self::Item>> {
+    let this = self.get_mut();
+    if this.pos < this.buf.len() {
+      let rest = this.buf.split_off(this.pos);
+      this.buf.clear();
+      this.pos = 0;
+      return Poll::Ready(Some(Ok(rest)));
+    }
+    this.poll_chunk(cx)
+  }
+}
+
+impl AsyncRead for ReadStream {
+  fn poll_read(
+    self: Pin<&mut Self>,
+    cx: &mut std::task::Context<'_>,
+    out: &mut [u8],
+  ) -> Poll<io::Result<usize>> {
+    let this = self.get_mut();
+    while this.pos >= this.buf.len() {
+      match this.poll_chunk(cx) {
+        Poll::Ready(Some(Ok(bytes))) => {
+          this.buf = bytes;
+          this.pos = 0;
+        }
+        Poll::Ready(Some(Err(e))) => {
+          return Poll::Ready(Err(io::Error::new(io::ErrorKind::Other, e)));
+        }
+        Poll::Ready(None) => return Poll::Ready(Ok(0)),
+        Poll::Pending => return Poll::Pending,
+      }
+    }
+    let n = out.len().min(this.buf.len() - this.pos);
+    out[..n].copy_from_slice(&this.buf[this.pos..this.pos + n]);
+    this.pos += n;
+    Poll::Ready(Ok(n))
+  }
+}
+
+impl Drop for ReadStream {
+  fn drop(&mut self) {
+    let inner = self.inner.clone();
+    match &self.runtime {
+      Some(rt) => {
+        rt.spawn(async move {
+          if let Err(e) = async { upgrade(&inner)?.close().await }.await {
+            log::trace!("failed to close a stream: {:?}", e);
+          }
+        });
+      }
+      None => log::trace!("stream opened outside of a runtime is not closed"),
+    }
   }
 }
@@ -0,0 +1,119 @@
+use crate::api::electron_application::ElectronApplication;
+use crate::imp::browser_type::RecordVideo;
+use crate::imp::core::*;
+use crate::imp::electron::Electron as Impl;
+use crate::imp::electron::ElectronLaunchArgs;
+use crate::imp::prelude::*;
+use crate::imp::utils::ColorScheme;
+use crate::imp::utils::Geolocation;
+use crate::imp::utils::HttpCredentials;
+
+/// Playwright has **experimental** support for Electron automation. You can
+/// access electron namespace via
+/// [`Playwright::electron`](crate::api::Playwright::electron).
+///
+/// An example of the Electron automation script would be:
+///
+/// ```js
+/// const { _electron: electron } = require('playwright');
+///
+/// (async () => {
+///  // Launch Electron app.
+///  const electronApp = await electron.launch({ args: ['main.js'] });
+///
+///  // Evaluation expression in the Electron context.
+///  const appPath = await electronApp.evaluate(async ({ app }) => {
+///    // This runs in the main Electron process, parameter here is always
+///    // the result of the require('electron') in the main app script.
+///    return app.getAppPath();
+///  });
+///  console.log(appPath);
+///
+///  // Get the first window that the app opens, wait if necessary.
+///  const window = await electronApp.firstWindow();
+///  // Print the title.
+///  console.log(await window.title());
+///  // Exit app.
+///  await electronApp.close();
+/// })();
+/// ```
+#[derive(Debug, Clone)]
+pub struct Electron {
+  inner: Weak<Impl>,
+}
+
+impl Electron {
+  pub(crate) fn new(inner: Weak<Impl>) -> Self {
+    Self { inner }
+  }
+
+  /// launch [`ElectronApplication`]
+  pub fn launcher(&self) -> ElectronLauncher {
+    ElectronLauncher::new(self.inner.clone())
+  }
+}
+
+/// [`Electron::launcher`]
+#[derive(Clone)]
+pub struct ElectronLauncher {
+  inner: Weak<Impl>,
+  args: ElectronLaunchArgs,
+}
+
+impl ElectronLauncher {
+  setter! {
+      /// Launches given Electron application. If not specified, launches the default Electron executable installed in this
+      /// package, located at `node_modules/.bin/electron`.
+      executable: Option<PathBuf>,
+      /// Additional arguments to pass to the application when launching. You typically pass the main script name here.
+      args: Option<Vec<String>>,
+      /// Current working directory to launch application from.
+      cwd: Option<PathBuf>,
+      /// Specifies environment variables that will be visible to Electron. Defaults to `process.env`.
+      env: Option<HashMap<String, String>>,
+      /// Maximum time in milliseconds to wait for the application to start. Defaults to `30000` (30 seconds). Pass `0` to
+      /// disable timeout.
+      timeout: Option<f64>,
+      /// Whether to automatically download all the attachments. Defaults to `true` where all the downloads are accepted.
+      accept_downloads: Option<bool>,
+      /// Toggles bypassing page's Content-Security-Policy.
+      bypass_csp: Option<bool>,
+      /// Emulates `'prefers-colors-scheme'` media feature, supported values are `'light'`, `'dark'`, `'no-preference'`.
+      color_scheme: Option<ColorScheme>,
+      geolocation: Option<Geolocation>,
+      /// Credentials for [HTTP authentication](https://developer.mozilla.org/en-US/docs/Web/HTTP/Authentication).
+      http_credentials: Option<HttpCredentials>,
+      /// Whether to ignore HTTPS errors when sending network requests. Defaults to `false`.
+      ignore_https_errors: Option<bool>,
+      /// Specify user locale, for example `en-GB`, `de-DE`, etc. Locale will affect `navigator.language` value,
+      /// `Accept-Language` request header value as well as number and date formatting rules.
+      locale: Option<String>,
+      /// Whether to emulate network being offline. Defaults to `false`.
+      offline: Option<bool>,
+      /// Enables video recording for all pages into `recordVideo.dir` directory. If not specified videos are not recorded. Make
+      /// sure to await [`method: BrowserContext.close`] for videos to be saved.
+      record_video: Option<RecordVideo>,
+      /// Changes the timezone of the context. See
+      /// [ICU's metaZones.txt](https://cs.chromium.org/chromium/src/third_party/icu/source/data/misc/metaZones.txt?rcl=faee8bc70570192d82d2978a71e2a615788597d1)
+      /// for a list of supported timezone IDs.
+      timezone_id: Option<String>
+  }
+
+  #[track_caller]
+  pub fn launch(self) -> impl Future<Output = ArcResult<ElectronApplication>> {
+    api_call!("electron.launch", {
+      let Self { inner, args } = self;
+      let r = upgrade(&inner)?.launch(args).await?;
+      Ok(ElectronApplication::new(r))
+    })
+  }
+
+  fn new(inner: Weak<Impl>) -> Self {
+    Self {
+      inner,
+      args: ElectronLaunchArgs::default(),
+    }
+  }
+}
+
+into_future!(ElectronLauncher, launch, ArcResult<ElectronApplication>);
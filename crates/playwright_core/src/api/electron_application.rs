@@ -0,0 +1,237 @@
+use crate::api::BrowserContext;
+use crate::api::JsHandle;
+use crate::api::Page;
+use crate::imp::core::*;
+use crate::imp::electron_application::ElectronApplication as Impl;
+pub use crate::imp::electron_application::EventType;
+use crate::imp::electron_application::Evt;
+use crate::imp::prelude::*;
+
+/// Electron application representation. You can use
+/// [`ElectronLauncher::launch`](crate::api::electron::ElectronLauncher::launch)
+/// to obtain the application instance. This instance you can control main
+/// electron process as well as work with Electron windows:
+///
+/// ```js
+/// const { _electron: electron } = require('playwright');
+///
+/// (async () => {
+///  // Launch Electron app.
+///  const electronApp = await electron.launch({ args: ['main.js'] });
+///
+///  // Evaluation expression in the Electron context.
+///  const appPath = await electronApp.evaluate(async ({ app }) => {
+///    // This runs in the main Electron process, parameter here is always
+///    // the result of the require('electron') in the main app script.
+///    return app.getAppPath();
+///  });
+///  console.log(appPath);
+///
+///  // Get the first window that the app opens, wait if necessary.
+///  const window = await electronApp.firstWindow();
+///  // Print the title.
+///  console.log(await window.title());
+///  // Capture a screenshot.
+///  await window.screenshot({ path: 'intro.png' });
+///  // Direct Electron console to Node terminal.
+///  window.on('console', console.log);
+///  // Click button.
+///  await window.click('text=Click me');
+///  // Exit app.
+///  await electronApp.close();
+/// })();
+/// ```
+#[derive(Debug, Clone)]
+pub struct ElectronApplication {
+  inner: Weak<Impl>,
+}
+
+impl PartialEq for ElectronApplication {
+  fn eq(&self, other: &Self) -> bool {
+    let a = self.inner.upgrade();
+    let b = other.inner.upgrade();
+    a.and_then(|a| b.map(|b| (a, b)))
+      .map(|(a, b)| a.guid() == b.guid())
+      .unwrap_or_default()
+  }
+}
+
+impl ElectronApplication {
+  subscribe_event! {}
+
+  event_handlers! {}
+
+  pub(crate) fn new(inner: Weak<Impl>) -> Self {
+    Self { inner }
+  }
+
+  /// This method returns browser context that can be used for setting up
+  /// context-wide routing, etc.
+  pub fn context(&self) -> Result<BrowserContext, Error> {
+    Ok(BrowserContext::new(upgrade(&self.inner)?.browser_context()))
+  }
+
+  /// Convenience method that returns all the opened windows.
+  pub fn windows(&self) -> Result<Vec<Page>, Error> {
+    Ok(
+      upgrade(&self.inner)?
+        .windows()
+        .into_iter()
+        .map(Page::new)
+        .collect(),
+    )
+  }
+
+  /// Convenience method that waits for the first application window to be
+  /// opened. Typically your script will start with:
+  ///
+  /// ```js
+  ///  const electronApp = await electron.launch({
+  ///    args: ['main.js']
+  ///  });
+  ///  const window = await electronApp.firstWindow();
+  ///  // ...
+  /// ```
+  #[track_caller]
+  pub fn first_window<'a>(&'a self) -> impl Future<Output = Result<Page, Error>> + 'a {
+    api_call!("electron_application.first_window", {
+      let inner = upgrade(&self.inner)?;
+      let stream = inner.subscribe_event();
+      if let Some(w) = inner.windows().into_iter().next() {
+        return Ok(Page::new(w));
+      }
+      let timeout = upgrade(&inner.browser_context())?.default_timeout();
+      match expect_event(stream, EventType::Window, timeout).await? {
+        Evt::Window(w) => Ok(Page::new(w)),
+        _ => unreachable!(),
+      }
+    })
+  }
+
+  #[track_caller]
+  pub fn expect_event<'a>(
+    &'a self,
+    evt: EventType,
+  ) -> impl Future<Output = Result<Event, Error>> + 'a {
+    api_call!("electron_application.expect_event", {
+      let inner = upgrade(&self.inner)?;
+      let stream = inner.subscribe_event();
+      let timeout = upgrade(&inner.browser_context())?.default_timeout();
+      expect_event(stream, evt, timeout).await.map(Event::from)
+    })
+  }
+
+  /// Returns the handle of the `BrowserWindow` object that corresponds to
+  /// `page`.
+  #[track_caller]
+  pub fn browser_window<'a>(
+    &'a self,
+    page: &'a Page,
+  ) -> impl Future<Output = ArcResult<JsHandle>> + 'a {
+    api_call!("electron_application.browser_window", {
+      upgrade(&self.inner)?
+        .browser_window(page.inner())
+        .await
+        .map(JsHandle::new)
+    })
+  }
+
+  /// Returns the return value of `expression` evaluated in the main Electron
+  /// process, whose first argument is always the result of
+  /// `require('electron')` in the main app script.
+  ///
+  /// ```js
+  /// const appPath = await electronApp.evaluate(async ({ app }) => app.getAppPath());
+  /// ```
+  #[track_caller]
+  pub fn eval<'a, U>(&'a self, expression: &'a str) -> impl Future<Output = ArcResult<U>> + 'a
+  where
+    U: DeserializeOwned,
+    U: 'a,
+  {
+    api_call!("electron_application.eval", {
+      upgrade(&self.inner)?
+        .evaluate::<(), U>(expression, None)
+        .await
+    })
+  }
+
+  #[track_caller]
+  pub fn evaluate<'a, T, U>(
+    &'a self,
+    expression: &'a str,
+    arg: Option<T>,
+  ) -> impl Future<Output = ArcResult<U>> + 'a
+  where
+    T: Serialize,
+    U: DeserializeOwned,
+    T: 'a,
+    U: 'a,
+  {
+    api_call!("electron_application.evaluate", {
+      upgrade(&self.inner)?.evaluate(expression, arg).await
+    })
+  }
+
+  #[track_caller]
+  pub fn eval_handle<'a>(
+    &'a self,
+    expression: &'a str,
+  ) -> impl Future<Output = ArcResult<JsHandle>> + 'a {
+    api_call!("electron_application.eval_handle", {
+      upgrade(&self.inner)?
+        .evaluate_handle::<()>(expression, None)
+        .await
+        .map(JsHandle::new)
+    })
+  }
+
+  #[track_caller]
+  pub fn evaluate_handle<'a, T>(
+    &'a self,
+    expression: &'a str,
+    arg: Option<T>,
+  ) -> impl Future<Output = ArcResult<JsHandle>> + 'a
+  where
+    T: Serialize,
+    T: 'a,
+  {
+    api_call!("electron_application.evaluate_handle", {
+      upgrade(&self.inner)?
+        .evaluate_handle(expression, arg)
+        .await
+        .map(JsHandle::new)
+    })
+  }
+
+  /// Closes Electron application.
+  #[track_caller]
+  pub fn close<'a>(&'a self) -> impl Future<Output = ArcResult<()>> + 'a {
+    api_call!("electron_application.close", {
+      let inner = match self.inner.upgrade() {
+        None => return Ok(()),
+        Some(inner) => inner,
+      };
+      inner.close().await
+    })
+  }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Event {
+  /// This event is issued when the application closes.
+  Close,
+  /// This event is issued for every window that is created **and loaded** in
+  /// Electron. It contains a [`Page`] that can be used for Playwright
+  /// automation.
+  Window(Page),
+}
+
+impl From<Evt> for Event {
+  fn from(e: Evt) -> Self {
+    match e {
+      Evt::Close => Self::Close,
+      Evt::Window(w) => Self::Window(Page::new(w)),
+    }
+  }
+}
@@ -1,18 +1,24 @@
+use crate::api::js_handle::Owner;
 use crate::api::Frame;
+use crate::api::JsHandle;
 use crate::imp::core::*;
 use crate::imp::element_handle::CheckArgs;
 use crate::imp::element_handle::ClickArgs;
+use crate::imp::element_handle::DragToArgs;
 use crate::imp::element_handle::ElementHandle as Impl;
 use crate::imp::element_handle::FillArgs;
 use crate::imp::element_handle::HoverArgs;
+use crate::imp::element_handle::Mask;
 use crate::imp::element_handle::Opt;
 use crate::imp::element_handle::PressArgs;
 use crate::imp::element_handle::ScreenshotArgs;
 use crate::imp::element_handle::SelectOptionArgs;
+use crate::imp::element_handle::SetInputFilePathsArgs;
 use crate::imp::element_handle::SetInputFilesArgs;
 use crate::imp::element_handle::TapArgs;
 use crate::imp::element_handle::TypeArgs;
 use crate::imp::element_handle::WaitForSelectorArgs;
+use crate::imp::js_handle::Handle;
 use crate::imp::prelude::*;
 use crate::imp::utils::ElementState;
 use crate::imp::utils::File;
@@ -20,6 +26,9 @@ use crate::imp::utils::FloatRect;
 use crate::imp::utils::KeyboardModifier;
 use crate::imp::utils::MouseButton;
 use crate::imp::utils::Position;
+use crate::imp::utils::ScreenshotAnimations;
+use crate::imp::utils::ScreenshotCaret;
+use crate::imp::utils::ScreenshotScale;
 use crate::imp::utils::ScreenshotType;
 use crate::imp::utils::WaitForSelectorState;
 
@@ -45,9 +54,11 @@ use crate::imp::utils::WaitForSelectorState;
 ///
 /// ElementHandle instances can be used as an argument in [`method:
 /// Page.evalOnSelector`] and [`method: Page.evaluate`] methods.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ElementHandle {
   inner: Weak<Impl>,
+  /// Disposes the element on drop unless it belongs to another object
+  _owner: Option<Arc<Owner>>,
 }
 
 impl PartialEq for ElementHandle {
@@ -62,8 +73,11 @@ impl PartialEq for ElementHandle {
 
 macro_rules! is_checked {
   ($f:ident) => {
-    pub async fn $f(&self) -> ArcResult<bool> {
-      upgrade(&self.inner)?.$f().await
+    #[track_caller]
+    pub fn $f<'a>(&'a self) -> impl Future<Output = ArcResult<bool>> + 'a {
+      api_call!(concat!("element_handle.", stringify!($f)), {
+        upgrade(&self.inner)?.$f().await
+      })
     }
   };
 }
@@ -82,7 +96,30 @@ impl ElementHandle {
   is_checked! {is_visible}
 
   pub(crate) fn new(inner: Weak<Impl>) -> Self {
-    Self { inner }
+    let owner = Owner::new(Handle::Element(inner.clone()));
+    Self {
+      inner,
+      _owner: Some(owner),
+    }
+  }
+
+  /// For an element that lives as long as the object it was obtained from
+  pub(crate) fn borrowed(inner: Weak<Impl>) -> Self {
+    Self {
+      inner,
+      _owner: None,
+    }
+  }
+
+  pub(crate) fn owned_by(inner: Weak<Impl>, owner: Arc<Owner>) -> Self {
+    Self {
+      inner,
+      _owner: Some(owner),
+    }
+  }
+
+  pub(crate) fn inner(&self) -> Weak<Impl> {
+    self.inner.clone()
   }
 
   pub(crate) fn guid(&self) -> Result<Str<Guid>, Error> {
@@ -92,52 +129,85 @@ impl ElementHandle {
   /// The method finds an element matching the specified selector in the
   /// `ElementHandle`'s subtree. If no elements match the selector, returns
   /// `null`.
-  pub async fn query_selector(&self, selector: &str) -> ArcResult<Option<ElementHandle>> {
-    Ok(
-      upgrade(&self.inner)?
-        .query_selector(selector)
-        .await?
-        .map(ElementHandle::new),
-    )
+  #[track_caller]
+  pub fn query_selector<'a>(
+    &'a self,
+    selector: &'a str,
+  ) -> impl Future<Output = ArcResult<Option<ElementHandle>>> + 'a {
+    api_call!("element_handle.query_selector", {
+      Ok(
+        upgrade(&self.inner)?
+          .query_selector(selector)
+          .await?
+          .map(ElementHandle::new),
+      )
+    })
   }
 
   /// The method finds all elements matching the specified selector in the
   /// `ElementHandle`s subtree. If no elements match the selector, returns
   /// empty array.
-  pub async fn query_selector_all(&self, selector: &str) -> ArcResult<Vec<ElementHandle>> {
-    let es = upgrade(&self.inner)?.query_selector_all(selector).await?;
-    Ok(es.into_iter().map(ElementHandle::new).collect())
+  #[track_caller]
+  pub fn query_selector_all<'a>(
+    &'a self,
+    selector: &'a str,
+  ) -> impl Future<Output = ArcResult<Vec<ElementHandle>>> + 'a {
+    api_call!("element_handle.query_selector_all", {
+      let es = upgrade(&self.inner)?.query_selector_all(selector).await?;
+      Ok(es.into_iter().map(ElementHandle::new).collect())
+    })
   }
 
   /// Returns the `element.innerText`.
-  pub async fn inner_text(&self) -> ArcResult<String> {
-    upgrade(&self.inner)?.inner_text().await
+  #[track_caller]
+  pub fn inner_text<'a>(&'a self) -> impl Future<Output = ArcResult<String>> + 'a {
+    api_call!("element_handle.inner_text", {
+      upgrade(&self.inner)?.inner_text().await
+    })
   }
 
   /// Returns the `element.innerHTML`.
-  pub async fn inner_html(&self) -> ArcResult<String> {
-    upgrade(&self.inner)?.inner_html().await
+  #[track_caller]
+  pub fn inner_html<'a>(&'a self) -> impl Future<Output = ArcResult<String>> + 'a {
+    api_call!("element_handle.inner_html", {
+      upgrade(&self.inner)?.inner_html().await
+    })
   }
 
   /// Returns the frame containing the given element.
-  pub async fn owner_frame(&self) -> ArcResult<Option<Frame>> {
-    Ok(upgrade(&self.inner)?.owner_frame().await?.map(Frame::new))
+  #[track_caller]
+  pub fn owner_frame<'a>(&'a self) -> impl Future<Output = ArcResult<Option<Frame>>> + 'a {
+    api_call!("element_handle.owner_frame", {
+      Ok(upgrade(&self.inner)?.owner_frame().await?.map(Frame::new))
+    })
   }
 
   /// Returns the content frame for element handles referencing iframe nodes, or
   /// `null` otherwise
-  pub async fn content_frame(&self) -> ArcResult<Option<Frame>> {
-    Ok(upgrade(&self.inner)?.content_frame().await?.map(Frame::new))
+  #[track_caller]
+  pub fn content_frame<'a>(&'a self) -> impl Future<Output = ArcResult<Option<Frame>>> + 'a {
+    api_call!("element_handle.content_frame", {
+      Ok(upgrade(&self.inner)?.content_frame().await?.map(Frame::new))
+    })
   }
 
   /// Returns element attribute value.
-  pub async fn get_attribute(&self, name: &str) -> ArcResult<Option<String>> {
-    upgrade(&self.inner)?.get_attribute(name).await
+  #[track_caller]
+  pub fn get_attribute<'a>(
+    &'a self,
+    name: &'a str,
+  ) -> impl Future<Output = ArcResult<Option<String>>> + 'a {
+    api_call!("element_handle.get_attribute", {
+      upgrade(&self.inner)?.get_attribute(name).await
+    })
   }
 
   /// Returns the `node.textContent`.
-  pub async fn text_content(&self) -> ArcResult<Option<String>> {
-    upgrade(&self.inner)?.text_content().await
+  #[track_caller]
+  pub fn text_content<'a>(&'a self) -> impl Future<Output = ArcResult<Option<String>>> + 'a {
+    api_call!("element_handle.text_content", {
+      upgrade(&self.inner)?.text_content().await
+    })
   }
 
   /// This method hovers over the element by performing the following steps:
@@ -156,6 +226,13 @@ impl ElementHandle {
     HoverBuilder::new(self.inner.clone())
   }
 
+  /// Drags this element and drops it onto `target`. Both are hovered as with
+  /// [`ElementHandle::hover_builder`], the mouse being pressed over this one
+  /// and released over `target`.
+  pub fn drag_to_builder(&self, target: &ElementHandle) -> DragToBuilder {
+    DragToBuilder::new(self.inner.clone(), target.inner.clone())
+  }
+
   pub fn click_builder(&self) -> ClickBuilder {
     ClickBuilder::new(self.inner.clone())
   }
@@ -248,13 +325,16 @@ impl ElementHandle {
   ///
   /// To send fine-grained keyboard events, use
   /// [ElementHandle::type_builder](ElementHandle::type_builder)
-  pub fn fill_builder<'a>(&self, value: &'a str) -> FillBuilder<'a> {
+  pub fn fill_builder(&self, value: &str) -> FillBuilder {
     FillBuilder::new(self.inner.clone(), value)
   }
 
   /// Calls [focus](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/focus) on the element.
-  pub async fn focus(&self) -> ArcResult<()> {
-    upgrade(&self.inner)?.focus().await
+  #[track_caller]
+  pub fn focus<'a>(&'a self) -> impl Future<Output = ArcResult<()>> + 'a {
+    api_call!("element_handle.focus", {
+      upgrade(&self.inner)?.focus().await
+    })
   }
 
   /// Focuses the element, and then sends a `keydown`, `keypress`/`input`, and
@@ -275,7 +355,7 @@ impl ElementHandle {
   /// await elementHandle.type('some text');
   /// await elementHandle.press('Enter');
   /// ```
-  pub fn type_builder<'a>(&self, text: &'a str) -> TypeBuilder<'a> {
+  pub fn type_builder(&self, text: &str) -> TypeBuilder {
     TypeBuilder::new(self.inner.clone(), text)
   }
 
@@ -303,7 +383,7 @@ impl ElementHandle {
   /// Shortcuts such as `key: "Control+o"` or `key: "Control+Shift+T"` are
   /// supported as well. When specified with the modifier, modifier is pressed
   /// and being held while the subsequent key is being pressed.
-  pub fn press_builder<'a>(&self, key: &'a str) -> PressBuilder<'a> {
+  pub fn press_builder(&self, key: &str) -> PressBuilder {
     PressBuilder::new(self.inner.clone(), key)
   }
 
@@ -313,16 +393,28 @@ impl ElementHandle {
   ///
   /// Throws when `elementHandle` does not point to an element
   /// [connected](https://developer.mozilla.org/en-US/docs/Web/API/Node/isConnected) to a Document or a ShadowRoot.
-  pub async fn scroll_into_view_if_needed(&self, timeout: Option<f64>) -> ArcResult<()> {
-    upgrade(&self.inner)?
-      .scroll_into_view_if_needed(timeout)
-      .await
+  #[track_caller]
+  pub fn scroll_into_view_if_needed<'a>(
+    &'a self,
+    timeout: Option<f64>,
+  ) -> impl Future<Output = ArcResult<()>> + 'a {
+    api_call!("element_handle.scroll_into_view_if_needed", {
+      upgrade(&self.inner)?
+        .scroll_into_view_if_needed(timeout)
+        .await
+    })
   }
 
   /// This method waits for actionability checks, then focuses the element and
   /// selects all its text content.
-  pub async fn select_text(&self, timeout: Option<f64>) -> ArcResult<()> {
-    upgrade(&self.inner)?.select_text(timeout).await
+  #[track_caller]
+  pub fn select_text<'a>(
+    &'a self,
+    timeout: Option<f64>,
+  ) -> impl Future<Output = ArcResult<()>> + 'a {
+    api_call!("element_handle.select_text", {
+      upgrade(&self.inner)?.select_text(timeout).await
+    })
   }
 
   /// This method returns the bounding box of the element, or `null` if the
@@ -344,8 +436,11 @@ impl ElementHandle {
   /// const box = await elementHandle.boundingBox();
   /// await page.mouse.click(box.x + box.width / 2, box.y + box.height / 2);
   /// ```
-  pub async fn bounding_box(&self) -> ArcResult<Option<FloatRect>> {
-    upgrade(&self.inner)?.bounding_box().await
+  #[track_caller]
+  pub fn bounding_box<'a>(&'a self) -> impl Future<Output = ArcResult<Option<FloatRect>>> + 'a {
+    api_call!("element_handle.bounding_box", {
+      upgrade(&self.inner)?.bounding_box().await
+    })
   }
 
   /// Returns the buffer with the captured screenshot.
@@ -353,19 +448,22 @@ impl ElementHandle {
   /// This method waits for the actionability checks, then scrolls element into
   /// view before taking a screenshot. If the element is detached from DOM,
   /// the method throws an error.
-  pub async fn screenshot_builder(&self) -> ScreenshotBuilder<'_> {
+  pub async fn screenshot_builder(&self) -> ScreenshotBuilder {
     ScreenshotBuilder::new(self.inner.clone())
   }
 
   /// Returns when the element satisfies the `state`.
-  pub async fn wait_for_element_state(
-    &self,
+  #[track_caller]
+  pub fn wait_for_element_state<'a>(
+    &'a self,
     state: ElementState,
     timeout: Option<f64>,
-  ) -> ArcResult<()> {
-    upgrade(&self.inner)?
-      .wait_for_element_state(state, timeout)
-      .await
+  ) -> impl Future<Output = ArcResult<()>> + 'a {
+    api_call!("element_handle.wait_for_element_state", {
+      upgrade(&self.inner)?
+        .wait_for_element_state(state, timeout)
+        .await
+    })
   }
 
   /// Returns when element specified by selector satisfies `state` option.
@@ -393,7 +491,7 @@ impl ElementHandle {
   ///  await browser.close();
   /// })();
   /// ```
-  pub fn wait_for_selector_builder<'a>(&self, selector: &'a str) -> WaitForSelectorBuilder<'a> {
+  pub fn wait_for_selector_builder(&self, selector: &str) -> WaitForSelectorBuilder {
     WaitForSelectorBuilder::new(self.inner.clone(), selector)
   }
 
@@ -427,13 +525,21 @@ impl ElementHandle {
   /// const dataTransfer = await page.evaluateHandle(() => new DataTransfer());
   /// await elementHandle.dispatchEvent('dragstart', { dataTransfer });
   /// ```
-  pub async fn dispatch_event<T>(&self, r#type: &str, event_init: Option<T>) -> ArcResult<()>
+  #[track_caller]
+  pub fn dispatch_event<'a, T>(
+    &'a self,
+    r#type: &'a str,
+    event_init: Option<T>,
+  ) -> impl Future<Output = ArcResult<()>> + 'a
   where
     T: Serialize,
+    T: 'a,
   {
-    upgrade(&self.inner)?
-      .dispatch_event(r#type, event_init)
-      .await
+    api_call!("element_handle.dispatch_event", {
+      upgrade(&self.inner)?
+        .dispatch_event(r#type, event_init)
+        .await
+    })
   }
 
   /// This method waits for [actionability](https://playwright.dev/docs/actionability/) checks, waits until all specified options are present in the
@@ -471,13 +577,122 @@ impl ElementHandle {
     SetInputFilesBuilder::new(self.inner.clone(), file)
   }
 
-  // eval_on_selector
-  // eval_on_selector_all
+  /// Like [`ElementHandle::set_input_files_builder`] but with files read from
+  /// `path`s, relative ones being resolved against the current working
+  /// directory. When connected to a remote browser the files are streamed to
+  /// it instead of being loaded into memory.
+  pub fn set_input_file_paths_builder<P: AsRef<Path>>(&self, path: P) -> SetInputFilePathsBuilder {
+    SetInputFilePathsBuilder::new(self.inner.clone(), path)
+  }
+
+  /// Evaluates `expression` in the browser with this element as the first
+  /// argument and `arg` as the second.
+  ///
+  /// ```js
+  /// const tweetHandle = await page.$('.tweet .retweets');
+  /// expect(await tweetHandle.evaluate(node => node.innerText)).toBe('10 retweets');
+  /// ```
+  #[track_caller]
+  pub fn evaluate<'a, T, U>(
+    &'a self,
+    expression: &'a str,
+    arg: Option<T>,
+  ) -> impl Future<Output = ArcResult<U>> + 'a
+  where
+    T: Serialize,
+    U: DeserializeOwned,
+    T: 'a,
+    U: 'a,
+  {
+    api_call!("element_handle.evaluate", {
+      self.as_handle().evaluate(expression, arg).await
+    })
+  }
+
+  /// Like [`ElementHandle::evaluate`] but returns the result as a handle
+  #[track_caller]
+  pub fn evaluate_handle<'a, T>(
+    &'a self,
+    expression: &'a str,
+    arg: Option<T>,
+  ) -> impl Future<Output = ArcResult<JsHandle>> + 'a
+  where
+    T: Serialize,
+    T: 'a,
+  {
+    api_call!("element_handle.evaluate_handle", {
+      self
+        .as_handle()
+        .evaluate_handle(expression, arg)
+        .await
+        .map(JsHandle::from_handle)
+    })
+  }
+
+  /// Finds the first element matching `selector` within this element and
+  /// passes it as the first argument to `expression`. Fails when no element
+  /// matches.
+  ///
+  /// ```js
+  /// const tweetHandle = await page.$('.tweet');
+  /// expect(await tweetHandle.$eval('.like', node => node.innerText)).toBe('100');
+  /// ```
+  #[track_caller]
+  pub fn eval_on_selector<'a, T, U>(
+    &'a self,
+    selector: &'a str,
+    expression: &'a str,
+    arg: Option<T>,
+  ) -> impl Future<Output = ArcResult<U>> + 'a
+  where
+    T: Serialize,
+    U: DeserializeOwned,
+    T: 'a,
+    U: 'a,
+  {
+    api_call!("element_handle.eval_on_selector", {
+      upgrade(&self.inner)?
+        .eval_on_selector(selector, expression, arg)
+        .await
+    })
+  }
+
+  /// Finds all elements matching `selector` within this element and passes
+  /// the array of them as the first argument to `expression`.
+  ///
+  /// ```js
+  /// const feedHandle = await page.$('.feed');
+  /// expect(await feedHandle.$$eval('.tweet', nodes => nodes.map(n => n.innerText))).toEqual(['Hello!', 'Hi!']);
+  /// ```
+  #[track_caller]
+  pub fn eval_on_selector_all<'a, T, U>(
+    &'a self,
+    selector: &'a str,
+    expression: &'a str,
+    arg: Option<T>,
+  ) -> impl Future<Output = ArcResult<U>> + 'a
+  where
+    T: Serialize,
+    U: DeserializeOwned,
+    T: 'a,
+    U: 'a,
+  {
+    api_call!("element_handle.eval_on_selector_all", {
+      upgrade(&self.inner)?
+        .eval_on_selector_all(selector, expression, arg)
+        .await
+    })
+  }
+
+  fn as_handle(&self) -> Handle {
+    Handle::Element(self.inner.clone())
+  }
 }
 
 // TODO: JsHandle
 impl ElementHandle {}
 
+#[derive(Clone)]
 pub struct HoverBuilder {
   inner: Weak<Impl>,
   args: HoverArgs,
@@ -504,14 +719,73 @@ impl HoverBuilder {
     Self { inner, args }
   }
 
-  pub async fn goto(self) -> Result<(), Arc<Error>> {
-    let Self { inner, args } = self;
-    upgrade(&inner)?.hover(args).await
+  #[track_caller]
+  pub fn hover(self) -> impl Future<Output = Result<(), Arc<Error>>> {
+    api_call!("element_handle.hover", {
+      let Self { inner, args } = self;
+      upgrade(&inner)?.hover(args).await
+    })
+  }
+
+  #[deprecated(note = "renamed to `hover`")]
+  #[track_caller]
+  pub fn goto(self) -> impl Future<Output = Result<(), Arc<Error>>> {
+    self.hover()
+  }
+}
+
+into_future!(HoverBuilder, hover, Result<(), Arc<Error>>);
+
+#[derive(Clone)]
+pub struct DragToBuilder {
+  inner: Weak<Impl>,
+  target: Weak<Impl>,
+  args: DragToArgs,
+}
+
+impl DragToBuilder {
+  setter! {
+      /// Whether to bypass the actionability checks. Defaults to `false`.
+      force: Option<bool>,
+      /// Clicks on the source element at this point relative to the top-left corner of the element's padding box. If not
+      /// specified, some visible point of the element is used.
+      source_position: Option<Position>,
+      /// Drops on the target element at this point relative to the top-left corner of the element's padding box. If not
+      /// specified, some visible point of the element is used.
+      target_position: Option<Position>,
+      timeout: Option<f64>,
+      /// When set, this method only performs the [actionability](https://playwright.dev/docs/actionability/) checks and skips the action. Defaults to
+      /// `false`. Useful to wait until the element is ready for the action without performing it.
+      trial: Option<bool>
+  }
+
+  pub(crate) fn new(inner: Weak<Impl>, target: Weak<Impl>) -> Self {
+    let args = DragToArgs::default();
+    Self {
+      inner,
+      target,
+      args,
+    }
+  }
+
+  #[track_caller]
+  pub fn drag_to(self) -> impl Future<Output = Result<(), Arc<Error>>> {
+    api_call!("element_handle.drag_to", {
+      let Self {
+        inner,
+        target,
+        args,
+      } = self;
+      upgrade(&inner)?.drag_to(&*upgrade(&target)?, args).await
+    })
   }
 }
 
+into_future!(DragToBuilder, drag_to, Result<(), Arc<Error>>);
+
 macro_rules! clicker {
   ($t:ident, $f:ident) => {
+    #[derive(Clone)]
     pub struct $t {
       inner: Weak<Impl>,
       args: ClickArgs,
@@ -546,12 +820,17 @@ macro_rules! clicker {
         Self { inner, args }
       }
 
-      pub async fn $f(self) -> Result<(), Arc<Error>> {
-        let Self { inner, args } = self;
-        let _ = upgrade(&inner)?.$f(args).await?;
-        Ok(())
+      #[track_caller]
+      pub fn $f(self) -> impl Future<Output = Result<(), Arc<Error>>> {
+        api_call!(concat!("element_handle.", stringify!($f)), {
+          let Self { inner, args } = self;
+          let _ = upgrade(&inner)?.$f(args).await?;
+          Ok(())
+        })
       }
     }
+
+    into_future!($t, $f, Result<(), Arc<Error>>);
   };
 }
 
@@ -560,6 +839,7 @@ clicker!(DblClickBuilder, dblclick);
 
 macro_rules! check_builder {
   ($t:ident, $m:ident) => {
+    #[derive(Clone)]
     pub struct $t {
       inner: Weak<Impl>,
       args: CheckArgs,
@@ -586,18 +866,24 @@ macro_rules! check_builder {
         Self { inner, args }
       }
 
-      pub async fn $m(self) -> Result<(), Arc<Error>> {
-        let Self { inner, args } = self;
-        let _ = upgrade(&inner)?.$m(args).await?;
-        Ok(())
+      #[track_caller]
+      pub fn $m(self) -> impl Future<Output = Result<(), Arc<Error>>> {
+        api_call!(concat!("element_handle.", stringify!($m)), {
+          let Self { inner, args } = self;
+          let _ = upgrade(&inner)?.$m(args).await?;
+          Ok(())
+        })
       }
     }
+
+    into_future!($t, $m, Result<(), Arc<Error>>);
   };
 }
 
 check_builder!(CheckBuilder, check);
 check_builder!(UncheckBuilder, uncheck);
 
+#[derive(Clone)]
 pub struct TapBuilder {
   inner: Weak<Impl>,
   args: TapArgs,
@@ -628,19 +914,25 @@ impl TapBuilder {
     Self { inner, args }
   }
 
-  pub async fn tap(self) -> Result<(), Arc<Error>> {
-    let Self { inner, args } = self;
-    let _ = upgrade(&inner)?.tap(args).await?;
-    Ok(())
+  #[track_caller]
+  pub fn tap(self) -> impl Future<Output = Result<(), Arc<Error>>> {
+    api_call!("element_handle.tap", {
+      let Self { inner, args } = self;
+      let _ = upgrade(&inner)?.tap(args).await?;
+      Ok(())
+    })
   }
 }
 
-pub struct FillBuilder<'a> {
+into_future!(TapBuilder, tap, Result<(), Arc<Error>>);
+
+#[derive(Clone)]
+pub struct FillBuilder {
   inner: Weak<Impl>,
-  args: FillArgs<'a>,
+  args: FillArgs,
 }
 
-impl<'a> FillBuilder<'a> {
+impl FillBuilder {
   setter! {
       /// Actions that initiate navigations are waiting for these navigations to happen and for pages to start loading. You can
       /// opt out of waiting via setting this flag. You would only need this option in the exceptional cases such as navigating to
@@ -649,26 +941,32 @@ impl<'a> FillBuilder<'a> {
       timeout: Option<f64>
   }
 
-  pub(crate) fn new(inner: Weak<Impl>, value: &'a str) -> Self {
+  pub(crate) fn new(inner: Weak<Impl>, value: &str) -> Self {
     let args = FillArgs::new(value);
     Self { inner, args }
   }
 
-  pub async fn fill(self) -> Result<(), Arc<Error>> {
-    let Self { inner, args } = self;
-    let _ = upgrade(&inner)?.fill(args).await?;
-    Ok(())
+  #[track_caller]
+  pub fn fill(self) -> impl Future<Output = Result<(), Arc<Error>>> {
+    api_call!("element_handle.fill", {
+      let Self { inner, args } = self;
+      let _ = upgrade(&inner)?.fill(args).await?;
+      Ok(())
+    })
   }
 }
 
+into_future!(FillBuilder, fill, Result<(), Arc<Error>>);
+
 macro_rules! type_builder {
   ($t:ident, $a:ident, $f:ident, $m:ident) => {
-    pub struct $t<'a> {
+    #[derive(Clone)]
+    pub struct $t {
       inner: Weak<Impl>,
-      args: $a<'a>,
+      args: $a,
     }
 
-    impl<'a> $t<'a> {
+    impl $t {
       setter! {
           /// Time to wait between `keydown` and `keyup` in milliseconds. Defaults to 0.
           delay: Option<f64>,
@@ -679,38 +977,55 @@ macro_rules! type_builder {
           timeout: Option<f64>
       }
 
-      pub(crate) fn new(inner: Weak<Impl>, $f: &'a str) -> Self {
+      pub(crate) fn new(inner: Weak<Impl>, $f: &str) -> Self {
         let args = $a::new($f);
         Self { inner, args }
       }
 
-      pub async fn $m(self) -> Result<(), Arc<Error>> {
-        let Self { inner, args } = self;
-        let _ = upgrade(&inner)?.$m(args).await?;
-        Ok(())
+      #[track_caller]
+      pub fn $m(self) -> impl Future<Output = Result<(), Arc<Error>>> {
+        api_call!(concat!("element_handle.", stringify!($m)), {
+          let Self { inner, args } = self;
+          let _ = upgrade(&inner)?.$m(args).await?;
+          Ok(())
+        })
       }
     }
+
+    into_future!($t, $m, Result<(), Arc<Error>>);
   };
 }
 
 type_builder!(TypeBuilder, TypeArgs, text, r#type);
 type_builder!(PressBuilder, PressArgs, key, press);
 
-pub struct ScreenshotBuilder<'a> {
+#[derive(Clone)]
+pub struct ScreenshotBuilder {
   inner: Weak<Impl>,
-  args: ScreenshotArgs<'a>,
+  args: ScreenshotArgs,
 }
 
-impl<'a> ScreenshotBuilder<'a> {
+impl ScreenshotBuilder {
   setter! {
+      /// When set to `disabled`, stops CSS animations, CSS transitions and Web Animations. Finite animations are fast-forwarded to
+      /// completion, so they'll fire `transitionend` event. Infinite animations are canceled to initial state, and then played
+      /// over after the screenshot. Defaults to `allow` that leaves animations untouched.
+      animations: Option<ScreenshotAnimations>,
+      /// When set to `hide`, screenshot will hide text caret. When set to `initial`, text caret behavior will not be changed.
+      /// Defaults to `hide`.
+      caret: Option<ScreenshotCaret>,
       /// Hides default white background and allows capturing screenshots with transparency. Not applicable to `jpeg` images.
       /// Defaults to `false`.
       omit_background: Option<bool>,
       /// The file path to save the image to. The screenshot type will be inferred from file extension. If `path` is a relative
       /// path, then it is resolved relative to the current working directory. If no path is provided, the image won't be saved to
       /// the disk.
-      path: Option<&'a Path>,
+      path: Option<PathBuf>,
       quality: Option<i64>,
+      /// When set to `css`, screenshot will have a single pixel per each css pixel on the page. For high-dpi devices, this will
+      /// keep screenshots small. Using `device` option will produce a single pixel per each device pixel, so screenshots of
+      /// high-dpi devices will be twice as large or even larger. Defaults to `device`.
+      scale: Option<ScreenshotScale>,
       timeout: Option<f64>
   }
 
@@ -719,9 +1034,34 @@ impl<'a> ScreenshotBuilder<'a> {
     Self { inner, args }
   }
 
-  pub async fn screenshot(self) -> ArcResult<Vec<u8>> {
-    let Self { inner, args } = self;
-    upgrade(&inner)?.screenshot(args).await
+  #[track_caller]
+  pub fn screenshot(self) -> impl Future<Output = ArcResult<Vec<u8>>> {
+    api_call!("element_handle.screenshot", {
+      let Self { inner, args } = self;
+      upgrade(&inner)?.screenshot(args).await
+    })
+  }
+
+  /// Covers the elements matching `selector` in `frame` with a pink box
+  /// `#FF00FF` that completely covers their bounding box.
+  pub fn mask_selector(mut self, frame: &Frame, selector: &str) -> Self {
+    self
+      .args
+      .masks
+      .push(Mask::Selector(frame.inner(), selector.to_owned()));
+    self
+  }
+
+  /// Covers `element` with a pink box `#FF00FF` that completely covers its
+  /// bounding box.
+  pub fn mask_element(mut self, element: &ElementHandle) -> Self {
+    self.args.masks.push(Mask::Element(element.inner()));
+    self
+  }
+
+  pub fn clear_mask(mut self) -> Self {
+    self.args.masks = vec![];
+    self
   }
 
   /// Specify screenshot type, defaults to `png`.
@@ -736,33 +1076,42 @@ impl<'a> ScreenshotBuilder<'a> {
   }
 }
 
-pub struct WaitForSelectorBuilder<'a> {
+into_future!(ScreenshotBuilder, screenshot, ArcResult<Vec<u8>>);
+
+#[derive(Clone)]
+pub struct WaitForSelectorBuilder {
   inner: Weak<Impl>,
-  args: WaitForSelectorArgs<'a>,
+  args: WaitForSelectorArgs,
 }
 
-impl<'a> WaitForSelectorBuilder<'a> {
+impl WaitForSelectorBuilder {
   setter! {
       state: Option<WaitForSelectorState>,
       timeout: Option<f64>
   }
 
-  pub(crate) fn new(inner: Weak<Impl>, selector: &'a str) -> Self {
+  pub(crate) fn new(inner: Weak<Impl>, selector: &str) -> Self {
     let args = WaitForSelectorArgs::new(selector);
     Self { inner, args }
   }
 
-  pub async fn wait_for_selector(self) -> Result<(), Arc<Error>> {
-    let Self { inner, args } = self;
-    let _ = upgrade(&inner)?.wait_for_selector(args).await?;
-    Ok(())
+  #[track_caller]
+  pub fn wait_for_selector(self) -> impl Future<Output = Result<(), Arc<Error>>> {
+    api_call!("element_handle.wait_for_selector", {
+      let Self { inner, args } = self;
+      let _ = upgrade(&inner)?.wait_for_selector(args).await?;
+      Ok(())
+    })
   }
 }
 
+into_future!(WaitForSelectorBuilder, wait_for_selector, Result<(), Arc<Error>>);
+
+#[derive(Clone)]
 pub struct SelectOptionBuilder {
   inner: Weak<Impl>,
   args: SelectOptionArgs,
-  err: Option<Error>,
+  err: Option<Arc<Error>>,
 }
 
 impl SelectOptionBuilder {
@@ -783,12 +1132,15 @@ impl SelectOptionBuilder {
     }
   }
 
-  pub async fn select_option(self) -> Result<Vec<String>, Arc<Error>> {
-    let Self { inner, args, err } = self;
-    if let Some(e) = err {
-      return Err(e.into());
-    }
-    upgrade(&inner)?.select_option(args).await
+  #[track_caller]
+  pub fn select_option(self) -> impl Future<Output = Result<Vec<String>, Arc<Error>>> {
+    api_call!("element_handle.select_option", {
+      let Self { inner, args, err } = self;
+      if let Some(e) = err {
+        return Err(e);
+      }
+      upgrade(&inner)?.select_option(args).await
+    })
   }
 
   pub fn add_element(mut self, x: &ElementHandle) -> Self {
@@ -796,7 +1148,7 @@ impl SelectOptionBuilder {
       Ok(i) => i,
       Err(e) => {
         if self.err.is_none() {
-          self.err = Some(e);
+          self.err = Some(Arc::new(e));
         }
         return self;
       }
@@ -851,6 +1203,13 @@ impl SelectOptionBuilder {
   }
 }
 
+into_future!(
+  SelectOptionBuilder,
+  select_option,
+  Result<Vec<String>, Arc<Error>>
+);
+
+#[derive(Clone)]
 pub struct SetInputFilesBuilder {
   inner: Weak<Impl>,
   args: SetInputFilesArgs,
@@ -873,9 +1232,12 @@ impl SetInputFilesBuilder {
     Self { inner, args }
   }
 
-  pub async fn set_input_files(self) -> Result<(), Arc<Error>> {
-    let Self { inner, args } = self;
-    upgrade(&inner)?.set_input_files(args).await
+  #[track_caller]
+  pub fn set_input_files(self) -> impl Future<Output = Result<(), Arc<Error>>> {
+    api_call!("element_handle.set_input_files", {
+      let Self { inner, args } = self;
+      upgrade(&inner)?.set_input_files(args).await
+    })
   }
 
   pub fn add_file(mut self, x: File) -> Self {
@@ -889,6 +1251,56 @@ impl SetInputFilesBuilder {
   }
 }
 
+into_future!(SetInputFilesBuilder, set_input_files, Result<(), Arc<Error>>);
+
+#[derive(Clone)]
+pub struct SetInputFilePathsBuilder {
+  inner: Weak<Impl>,
+  args: SetInputFilePathsArgs,
+}
+
+impl SetInputFilePathsBuilder {
+  setter! {
+      /// Actions that initiate navigations are waiting for these navigations to happen and for pages to start loading. You can
+      /// opt out of waiting via setting this flag. You would only need this option in the exceptional cases such as navigating to
+      /// inaccessible pages. Defaults to `false`.
+      no_wait_after: Option<bool>,
+      timeout: Option<f64>
+  }
+
+  pub(crate) fn new<P: AsRef<Path>>(inner: Weak<Impl>, path: P) -> Self {
+    let args = SetInputFilePathsArgs {
+      paths: vec![path.as_ref().to_owned()],
+      ..SetInputFilePathsArgs::default()
+    };
+    Self { inner, args }
+  }
+
+  #[track_caller]
+  pub fn set_input_file_paths(self) -> impl Future<Output = Result<(), Arc<Error>>> {
+    api_call!("element_handle.set_input_file_paths", {
+      let Self { inner, args } = self;
+      upgrade(&inner)?.set_input_file_paths(args).await
+    })
+  }
+
+  pub fn add_path<P: AsRef<Path>>(mut self, x: P) -> Self {
+    self.args.paths.push(x.as_ref().to_owned());
+    self
+  }
+
+  pub fn clear_paths(mut self) -> Self {
+    self.args.paths = vec![];
+    self
+  }
+}
+
+into_future!(
+  SetInputFilePathsBuilder,
+  set_input_file_paths,
+  Result<(), Arc<Error>>
+);
+
 mod ser {
   use serde::ser;
   use serde::ser::SerializeStruct;
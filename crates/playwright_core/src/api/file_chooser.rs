@@ -1,3 +1,6 @@
+use std::path::Path;
+
+use crate::api::element_handle::SetInputFilePathsBuilder;
 use crate::api::element_handle::SetInputFilesBuilder;
 use crate::api::ElementHandle;
 use crate::api::Page;
@@ -6,17 +9,17 @@ use crate::imp::utils::File;
 
 impl FileChooser {
   /// Returns input element associated with this file chooser.
-  fn element(&self) -> ElementHandle {
-    ElementHandle::new(self.element_handle.clone())
+  pub fn element(&self) -> ElementHandle {
+    ElementHandle::borrowed(self.element_handle.clone())
   }
 
   /// Returns whether this file chooser accepts multiple files.
-  fn is_multiple(&self) -> bool {
+  pub fn is_multiple(&self) -> bool {
     self.is_multiple
   }
 
   /// Returns page this file chooser belongs to.
-  fn page(&self) -> Page {
+  pub fn page(&self) -> Page {
     Page::new(self.page.clone())
   }
 
@@ -24,7 +27,13 @@ impl FileChooser {
   /// of the `filePaths` are relative paths, then they are resolved relative
   /// to the the current working directory. For empty array, clears the selected
   /// files.
-  fn set_input_files_builder(&self, file: File) -> SetInputFilesBuilder {
+  pub fn set_input_files_builder(&self, file: File) -> SetInputFilesBuilder {
     SetInputFilesBuilder::new(self.element_handle.clone(), file)
   }
+
+  /// Sets the value of the file input to the files at `path`s. See
+  /// [`ElementHandle::set_input_file_paths_builder`].
+  pub fn set_input_file_paths_builder<P: AsRef<Path>>(&self, path: P) -> SetInputFilePathsBuilder {
+    SetInputFilePathsBuilder::new(self.element_handle.clone(), path)
+  }
 }
@@ -1,3 +1,5 @@
+use futures::TryFutureExt;
+
 use crate::api::ElementHandle;
 use crate::api::JsHandle;
 use crate::api::Page;
@@ -6,6 +8,7 @@ use crate::imp::core::*;
 use crate::imp::frame::AddScriptTagArgs;
 use crate::imp::frame::CheckArgs;
 use crate::imp::frame::ClickArgs;
+use crate::imp::frame::DragAndDropArgs;
 use crate::imp::frame::Evt;
 use crate::imp::frame::FillArgs;
 use crate::imp::frame::Frame as Impl;
@@ -18,6 +21,7 @@ pub use crate::imp::frame::Polling;
 use crate::imp::frame::PressArgs;
 use crate::imp::frame::SelectOptionArgs;
 use crate::imp::frame::SetContentArgs;
+use crate::imp::frame::SetInputFilePathsArgs;
 use crate::imp::frame::SetInputFilesArgs;
 use crate::imp::frame::TapArgs;
 use crate::imp::frame::TypeArgs;
@@ -29,6 +33,7 @@ use crate::imp::utils::File;
 use crate::imp::utils::KeyboardModifier;
 use crate::imp::utils::MouseButton;
 use crate::imp::utils::Position;
+use crate::imp::utils::UrlMatcher;
 use crate::protocol::generated::LifecycleEvent;
 
 /// At every point of time, page exposes its current frame tree via the
@@ -82,8 +87,15 @@ impl PartialEq for Frame {
 
 macro_rules! is_checked {
   ($f:ident) => {
-    pub async fn $f(&self, selector: &str, timeout: Option<f64>) -> ArcResult<bool> {
-      upgrade(&self.inner)?.$f(selector, timeout).await
+    #[track_caller]
+    pub fn $f<'a>(
+      &'a self,
+      selector: &'a str,
+      timeout: Option<f64>,
+    ) -> impl Future<Output = ArcResult<bool>> + 'a {
+      api_call!(concat!("frame.", stringify!($f)), {
+        upgrade(&self.inner)?.$f(selector, timeout).await
+      })
     }
   };
 }
@@ -107,6 +119,10 @@ impl Frame {
     Self { inner }
   }
 
+  pub(crate) fn inner(&self) -> Weak<Impl> {
+    self.inner.clone()
+  }
+
   pub fn url(&self) -> Result<String, Error> {
     Ok(upgrade(&self.inner)?.url())
   }
@@ -160,7 +176,7 @@ impl Frame {
   /// navigation to the same URL with a different hash, which would succeed and
   /// return `null`. > NOTE: Headless mode doesn't support navigation to a PDF
   /// document. See the [upstream issue](https://bugs.chromium.org/p/chromium/issues/detail?id=761295).
-  pub fn goto_builder<'a>(&self, url: &'a str) -> GotoBuilder<'a, '_> {
+  pub fn goto_builder(&self, url: &str) -> GotoBuilder {
     GotoBuilder::new(self.inner.clone(), url)
   }
 
@@ -175,7 +191,7 @@ impl Frame {
   ///
   /// When all steps combined have not finished during the specified `timeout`,
   /// this method throws a `TimeoutError`. Passing zero timeout disables this.
-  pub fn click_builder<'a>(&self, selector: &'a str) -> ClickBuilder<'a> {
+  pub fn click_builder(&self, selector: &str) -> ClickBuilder {
     ClickBuilder::new(self.inner.clone(), selector)
   }
 
@@ -195,7 +211,7 @@ impl Frame {
   ///
   /// > NOTE: `frame.dblclick()` dispatches two `click` events and a single
   /// `dblclick` event.
-  pub fn dblclick_builder<'a>(&self, selector: &'a str) -> DblClickBuilder<'a> {
+  pub fn dblclick_builder(&self, selector: &str) -> DblClickBuilder {
     DblClickBuilder::new(self.inner.clone(), selector)
   }
 
@@ -213,7 +229,7 @@ impl Frame {
   ///
   /// > NOTE: `frame.tap()` requires that the `hasTouch` option of the browser
   /// context be set to true.
-  pub fn tap_builder<'a>(&self, selector: &'a str) -> TapBuilder<'a> {
+  pub fn tap_builder(&self, selector: &str) -> TapBuilder {
     TapBuilder::new(self.inner.clone(), selector)
   }
 
@@ -228,60 +244,99 @@ impl Frame {
   ///
   /// To send fine-grained keyboard events, use
   /// [Frame::type_builder](Frame::type_builder).
-  pub fn fill_builder<'a, 'b>(&self, selector: &'a str, value: &'b str) -> FillBuilder<'a, 'b> {
+  pub fn fill_builder(&self, selector: &str, value: &str) -> FillBuilder {
     FillBuilder::new(self.inner.clone(), selector, value)
   }
 
   /// This method fetches an element with `selector` and focuses it. If there's
   /// no element matching `selector`, the method waits until a matching
   /// element appears in the DOM.
-  pub async fn focus(&self, selector: &str, timeout: Option<f64>) -> ArcResult<()> {
-    upgrade(&self.inner)?.focus(selector, timeout).await
+  #[track_caller]
+  pub fn focus<'a>(
+    &'a self,
+    selector: &'a str,
+    timeout: Option<f64>,
+  ) -> impl Future<Output = ArcResult<()>> + 'a {
+    api_call!("frame.focus", {
+      upgrade(&self.inner)?.focus(selector, timeout).await
+    })
   }
 
   /// Returns `element.textContent`.
-  pub async fn text_content(
-    &self,
-    selector: &str,
+  #[track_caller]
+  pub fn text_content<'a>(
+    &'a self,
+    selector: &'a str,
     timeout: Option<f64>,
-  ) -> ArcResult<Option<String>> {
-    upgrade(&self.inner)?.text_content(selector, timeout).await
+  ) -> impl Future<Output = ArcResult<Option<String>>> + 'a {
+    api_call!("frame.text_content", {
+      upgrade(&self.inner)?.text_content(selector, timeout).await
+    })
   }
 
   /// Returns `element.innerText`.
-  pub async fn inner_text(&self, selector: &str, timeout: Option<f64>) -> ArcResult<String> {
-    upgrade(&self.inner)?.inner_text(selector, timeout).await
+  #[track_caller]
+  pub fn inner_text<'a>(
+    &'a self,
+    selector: &'a str,
+    timeout: Option<f64>,
+  ) -> impl Future<Output = ArcResult<String>> + 'a {
+    api_call!("frame.inner_text", {
+      upgrade(&self.inner)?.inner_text(selector, timeout).await
+    })
   }
 
   /// Returns `element.innerHTML`.
-  pub async fn inner_html(&self, selector: &str, timeout: Option<f64>) -> ArcResult<String> {
-    upgrade(&self.inner)?.inner_html(selector, timeout).await
+  #[track_caller]
+  pub fn inner_html<'a>(
+    &'a self,
+    selector: &'a str,
+    timeout: Option<f64>,
+  ) -> impl Future<Output = ArcResult<String>> + 'a {
+    api_call!("frame.inner_html", {
+      upgrade(&self.inner)?.inner_html(selector, timeout).await
+    })
   }
 
   /// Returns element attribute value.
-  pub async fn get_attribute(
-    &self,
-    selector: &str,
-    name: &str,
+  #[track_caller]
+  pub fn get_attribute<'a>(
+    &'a self,
+    selector: &'a str,
+    name: &'a str,
     timeout: Option<f64>,
-  ) -> ArcResult<Option<String>> {
-    upgrade(&self.inner)?
-      .get_attribute(selector, name, timeout)
-      .await
-  }
-
-  pub async fn query_selector(&self, selector: &str) -> ArcResult<Option<ElementHandle>> {
-    Ok(
+  ) -> impl Future<Output = ArcResult<Option<String>>> + 'a {
+    api_call!("frame.get_attribute", {
       upgrade(&self.inner)?
-        .query_selector(selector)
-        .await?
-        .map(ElementHandle::new),
-    )
+        .get_attribute(selector, name, timeout)
+        .await
+    })
   }
 
-  pub async fn query_selector_all(&self, selector: &str) -> ArcResult<Vec<ElementHandle>> {
-    let es = upgrade(&self.inner)?.query_selector_all(selector).await?;
-    Ok(es.into_iter().map(ElementHandle::new).collect())
+  #[track_caller]
+  pub fn query_selector<'a>(
+    &'a self,
+    selector: &'a str,
+  ) -> impl Future<Output = ArcResult<Option<ElementHandle>>> + 'a {
+    api_call!("frame.query_selector", {
+      Ok(
+        upgrade(&self.inner)?
+          .query_selector(selector)
+          .await?
+          .map(ElementHandle::new),
+      )
+    })
+  }
+
+  #[track_caller]
+  pub fn query_selector_all<'a>(
+    &'a self,
+    selector: &'a str,
+  ) -> impl Future<Output = ArcResult<Vec<ElementHandle>>> + 'a {
+    api_call!("frame.query_selector_all", {
+      let es = upgrade(&self.inner)?.query_selector_all(selector).await?;
+      Ok(es.into_iter().map(ElementHandle::new).collect())
+    })
   }
 
   /// Returns the `frame` or `iframe` element handle which corresponds to this
@@ -298,10 +353,13 @@ impl Frame {
   /// const contentFrame = await frameElement.contentFrame();
   /// console.log(frame === contentFrame);  // -> true
   /// ```
-  pub async fn frame_element(&self) -> ArcResult<ElementHandle> {
-    Ok(ElementHandle::new(
-      upgrade(&self.inner)?.frame_element().await?,
-    ))
+  #[track_caller]
+  pub fn frame_element<'a>(&'a self) -> impl Future<Output = ArcResult<ElementHandle>> + 'a {
+    api_call!("frame.frame_element", {
+      Ok(ElementHandle::new(
+        upgrade(&self.inner)?.frame_element().await?,
+      ))
+    })
   }
 
   /// Returns when element specified by selector satisfies `state` option.
@@ -329,12 +387,24 @@ impl Frame {
   ///  await browser.close();
   /// })();
   /// ```
-  pub fn wait_for_selector_builder<'a>(&self, selector: &'a str) -> WaitForSelectorBuilder<'a> {
+  pub fn wait_for_selector_builder(&self, selector: &str) -> WaitForSelectorBuilder {
     WaitForSelectorBuilder::new(self.inner.clone(), selector)
   }
 
-  pub async fn title(&self) -> ArcResult<String> {
-    upgrade(&self.inner)?.title().await
+  /// Highlights the elements matching `selector` on the page, as the
+  /// inspector does. Highlights stay until [`Playwright::hide_highlight`].
+  ///
+  /// [`Playwright::hide_highlight`]: crate::api::Playwright::hide_highlight
+  #[track_caller]
+  pub fn highlight<'a>(&'a self, selector: &'a str) -> impl Future<Output = ArcResult<()>> + 'a {
+    api_call!("frame.highlight", {
+      upgrade(&self.inner)?.highlight(selector).await
+    })
+  }
+
+  #[track_caller]
+  pub fn title<'a>(&'a self) -> impl Future<Output = ArcResult<String>> + 'a {
+    api_call!("frame.title", { upgrade(&self.inner)?.title().await })
   }
 
   /// Sends a `keydown`, `keypress`/`input`, and `keyup` event for each
@@ -349,7 +419,7 @@ impl Frame {
   /// await frame.type('#mytextarea', 'Hello'); // Types instantly
   /// await frame.type('#mytextarea', 'World', {delay: 100}); // Types slower, like a user
   /// ```
-  pub fn type_builder<'a, 'b>(&self, selector: &'a str, text: &'b str) -> TypeBuilder<'a, 'b> {
+  pub fn type_builder(&self, selector: &str, text: &str) -> TypeBuilder {
     TypeBuilder::new(self.inner.clone(), selector, text)
   }
 
@@ -374,7 +444,7 @@ impl Frame {
   /// Shortcuts such as `key: "Control+o"` or `key: "Control+Shift+T"` are
   /// supported as well. When specified with the modifier, modifier is pressed
   /// and being held while the subsequent key is being pressed.
-  pub fn press_builder<'a, 'b>(&self, selector: &'a str, key: &'b str) -> PressBuilder<'a, 'b> {
+  pub fn press_builder(&self, selector: &str, key: &str) -> PressBuilder {
     PressBuilder::new(self.inner.clone(), selector, key)
   }
 
@@ -389,16 +459,24 @@ impl Frame {
   ///
   /// When all steps combined have not finished during the specified `timeout`,
   /// this method throws a `TimeoutError`. Passing zero timeout disables this.
-  pub fn hover_builder<'a>(&self, selector: &'a str) -> HoverBuilder<'a> {
+  pub fn hover_builder(&self, selector: &str) -> HoverBuilder {
     HoverBuilder::new(self.inner.clone(), selector)
   }
 
+  /// Drags the element matching `source` and drops it onto the element
+  /// matching `target`. Both are hovered as with [`Frame::hover_builder`],
+  /// the mouse being pressed over the first and released over the second.
+  pub fn drag_and_drop_builder(&self, source: &str, target: &str) -> DragAndDropBuilder {
+    DragAndDropBuilder::new(self.inner.clone(), source, target)
+  }
+
   /// Gets the full HTML contents of the frame, including the doctype.
-  pub async fn content<'a>(&self) -> ArcResult<String> {
-    upgrade(&self.inner)?.content().await
+  #[track_caller]
+  pub fn content<'a>(&'a self) -> impl Future<Output = ArcResult<String>> + 'a {
+    api_call!("frame.content", { upgrade(&self.inner)?.content().await })
   }
 
-  pub fn set_content_builder<'a>(&self, html: &'a str) -> SetContentBuilder<'a> {
+  pub fn set_content_builder(&self, html: &str) -> SetContentBuilder {
     SetContentBuilder::new(self.inner.clone(), html)
   }
 
@@ -417,7 +495,7 @@ impl Frame {
   ///
   /// When all steps combined have not finished during the specified `timeout`,
   /// this method throws a `TimeoutError`. Passing zero timeout disables this.
-  pub fn check_builder<'a>(&self, selector: &'a str) -> CheckBuilder<'a> {
+  pub fn check_builder(&self, selector: &str) -> CheckBuilder {
     CheckBuilder::new(self.inner.clone(), selector)
   }
 
@@ -436,13 +514,16 @@ impl Frame {
   ///
   /// When all steps combined have not finished during the specified `timeout`,
   /// this method throws a `TimeoutError`. Passing zero timeout disables this.
-  pub fn uncheck_builder<'a>(&self, selector: &'a str) -> UncheckBuilder<'a> {
+  pub fn uncheck_builder(&self, selector: &str) -> UncheckBuilder {
     UncheckBuilder::new(self.inner.clone(), selector)
   }
 
   // = |timeout| async { sleep(timeout).await }
-  pub async fn wait_for_timeout(&self, timeout: f64) {
-    sleep(std::time::Duration::from_millis(timeout as u64)).await
+  #[track_caller]
+  pub fn wait_for_timeout<'a>(&'a self, timeout: f64) -> impl Future<Output = ()> + 'a {
+    api_call!("frame.wait_for_timeout", {
+      sleep(std::time::Duration::from_millis(timeout as u64)).await
+    })
   }
 
   /// Returns the added tag when the stylesheet's onload fires or when the CSS
@@ -450,33 +531,44 @@ impl Frame {
   ///
   /// Adds a `<link rel="stylesheet">` tag into the page with the desired url or
   /// a `<style type="text/css">` tag with the content.
-  pub async fn add_style_tag(&self, content: &str, url: Option<&str>) -> ArcResult<ElementHandle> {
-    upgrade(&self.inner)?
-      .add_style_tag(content, url)
-      .await
-      .map(ElementHandle::new)
+  #[track_caller]
+  pub fn add_style_tag<'a>(
+    &'a self,
+    content: &'a str,
+    url: Option<&'a str>,
+  ) -> impl Future<Output = ArcResult<ElementHandle>> + 'a {
+    api_call!("frame.add_style_tag", {
+      upgrade(&self.inner)?
+        .add_style_tag(content, url)
+        .await
+        .map(ElementHandle::new)
+    })
   }
 
   /// Returns the added tag when the script's onload fires or when the script
   /// content was injected into frame.
   ///
   /// Adds a `<script>` tag into the page with the desired url or content.
-  pub fn add_script_tag_builder<'a>(&self, content: &'a str) -> AddScriptTagBuilder<'a, '_, '_> {
+  pub fn add_script_tag_builder(&self, content: &str) -> AddScriptTagBuilder {
     AddScriptTagBuilder::new(self.inner.clone(), content)
   }
 
-  pub async fn evaluate_element_handle<T>(
-    &self,
-    expression: &str,
+  #[track_caller]
+  pub fn evaluate_element_handle<'a, T>(
+    &'a self,
+    expression: &'a str,
     args: Option<T>,
-  ) -> ArcResult<ElementHandle>
+  ) -> impl Future<Output = ArcResult<ElementHandle>> + 'a
   where
     T: Serialize,
+    T: 'a,
   {
-    upgrade(&self.inner)?
-      .evaluate_element_handle(expression, args)
-      .await
-      .map(ElementHandle::new)
+    api_call!("frame.evaluate_element_handle", {
+      upgrade(&self.inner)?
+        .evaluate_element_handle(expression, args)
+        .await
+        .map(ElementHandle::new)
+    })
   }
 
   /// Returns the return value of `expression` as a `JSHandle`.
@@ -506,21 +598,33 @@ impl Frame {
   /// body.innerHTML + suffix, [aHandle, 'hello']); console.log(await
   /// resultHandle.jsonValue()); await resultHandle.dispose();
   /// ```
-  pub async fn evaluate_js_handle<T>(&self, expression: &str, arg: Option<T>) -> ArcResult<JsHandle>
+  #[track_caller]
+  pub fn evaluate_js_handle<'a, T>(
+    &'a self,
+    expression: &'a str,
+    arg: Option<T>,
+  ) -> impl Future<Output = ArcResult<JsHandle>> + 'a
   where
     T: Serialize,
+    T: 'a,
   {
-    upgrade(&self.inner)?
-      .evaluate_js_handle(expression, arg)
-      .await
-      .map(JsHandle::new)
+    api_call!("frame.evaluate_js_handle", {
+      upgrade(&self.inner)?
+        .evaluate_js_handle(expression, arg)
+        .await
+        .map(JsHandle::new)
+    })
   }
 
-  pub async fn eval<U>(&self, expression: &str) -> ArcResult<U>
+  #[track_caller]
+  pub fn eval<'a, U>(&'a self, expression: &'a str) -> impl Future<Output = ArcResult<U>> + 'a
   where
     U: DeserializeOwned,
+    U: 'a,
   {
-    upgrade(&self.inner)?.eval(expression).await
+    api_call!("frame.eval", {
+      upgrade(&self.inner)?.eval(expression).await
+    })
   }
 
   /// Returns the return value of `expression`.
@@ -550,12 +654,21 @@ impl Frame {
   /// const html = await frame.evaluate(([body, suffix]) => body.innerHTML + suffix, [bodyHandle, 'hello']);
   /// await bodyHandle.dispose();
   /// ```
-  pub async fn evaluate<T, U>(&self, expression: &str, arg: T) -> ArcResult<U>
+  #[track_caller]
+  pub fn evaluate<'a, T, U>(
+    &'a self,
+    expression: &'a str,
+    arg: T,
+  ) -> impl Future<Output = ArcResult<U>> + 'a
   where
     T: Serialize,
     U: DeserializeOwned,
+    T: 'a,
+    U: 'a,
   {
-    upgrade(&self.inner)?.evaluate(expression, Some(arg)).await
+    api_call!("frame.evaluate", {
+      upgrade(&self.inner)?.evaluate(expression, Some(arg)).await
+    })
   }
 
   /// Returns the return value of `expression`.
@@ -574,19 +687,24 @@ impl Frame {
   /// const preloadHref = await frame.$eval('link[rel=preload]', el => el.href);
   /// const html = await frame.$eval('.main-container', (e, suffix) => e.outerHTML + suffix, 'hello');
   /// ```
-  pub async fn evaluate_on_selector<T, U>(
-    &self,
-    selector: &str,
-    expression: &str,
+  #[track_caller]
+  pub fn evaluate_on_selector<'a, T, U>(
+    &'a self,
+    selector: &'a str,
+    expression: &'a str,
     arg: Option<T>,
-  ) -> ArcResult<U>
+  ) -> impl Future<Output = ArcResult<U>> + 'a
   where
     T: Serialize,
     U: DeserializeOwned,
+    T: 'a,
+    U: 'a,
   {
-    upgrade(&self.inner)?
-      .evaluate_on_selector(selector, expression, arg)
-      .await
+    api_call!("frame.evaluate_on_selector", {
+      upgrade(&self.inner)?
+        .evaluate_on_selector(selector, expression, arg)
+        .await
+    })
   }
 
   /// Returns the return value of `expression`.
@@ -604,19 +722,24 @@ impl Frame {
   /// ```js
   /// const divsCounts = await frame.$$eval('div', (divs, min) => divs.length >= min, 10);
   /// ```
-  pub async fn evaluate_on_selector_all<T, U>(
-    &self,
-    selector: &str,
-    expression: &str,
+  #[track_caller]
+  pub fn evaluate_on_selector_all<'a, T, U>(
+    &'a self,
+    selector: &'a str,
+    expression: &'a str,
     arg: Option<T>,
-  ) -> ArcResult<U>
+  ) -> impl Future<Output = ArcResult<U>> + 'a
   where
     T: Serialize,
     U: DeserializeOwned,
+    T: 'a,
+    U: 'a,
   {
-    upgrade(&self.inner)?
-      .evaluate_on_selector_all(selector, expression, arg)
-      .await
+    api_call!("frame.evaluate_on_selector_all", {
+      upgrade(&self.inner)?
+        .evaluate_on_selector_all(selector, expression, arg)
+        .await
+    })
   }
 
   /// The snippet below dispatches the `click` event on the element. Regardless
@@ -649,19 +772,23 @@ impl Frame {
   /// const dataTransfer = await frame.evaluateHandle(() => new DataTransfer());
   /// await frame.dispatchEvent('#source', 'dragstart', { dataTransfer });
   /// ```
-  pub async fn dispatch_event<T>(
-    &self,
-    selector: &str,
-    r#type: &str,
+  #[track_caller]
+  pub fn dispatch_event<'a, T>(
+    &'a self,
+    selector: &'a str,
+    r#type: &'a str,
     event_init: Option<T>,
-  ) -> ArcResult<()>
+  ) -> impl Future<Output = ArcResult<()>> + 'a
   where
     T: Serialize,
+    T: 'a,
   {
-    // timeout not supported
-    upgrade(&self.inner)?
-      .dispatch_event(selector, r#type, event_init)
-      .await
+    api_call!("frame.dispatch_event", {
+      // timeout not supported
+      upgrade(&self.inner)?
+        .dispatch_event(selector, r#type, event_init)
+        .await
+    })
   }
 
   /// This method waits for an element matching `selector`, waits for [actionability](https://playwright.dev/docs/actionability/) checks, waits until
@@ -685,7 +812,7 @@ impl Frame {
   ///// multiple selection
   /// frame.selectOption('select#colors', 'red', 'green', 'blue');
   /// ```
-  pub fn select_option_builder<'a>(&self, selector: &'a str) -> SelectOptionBuilder<'a> {
+  pub fn select_option_builder(&self, selector: &str) -> SelectOptionBuilder {
     SelectOptionBuilder::new(self.inner.clone(), selector)
   }
 
@@ -696,14 +823,22 @@ impl Frame {
   /// the `filePaths` are relative paths, then they are resolved relative to
   /// the the current working directory. For empty array, clears the selected
   /// files.
-  pub fn set_input_files_builder<'a>(
-    &self,
-    selector: &'a str,
-    file: File,
-  ) -> SetInputFilesBuilder<'a> {
+  pub fn set_input_files_builder(&self, selector: &str, file: File) -> SetInputFilesBuilder {
     SetInputFilesBuilder::new(self.inner.clone(), selector, file)
   }
 
+  /// Like [`Frame::set_input_files_builder`] but with files read from
+  /// `path`s, relative ones being resolved against the current working
+  /// directory. When connected to a remote browser the files are streamed to
+  /// it instead of being loaded into memory.
+  pub fn set_input_file_paths_builder<P: AsRef<Path>>(
+    &self,
+    selector: &str,
+    path: P,
+  ) -> SetInputFilePathsBuilder {
+    SetInputFilePathsBuilder::new(self.inner.clone(), selector, path)
+  }
+
   /// Returns when the `expression` returns a truthy value, returns that value.
   ///
   /// The [`method: Frame.waitForFunction`] can be used to observe viewport size
@@ -721,11 +856,77 @@ impl Frame {
   ///  await browser.close();
   /// })();
   /// ```
-  pub fn wait_for_function_builder<'a>(&self, expression: &'a str) -> WaitForFunctionBuilder<'a> {
+  pub fn wait_for_function_builder(&self, expression: &str) -> WaitForFunctionBuilder {
     WaitForFunctionBuilder::new(self.inner.clone(), expression)
   }
 
-  // wait_for_url
+  /// Waits for the frame to reach `state`, `load` by default. Returns
+  /// immediately when the state has already been reached.
+  #[track_caller]
+  pub fn wait_for_load_state<'a>(
+    &'a self,
+    state: Option<DocumentLoadState>,
+    timeout: Option<f64>,
+  ) -> impl Future<Output = ArcResult<()>> + 'a {
+    api_call!("frame.wait_for_load_state", {
+      let state = state.unwrap_or(DocumentLoadState::Load);
+      upgrade(&self.inner)?
+        .wait_for_load_state(state, timeout)
+        .await
+    })
+  }
+
+  /// Waits for the frame to navigate to a URL matching `url` and reach
+  /// `wait_until`, `load` by default. Pushing history state counts as a
+  /// navigation, so this also works for single page applications.
+  ///
+  /// ```js
+  /// await frame.click('a.delayed-navigation'); // Clicking the link will indirectly cause a navigation
+  /// await frame.waitForURL('**/target.html');
+  /// ```
+  #[track_caller]
+  pub fn wait_for_url<'a, U>(
+    &'a self,
+    url: U,
+    wait_until: Option<DocumentLoadState>,
+    timeout: Option<f64>,
+  ) -> impl Future<Output = ArcResult<()>> + 'a
+  where
+    U: Into<UrlMatcher>,
+    U: 'a,
+  {
+    api_call!("frame.wait_for_url", {
+      let wait_until = wait_until.unwrap_or(DocumentLoadState::Load);
+      upgrade(&self.inner)?
+        .wait_for_url(&url.into(), wait_until, timeout)
+        .await
+    })
+  }
+
+  /// Runs `action` and waits for the navigation it causes to reach `load`.
+  /// Returns the main resource response, or `None` for navigations within
+  /// the same document.
+  #[track_caller]
+  pub fn expect_navigation<'a, F, T, E>(
+    &'a self,
+    action: F,
+  ) -> impl Future<Output = ArcResult<Option<Response>>> + 'a
+  where
+    F: Future<Output = Result<T, E>>,
+    E: Into<Arc<Error>>,
+    F: 'a,
+    T: 'a,
+    E: 'a,
+  {
+    api_call!("frame.expect_navigation", {
+      let inner = upgrade(&self.inner)?;
+      let rx = inner.subscribe_event();
+      let wait = inner.wait_for_navigation(rx, None, DocumentLoadState::Load, None);
+      let action = ApiCall::unscoped(action.map_err(Into::into));
+      let (response, _) = futures::try_join!(wait, action)?;
+      Ok(response.map(Response::new))
+    })
+  }
 }
 
 #[derive(Debug)]
@@ -743,40 +944,59 @@ impl From<Evt> for Event {
   }
 }
 
-pub struct GotoBuilder<'a, 'b> {
+#[derive(Clone)]
+pub struct GotoBuilder {
   inner: Weak<Impl>,
-  args: GotoArgs<'a, 'b>,
+  api_name: &'static str,
+  args: GotoArgs,
 }
 
-impl<'a, 'b> GotoBuilder<'a, 'b> {
+impl GotoBuilder {
   setter! {
       /// Referer header value. If provided it will take preference over the referer header value set by
       /// [`method: Page.setExtraHTTPHeaders`].
-      referer: Option<&'b str>,
+      referer: Option<String>,
       timeout: Option<f64>,
       wait_until: Option<DocumentLoadState>
   }
 
-  pub(crate) fn new(inner: Weak<Impl>, url: &'a str) -> Self {
+  pub(crate) fn new(inner: Weak<Impl>, url: &str) -> Self {
     let args = GotoArgs::new(url);
-    Self { inner, args }
+    Self {
+      inner,
+      args,
+      api_name: "frame.goto",
+    }
+  }
+
+  /// Reports the call as `api_name`, for the `Page` shortcuts
+  pub(crate) fn api_name(mut self, api_name: &'static str) -> Self {
+    self.api_name = api_name;
+    self
   }
 
-  pub async fn goto(self) -> Result<Option<Response>, Arc<Error>> {
-    let Self { inner, args } = self;
-    let r = upgrade(&inner)?.goto(args).await?;
-    Ok(r.map(Response::new))
+  #[track_caller]
+  pub fn goto(self) -> impl Future<Output = Result<Option<Response>, Arc<Error>>> {
+    api_call!(self.api_name, {
+      let Self { inner, args, .. } = self;
+      let r = upgrade(&inner)?.goto(args).await?;
+      Ok(r.map(Response::new))
+    })
   }
 }
 
+into_future!(GotoBuilder, goto, Result<Option<Response>, Arc<Error>>);
+
 macro_rules! clicker {
   ($t:ident, $f:ident) => {
-    pub struct $t<'a> {
+    #[derive(Clone)]
+    pub struct $t {
       inner: Weak<Impl>,
-      args: ClickArgs<'a>,
+      api_name: &'static str,
+      args: ClickArgs,
     }
 
-    impl<'a> $t<'a> {
+    impl $t {
       setter! {
           /// Defaults to `left`.
           button: Option<MouseButton>,
@@ -802,55 +1022,95 @@ macro_rules! clicker {
           trial: Option<bool>
       }
 
-      pub(crate) fn new(inner: Weak<Impl>, selector: &'a str) -> Self {
+      pub(crate) fn new(inner: Weak<Impl>, selector: &str) -> Self {
         let args = ClickArgs::new(selector);
-        Self { inner, args }
+        Self {
+          inner,
+          args,
+          api_name: concat!("frame.", stringify!($f)),
+        }
       }
 
-      pub async fn $f(self) -> Result<(), Arc<Error>> {
-        let Self { inner, args } = self;
-        let _ = upgrade(&inner)?.$f(args).await?;
-        Ok(())
+      /// Reports the call as `api_name`, for the `Page` shortcuts
+      pub(crate) fn api_name(mut self, api_name: &'static str) -> Self {
+        self.api_name = api_name;
+        self
+      }
+
+      #[track_caller]
+      pub fn $f(self) -> impl Future<Output = Result<(), Arc<Error>>> {
+        api_call!(self.api_name, {
+          let Self { inner, args, .. } = self;
+          let _ = upgrade(&inner)?.$f(args).await?;
+          Ok(())
+        })
       }
     }
+
+    into_future!($t, $f, Result<(), Arc<Error>>);
   };
 }
 
 clicker!(ClickBuilder, click);
 clicker!(DblClickBuilder, dblclick);
 
-pub struct WaitForSelectorBuilder<'a> {
+#[derive(Clone)]
+pub struct WaitForSelectorBuilder {
   inner: Weak<Impl>,
-  args: WaitForSelectorArgs<'a>,
+  api_name: &'static str,
+  args: WaitForSelectorArgs,
 }
 
-impl<'a> WaitForSelectorBuilder<'a> {
+impl WaitForSelectorBuilder {
   setter! {
       /// Defaults to `'visible'`.
       state: Option<FrameState>,
       timeout: Option<f64>
   }
 
-  pub(crate) fn new(inner: Weak<Impl>, selector: &'a str) -> Self {
+  pub(crate) fn new(inner: Weak<Impl>, selector: &str) -> Self {
     let args = WaitForSelectorArgs::new(selector);
-    Self { inner, args }
+    Self {
+      inner,
+      args,
+      api_name: "frame.wait_for_selector",
+    }
+  }
+
+  /// Reports the call as `api_name`, for the `Page` shortcuts
+  pub(crate) fn api_name(mut self, api_name: &'static str) -> Self {
+    self.api_name = api_name;
+    self
   }
 
-  pub async fn wait_for_selector(self) -> Result<Option<ElementHandle>, Arc<Error>> {
-    let Self { inner, args } = self;
-    let e = upgrade(&inner)?.wait_for_selector(args).await?;
-    Ok(e.map(ElementHandle::new))
+  #[track_caller]
+  pub fn wait_for_selector(
+    self,
+  ) -> impl Future<Output = Result<Option<ElementHandle>, Arc<Error>>> {
+    api_call!(self.api_name, {
+      let Self { inner, args, .. } = self;
+      let e = upgrade(&inner)?.wait_for_selector(args).await?;
+      Ok(e.map(ElementHandle::new))
+    })
   }
 }
 
+into_future!(
+  WaitForSelectorBuilder,
+  wait_for_selector,
+  Result<Option<ElementHandle>, Arc<Error>>
+);
+
 macro_rules! type_builder {
   ($t:ident, $a:ident, $f:ident, $m:ident) => {
-    pub struct $t<'a, 'b> {
+    #[derive(Clone)]
+    pub struct $t {
       inner: Weak<Impl>,
-      args: $a<'a, 'b>,
+      api_name: &'static str,
+      args: $a,
     }
 
-    impl<'a, 'b> $t<'a, 'b> {
+    impl $t {
       setter! {
           /// Time to wait between `keydown` and `keyup` in milliseconds. Defaults to 0.
           delay: Option<f64>,
@@ -861,29 +1121,46 @@ macro_rules! type_builder {
           timeout: Option<f64>
       }
 
-      pub(crate) fn new(inner: Weak<Impl>, selector: &'a str, $f: &'b str) -> Self {
+      pub(crate) fn new(inner: Weak<Impl>, selector: &str, $f: &str) -> Self {
         let args = $a::new(selector, $f);
-        Self { inner, args }
+        Self {
+          inner,
+          args,
+          api_name: concat!("frame.", stringify!($m)),
+        }
+      }
+
+      /// Reports the call as `api_name`, for the `Page` shortcuts
+      pub(crate) fn api_name(mut self, api_name: &'static str) -> Self {
+        self.api_name = api_name;
+        self
       }
 
-      pub async fn $m(self) -> Result<(), Arc<Error>> {
-        let Self { inner, args } = self;
-        let _ = upgrade(&inner)?.$m(args).await?;
-        Ok(())
+      #[track_caller]
+      pub fn $m(self) -> impl Future<Output = Result<(), Arc<Error>>> {
+        api_call!(self.api_name, {
+          let Self { inner, args, .. } = self;
+          let _ = upgrade(&inner)?.$m(args).await?;
+          Ok(())
+        })
       }
     }
+
+    into_future!($t, $m, Result<(), Arc<Error>>);
   };
 }
 
 type_builder!(TypeBuilder, TypeArgs, text, r#type);
 type_builder!(PressBuilder, PressArgs, key, press);
 
-pub struct HoverBuilder<'a> {
+#[derive(Clone)]
+pub struct HoverBuilder {
   inner: Weak<Impl>,
-  args: HoverArgs<'a>,
+  api_name: &'static str,
+  args: HoverArgs,
 }
 
-impl<'a> HoverBuilder<'a> {
+impl HoverBuilder {
   setter! {
       /// Whether to bypass the actionability checks. Defaults to `false`.
       force: Option<bool>,
@@ -899,45 +1176,141 @@ impl<'a> HoverBuilder<'a> {
       trial: Option<bool>
   }
 
-  pub(crate) fn new(inner: Weak<Impl>, selector: &'a str) -> Self {
+  pub(crate) fn new(inner: Weak<Impl>, selector: &str) -> Self {
     let args = HoverArgs::new(selector);
-    Self { inner, args }
+    Self {
+      inner,
+      args,
+      api_name: "frame.hover",
+    }
+  }
+
+  /// Reports the call as `api_name`, for the `Page` shortcuts
+  pub(crate) fn api_name(mut self, api_name: &'static str) -> Self {
+    self.api_name = api_name;
+    self
+  }
+
+  #[track_caller]
+  pub fn hover(self) -> impl Future<Output = Result<(), Arc<Error>>> {
+    api_call!(self.api_name, {
+      let Self { inner, args, .. } = self;
+      upgrade(&inner)?.hover(args).await
+    })
   }
 
-  pub async fn goto(self) -> Result<(), Arc<Error>> {
-    let Self { inner, args } = self;
-    upgrade(&inner)?.hover(args).await
+  #[deprecated(note = "renamed to `hover`")]
+  #[track_caller]
+  pub fn goto(self) -> impl Future<Output = Result<(), Arc<Error>>> {
+    self.hover()
   }
 }
 
-pub struct SetContentBuilder<'a> {
+into_future!(HoverBuilder, hover, Result<(), Arc<Error>>);
+
+#[derive(Clone)]
+pub struct DragAndDropBuilder {
   inner: Weak<Impl>,
-  args: SetContentArgs<'a>,
+  api_name: &'static str,
+  args: DragAndDropArgs,
 }
 
-impl<'a> SetContentBuilder<'a> {
+impl DragAndDropBuilder {
+  setter! {
+      /// Whether to bypass the actionability checks. Defaults to `false`.
+      force: Option<bool>,
+      /// Actions that initiate navigations are waiting for these navigations to happen and for pages to start loading. You can
+      /// opt out of waiting via setting this flag. You would only need this option in the exceptional cases such as navigating to
+      /// inaccessible pages. Defaults to `false`.
+      no_wait_after: Option<bool>,
+      /// Clicks on the source element at this point relative to the top-left corner of the element's padding box. If not
+      /// specified, some visible point of the element is used.
+      source_position: Option<Position>,
+      /// When true, the call requires selectors to resolve to a single element. If given selectors resolve to more than one
+      /// element, the call throws an exception.
+      strict: Option<bool>,
+      /// Drops on the target element at this point relative to the top-left corner of the element's padding box. If not
+      /// specified, some visible point of the element is used.
+      target_position: Option<Position>,
+      timeout: Option<f64>,
+      /// When set, this method only performs the [actionability](https://playwright.dev/docs/actionability/) checks and skips the action. Defaults to
+      /// `false`. Useful to wait until the element is ready for the action without performing it.
+      trial: Option<bool>
+  }
+
+  pub(crate) fn new(inner: Weak<Impl>, source: &str, target: &str) -> Self {
+    let args = DragAndDropArgs::new(source, target);
+    Self {
+      inner,
+      args,
+      api_name: "frame.drag_and_drop",
+    }
+  }
+
+  /// Reports the call as `api_name`, for the `Page` shortcuts
+  pub(crate) fn api_name(mut self, api_name: &'static str) -> Self {
+    self.api_name = api_name;
+    self
+  }
+
+  #[track_caller]
+  pub fn drag_and_drop(self) -> impl Future<Output = Result<(), Arc<Error>>> {
+    api_call!(self.api_name, {
+      let Self { inner, args, .. } = self;
+      upgrade(&inner)?.drag_and_drop(args).await
+    })
+  }
+}
+
+into_future!(DragAndDropBuilder, drag_and_drop, Result<(), Arc<Error>>);
+
+#[derive(Clone)]
+pub struct SetContentBuilder {
+  inner: Weak<Impl>,
+  api_name: &'static str,
+  args: SetContentArgs,
+}
+
+impl SetContentBuilder {
   setter! {
       timeout: Option<f64>,
       wait_until: Option<DocumentLoadState>
   }
 
-  pub(crate) fn new(inner: Weak<Impl>, html: &'a str) -> Self {
+  pub(crate) fn new(inner: Weak<Impl>, html: &str) -> Self {
     let args = SetContentArgs::new(html);
-    Self { inner, args }
+    Self {
+      inner,
+      args,
+      api_name: "frame.set_content",
+    }
   }
 
-  pub async fn set_content(self) -> Result<(), Arc<Error>> {
-    let Self { inner, args } = self;
-    upgrade(&inner)?.set_content(args).await
+  /// Reports the call as `api_name`, for the `Page` shortcuts
+  pub(crate) fn api_name(mut self, api_name: &'static str) -> Self {
+    self.api_name = api_name;
+    self
+  }
+
+  #[track_caller]
+  pub fn set_content(self) -> impl Future<Output = Result<(), Arc<Error>>> {
+    api_call!(self.api_name, {
+      let Self { inner, args, .. } = self;
+      upgrade(&inner)?.set_content(args).await
+    })
   }
 }
 
-pub struct TapBuilder<'a> {
+into_future!(SetContentBuilder, set_content, Result<(), Arc<Error>>);
+
+#[derive(Clone)]
+pub struct TapBuilder {
   inner: Weak<Impl>,
-  args: TapArgs<'a>,
+  api_name: &'static str,
+  args: TapArgs,
 }
 
-impl<'a> TapBuilder<'a> {
+impl TapBuilder {
   setter! {
       /// Whether to bypass the actionability checks. Defaults to `false`.
       force: Option<bool>,
@@ -957,24 +1330,41 @@ impl<'a> TapBuilder<'a> {
       trial: Option<bool>
   }
 
-  pub(crate) fn new(inner: Weak<Impl>, selector: &'a str) -> Self {
+  pub(crate) fn new(inner: Weak<Impl>, selector: &str) -> Self {
     let args = TapArgs::new(selector);
-    Self { inner, args }
+    Self {
+      inner,
+      args,
+      api_name: "frame.tap",
+    }
+  }
+
+  /// Reports the call as `api_name`, for the `Page` shortcuts
+  pub(crate) fn api_name(mut self, api_name: &'static str) -> Self {
+    self.api_name = api_name;
+    self
   }
 
-  pub async fn tap(self) -> Result<(), Arc<Error>> {
-    let Self { inner, args } = self;
-    let _ = upgrade(&inner)?.tap(args).await?;
-    Ok(())
+  #[track_caller]
+  pub fn tap(self) -> impl Future<Output = Result<(), Arc<Error>>> {
+    api_call!(self.api_name, {
+      let Self { inner, args, .. } = self;
+      let _ = upgrade(&inner)?.tap(args).await?;
+      Ok(())
+    })
   }
 }
 
-pub struct FillBuilder<'a, 'b> {
+into_future!(TapBuilder, tap, Result<(), Arc<Error>>);
+
+#[derive(Clone)]
+pub struct FillBuilder {
   inner: Weak<Impl>,
-  args: FillArgs<'a, 'b>,
+  api_name: &'static str,
+  args: FillArgs,
 }
 
-impl<'a, 'b> FillBuilder<'a, 'b> {
+impl FillBuilder {
   setter! {
       /// Actions that initiate navigations are waiting for these navigations to happen and for pages to start loading. You can
       /// opt out of waiting via setting this flag. You would only need this option in the exceptional cases such as navigating to
@@ -983,26 +1373,43 @@ impl<'a, 'b> FillBuilder<'a, 'b> {
       timeout: Option<f64>
   }
 
-  pub(crate) fn new(inner: Weak<Impl>, selector: &'a str, value: &'b str) -> Self {
+  pub(crate) fn new(inner: Weak<Impl>, selector: &str, value: &str) -> Self {
     let args = FillArgs::new(selector, value);
-    Self { inner, args }
+    Self {
+      inner,
+      args,
+      api_name: "frame.fill",
+    }
+  }
+
+  /// Reports the call as `api_name`, for the `Page` shortcuts
+  pub(crate) fn api_name(mut self, api_name: &'static str) -> Self {
+    self.api_name = api_name;
+    self
   }
 
-  pub async fn fill(self) -> Result<(), Arc<Error>> {
-    let Self { inner, args } = self;
-    let _ = upgrade(&inner)?.fill(args).await?;
-    Ok(())
+  #[track_caller]
+  pub fn fill(self) -> impl Future<Output = Result<(), Arc<Error>>> {
+    api_call!(self.api_name, {
+      let Self { inner, args, .. } = self;
+      let _ = upgrade(&inner)?.fill(args).await?;
+      Ok(())
+    })
   }
 }
 
+into_future!(FillBuilder, fill, Result<(), Arc<Error>>);
+
 macro_rules! check_builder {
   ($t:ident, $m:ident) => {
-    pub struct $t<'a> {
+    #[derive(Clone)]
+    pub struct $t {
       inner: Weak<Impl>,
-      args: CheckArgs<'a>,
+      api_name: &'static str,
+      args: CheckArgs,
     }
 
-    impl<'a> $t<'a> {
+    impl $t {
       setter! {
           /// A point to use relative to the top-left corner of element padding box. If not specified, uses some visible point of the element.
           position: Option<Position>,
@@ -1018,51 +1425,81 @@ macro_rules! check_builder {
           trial: Option<bool>
       }
 
-      pub(crate) fn new(inner: Weak<Impl>, selector: &'a str) -> Self {
+      pub(crate) fn new(inner: Weak<Impl>, selector: &str) -> Self {
         let args = CheckArgs::new(selector);
-        Self { inner, args }
+        Self {
+          inner,
+          args,
+          api_name: concat!("frame.", stringify!($m)),
+        }
       }
 
-      pub async fn $m(self) -> Result<(), Arc<Error>> {
-        let Self { inner, args } = self;
-        let _ = upgrade(&inner)?.$m(args).await?;
-        Ok(())
+      /// Reports the call as `api_name`, for the `Page` shortcuts
+      pub(crate) fn api_name(mut self, api_name: &'static str) -> Self {
+        self.api_name = api_name;
+        self
+      }
+
+      #[track_caller]
+      pub fn $m(self) -> impl Future<Output = Result<(), Arc<Error>>> {
+        api_call!(self.api_name, {
+          let Self { inner, args, .. } = self;
+          let _ = upgrade(&inner)?.$m(args).await?;
+          Ok(())
+        })
       }
     }
+
+    into_future!($t, $m, Result<(), Arc<Error>>);
   };
 }
 
 check_builder!(CheckBuilder, check);
 check_builder!(UncheckBuilder, uncheck);
 
-pub struct AddScriptTagBuilder<'a, 'b, 'c> {
+#[derive(Clone)]
+pub struct AddScriptTagBuilder {
   inner: Weak<Impl>,
-  args: AddScriptTagArgs<'a, 'b, 'c>,
+  api_name: &'static str,
+  args: AddScriptTagArgs,
 }
 
-impl<'a, 'b, 'c> AddScriptTagBuilder<'a, 'b, 'c> {
+impl AddScriptTagBuilder {
   setter! {
       /// URL of a script to be added.
-      url: Option<&'b str>
+      url: Option<String>
   }
 
-  pub(crate) fn new(inner: Weak<Impl>, content: &'a str) -> Self {
+  pub(crate) fn new(inner: Weak<Impl>, content: &str) -> Self {
     let args = AddScriptTagArgs::new(content);
-    Self { inner, args }
+    Self {
+      inner,
+      args,
+      api_name: "frame.add_script_tag",
+    }
+  }
+
+  /// Reports the call as `api_name`, for the `Page` shortcuts
+  pub(crate) fn api_name(mut self, api_name: &'static str) -> Self {
+    self.api_name = api_name;
+    self
   }
 
-  pub async fn add_script_tag(self) -> Result<ElementHandle, Arc<Error>> {
-    let Self { inner, args } = self;
-    upgrade(&inner)?
-      .add_script_tag(args)
-      .await
-      .map(ElementHandle::new)
+  #[track_caller]
+  pub fn add_script_tag(self) -> impl Future<Output = Result<ElementHandle, Arc<Error>>> {
+    api_call!(self.api_name, {
+      let Self { inner, args, .. } = self;
+      upgrade(&inner)?
+        .add_script_tag(args)
+        .await
+        .map(ElementHandle::new)
+    })
   }
 
   /// Script type. Use 'module' in order to load a Javascript ES6 module. See
   /// [script](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/script) for more details.
-  pub fn r#type(mut self, x: &'c str) -> Self {
-    self.args.r#type = Some(x);
+  pub fn r#type(mut self, x: impl Into<String>) -> Self {
+    self.args.r#type = Some(x.into());
     self
   }
 
@@ -1072,13 +1509,17 @@ impl<'a, 'b, 'c> AddScriptTagBuilder<'a, 'b, 'c> {
   }
 }
 
-pub struct SelectOptionBuilder<'a> {
+into_future!(AddScriptTagBuilder, add_script_tag, Result<ElementHandle, Arc<Error>>);
+
+#[derive(Clone)]
+pub struct SelectOptionBuilder {
   inner: Weak<Impl>,
-  args: SelectOptionArgs<'a>,
-  err: Option<Error>,
+  api_name: &'static str,
+  args: SelectOptionArgs,
+  err: Option<Arc<Error>>,
 }
 
-impl<'a> SelectOptionBuilder<'a> {
+impl SelectOptionBuilder {
   setter! {
       /// Actions that initiate navigations are waiting for these navigations to happen and for pages to start loading. You can
       /// opt out of waiting via setting this flag. You would only need this option in the exceptional cases such as navigating to
@@ -1087,21 +1528,33 @@ impl<'a> SelectOptionBuilder<'a> {
       timeout: Option<f64>
   }
 
-  pub(crate) fn new(inner: Weak<Impl>, selector: &'a str) -> Self {
+  pub(crate) fn new(inner: Weak<Impl>, selector: &str) -> Self {
     let args = SelectOptionArgs::new(selector);
     Self {
       inner,
       args,
       err: None,
+      api_name: "frame.select_option",
     }
   }
 
-  pub async fn select_option(self) -> Result<Vec<String>, Arc<Error>> {
-    let Self { inner, args, err } = self;
-    if let Some(e) = err {
-      return Err(e.into());
-    }
-    upgrade(&inner)?.select_option(args).await
+  /// Reports the call as `api_name`, for the `Page` shortcuts
+  pub(crate) fn api_name(mut self, api_name: &'static str) -> Self {
+    self.api_name = api_name;
+    self
+  }
+
+  #[track_caller]
+  pub fn select_option(self) -> impl Future<Output = Result<Vec<String>, Arc<Error>>> {
+    api_call!(self.api_name, {
+      let Self {
+        inner, args, err, ..
+      } = self;
+      if let Some(e) = err {
+        return Err(e);
+      }
+      upgrade(&inner)?.select_option(args).await
+    })
   }
 
   pub fn add_element(mut self, x: &ElementHandle) -> Self {
@@ -1109,7 +1562,7 @@ impl<'a> SelectOptionBuilder<'a> {
       Ok(i) => i,
       Err(e) => {
         if self.err.is_none() {
-          self.err = Some(e);
+          self.err = Some(Arc::new(e));
         }
         return self;
       }
@@ -1164,12 +1617,20 @@ impl<'a> SelectOptionBuilder<'a> {
   }
 }
 
-pub struct SetInputFilesBuilder<'a> {
+into_future!(
+  SelectOptionBuilder,
+  select_option,
+  Result<Vec<String>, Arc<Error>>
+);
+
+#[derive(Clone)]
+pub struct SetInputFilesBuilder {
   inner: Weak<Impl>,
-  args: SetInputFilesArgs<'a>,
+  api_name: &'static str,
+  args: SetInputFilesArgs,
 }
 
-impl<'a> SetInputFilesBuilder<'a> {
+impl SetInputFilesBuilder {
   setter! {
       /// Actions that initiate navigations are waiting for these navigations to happen and for pages to start loading. You can
       /// opt out of waiting via setting this flag. You would only need this option in the exceptional cases such as navigating to
@@ -1178,15 +1639,28 @@ impl<'a> SetInputFilesBuilder<'a> {
       timeout: Option<f64>
   }
 
-  pub(crate) fn new(inner: Weak<Impl>, selector: &'a str, file: File) -> Self {
+  pub(crate) fn new(inner: Weak<Impl>, selector: &str, file: File) -> Self {
     let mut args = SetInputFilesArgs::new(selector);
     args.files = vec![file];
-    Self { inner, args }
+    Self {
+      inner,
+      args,
+      api_name: "frame.set_input_files",
+    }
   }
 
-  pub async fn set_input_files(self) -> Result<(), Arc<Error>> {
-    let Self { inner, args } = self;
-    upgrade(&inner)?.set_input_files(args).await
+  /// Reports the call as `api_name`, for the `Page` shortcuts
+  pub(crate) fn api_name(mut self, api_name: &'static str) -> Self {
+    self.api_name = api_name;
+    self
+  }
+
+  #[track_caller]
+  pub fn set_input_files(self) -> impl Future<Output = Result<(), Arc<Error>>> {
+    api_call!(self.api_name, {
+      let Self { inner, args, .. } = self;
+      upgrade(&inner)?.set_input_files(args).await
+    })
   }
 
   pub fn add_file(mut self, x: File) -> Self {
@@ -1200,13 +1674,74 @@ impl<'a> SetInputFilesBuilder<'a> {
   }
 }
 
-pub struct WaitForFunctionBuilder<'a> {
+into_future!(SetInputFilesBuilder, set_input_files, Result<(), Arc<Error>>);
+
+#[derive(Clone)]
+pub struct SetInputFilePathsBuilder {
+  inner: Weak<Impl>,
+  api_name: &'static str,
+  args: SetInputFilePathsArgs,
+}
+
+impl SetInputFilePathsBuilder {
+  setter! {
+      /// Actions that initiate navigations are waiting for these navigations to happen and for pages to start loading. You can
+      /// opt out of waiting via setting this flag. You would only need this option in the exceptional cases such as navigating to
+      /// inaccessible pages. Defaults to `false`.
+      no_wait_after: Option<bool>,
+      timeout: Option<f64>
+  }
+
+  pub(crate) fn new<P: AsRef<Path>>(inner: Weak<Impl>, selector: &str, path: P) -> Self {
+    let mut args = SetInputFilePathsArgs::new(selector);
+    args.paths = vec![path.as_ref().to_owned()];
+    Self {
+      inner,
+      args,
+      api_name: "frame.set_input_file_paths",
+    }
+  }
+
+  /// Reports the call as `api_name`, for the `Page` shortcuts
+  pub(crate) fn api_name(mut self, api_name: &'static str) -> Self {
+    self.api_name = api_name;
+    self
+  }
+
+  #[track_caller]
+  pub fn set_input_file_paths(self) -> impl Future<Output = Result<(), Arc<Error>>> {
+    api_call!(self.api_name, {
+      let Self { inner, args, .. } = self;
+      upgrade(&inner)?.set_input_file_paths(args).await
+    })
+  }
+
+  pub fn add_path<P: AsRef<Path>>(mut self, x: P) -> Self {
+    self.args.paths.push(x.as_ref().to_owned());
+    self
+  }
+
+  pub fn clear_paths(mut self) -> Self {
+    self.args.paths = vec![];
+    self
+  }
+}
+
+into_future!(
+  SetInputFilePathsBuilder,
+  set_input_file_paths,
+  Result<(), Arc<Error>>
+);
+
+#[derive(Clone)]
+pub struct WaitForFunctionBuilder {
   inner: Weak<Impl>,
-  args: WaitForFunctionArgs<'a>,
-  err: Option<Error>,
+  api_name: &'static str,
+  args: WaitForFunctionArgs,
+  err: Option<Arc<Error>>,
 }
 
-impl<'a> WaitForFunctionBuilder<'a> {
+impl WaitForFunctionBuilder {
   setter! {
       /// If `polling` is `'raf'`, then `expression` is constantly executed in `requestAnimationFrame` callback. If `polling` is a
       /// number, then it is treated as an interval in milliseconds at which the function would be executed. Defaults to `raf`.
@@ -1214,24 +1749,36 @@ impl<'a> WaitForFunctionBuilder<'a> {
       timeout: Option<f64>
   }
 
-  pub(crate) fn new(inner: Weak<Impl>, expression: &'a str) -> Self {
+  pub(crate) fn new(inner: Weak<Impl>, expression: &str) -> Self {
     let args = WaitForFunctionArgs::new(expression);
     Self {
       inner,
       args,
       err: None,
+      api_name: "frame.wait_for_function",
     }
   }
 
-  pub async fn wait_for_function(self) -> Result<JsHandle, Arc<Error>> {
-    let Self { inner, args, err } = self;
-    if let Some(e) = err {
-      return Err(e.into());
-    }
-    upgrade(&inner)?
-      .wait_for_function(args)
-      .await
-      .map(JsHandle::new)
+  /// Reports the call as `api_name`, for the `Page` shortcuts
+  pub(crate) fn api_name(mut self, api_name: &'static str) -> Self {
+    self.api_name = api_name;
+    self
+  }
+
+  #[track_caller]
+  pub fn wait_for_function(self) -> impl Future<Output = Result<JsHandle, Arc<Error>>> {
+    api_call!(self.api_name, {
+      let Self {
+        inner, args, err, ..
+      } = self;
+      if let Some(e) = err {
+        return Err(e);
+      }
+      upgrade(&inner)?
+        .wait_for_function(args)
+        .await
+        .map(JsHandle::new)
+    })
   }
 
   pub fn arg<T>(mut self, x: &T) -> Self
@@ -1240,7 +1787,7 @@ impl<'a> WaitForFunctionBuilder<'a> {
   {
     let arg = match ser::to_value(x).map_err(Error::SerializationPwJson) {
       Err(e) => {
-        self.err = Some(e);
+        self.err = Some(Arc::new(e));
         return self;
       }
       Ok(arg) => arg,
@@ -1255,3 +1802,5 @@ impl<'a> WaitForFunctionBuilder<'a> {
     self
   }
 }
+
+into_future!(WaitForFunctionBuilder, wait_for_function, Result<JsHandle, Arc<Error>>);
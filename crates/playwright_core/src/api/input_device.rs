@@ -110,14 +110,20 @@ impl Keyboard {
   ///
   /// > NOTE: Modifier keys DO influence `keyboard.down`. Holding down `Shift`
   /// will type the text in upper case.
-  pub async fn down(&self, key: &str) -> Result<(), Arc<Error>> {
-    let inner = upgrade(&self.inner)?;
-    inner.key_down(key).await
+  #[track_caller]
+  pub fn down<'a>(&'a self, key: &'a str) -> impl Future<Output = Result<(), Arc<Error>>> + 'a {
+    api_call!("keyboard.down", {
+      let inner = upgrade(&self.inner)?;
+      inner.key_down(key).await
+    })
   }
 
-  pub async fn up(&self, key: &str) -> Result<(), Arc<Error>> {
-    let inner = upgrade(&self.inner)?;
-    inner.key_up(key).await
+  #[track_caller]
+  pub fn up<'a>(&'a self, key: &'a str) -> impl Future<Output = Result<(), Arc<Error>>> + 'a {
+    api_call!("keyboard.up", {
+      let inner = upgrade(&self.inner)?;
+      inner.key_up(key).await
+    })
   }
 
   /// Dispatches only `input` event, does not emit the `keydown`, `keyup` or
@@ -130,9 +136,15 @@ impl Keyboard {
   ///
   /// > NOTE: Modifier keys DO NOT effect `keyboard.insertText`. Holding down
   /// `Shift` will not type the text in upper case.
-  pub async fn input_text(&self, text: &str) -> Result<(), Arc<Error>> {
-    let inner = upgrade(&self.inner)?;
-    inner.key_input_text(text).await
+  #[track_caller]
+  pub fn input_text<'a>(
+    &'a self,
+    text: &'a str,
+  ) -> impl Future<Output = Result<(), Arc<Error>>> + 'a {
+    api_call!("keyboard.input_text", {
+      let inner = upgrade(&self.inner)?;
+      inner.key_input_text(text).await
+    })
   }
 
   /// Sends a `keydown`, `keypress`/`input`, and `keyup` event for each
@@ -149,15 +161,29 @@ impl Keyboard {
   /// > NOTE: Modifier keys DO NOT effect `keyboard.type`. Holding down `Shift`
   /// will not type the text in upper case. > NOTE: For characters that are
   /// not on a US keyboard, only an `input` event will be sent.
-  pub async fn r#type(&self, text: &str, delay: Option<f64>) -> Result<(), Arc<Error>> {
-    let inner = upgrade(&self.inner)?;
-    inner.key_type(text, delay).await
+  #[track_caller]
+  pub fn r#type<'a>(
+    &'a self,
+    text: &'a str,
+    delay: Option<f64>,
+  ) -> impl Future<Output = Result<(), Arc<Error>>> + 'a {
+    api_call!("keyboard.type", {
+      let inner = upgrade(&self.inner)?;
+      inner.key_type(text, delay).await
+    })
   }
 
   /// Shortcut for [`method: Keyboard.down`] and [`method: Keyboard.up`].
-  pub async fn press(&self, key: &str, delay: Option<f64>) -> Result<(), Arc<Error>> {
-    let inner = upgrade(&self.inner)?;
-    inner.key_press(key, delay).await
+  #[track_caller]
+  pub fn press<'a>(
+    &'a self,
+    key: &'a str,
+    delay: Option<f64>,
+  ) -> impl Future<Output = Result<(), Arc<Error>>> + 'a {
+    api_call!("keyboard.press", {
+      let inner = upgrade(&self.inner)?;
+      inner.key_press(key, delay).await
+    })
   }
 }
 
@@ -166,27 +192,57 @@ impl Mouse {
     Self { inner }
   }
 
-  pub async fn r#move(&self, x: f64, y: f64, steps: Option<i32>) -> Result<(), Arc<Error>> {
-    let inner = upgrade(&self.inner)?;
-    inner.mouse_move(x, y, steps).await
+  #[track_caller]
+  pub fn r#move<'a>(
+    &'a self,
+    x: f64,
+    y: f64,
+    steps: Option<i32>,
+  ) -> impl Future<Output = Result<(), Arc<Error>>> + 'a {
+    api_call!("mouse.move", {
+      let inner = upgrade(&self.inner)?;
+      inner.mouse_move(x, y, steps).await
+    })
   }
 
-  pub async fn down(
-    &self,
+  #[track_caller]
+  pub fn down<'a>(
+    &'a self,
     button: Option<MouseButton>,
     click_count: Option<i32>,
-  ) -> Result<(), Arc<Error>> {
-    let inner = upgrade(&self.inner)?;
-    inner.mouse_down(button, click_count).await
+  ) -> impl Future<Output = Result<(), Arc<Error>>> + 'a {
+    api_call!("mouse.down", {
+      let inner = upgrade(&self.inner)?;
+      inner.mouse_down(button, click_count).await
+    })
   }
 
-  pub async fn up(
-    &self,
+  #[track_caller]
+  pub fn up<'a>(
+    &'a self,
     button: Option<MouseButton>,
     click_count: Option<i32>,
-  ) -> Result<(), Arc<Error>> {
-    let inner = upgrade(&self.inner)?;
-    inner.mouse_up(button, click_count).await
+  ) -> impl Future<Output = Result<(), Arc<Error>>> + 'a {
+    api_call!("mouse.up", {
+      let inner = upgrade(&self.inner)?;
+      inner.mouse_up(button, click_count).await
+    })
+  }
+
+  /// Dispatches a `wheel` event.
+  ///
+  /// > NOTE: Wheel events may cause scrolling if they are not handled, and
+  /// this method does not wait for the scrolling to finish before returning.
+  #[track_caller]
+  pub fn wheel<'a>(
+    &'a self,
+    delta_x: f64,
+    delta_y: f64,
+  ) -> impl Future<Output = Result<(), Arc<Error>>> + 'a {
+    api_call!("mouse.wheel", {
+      let inner = upgrade(&self.inner)?;
+      inner.mouse_wheel(delta_x, delta_y).await
+    })
   }
 
   /// Shortcut for [`method: Mouse.move`], [`method: Mouse.down`], [`method:
@@ -207,9 +263,12 @@ impl TouchScreen {
     Self { inner }
   }
 
-  pub async fn tap(&self, x: f64, y: f64) -> Result<(), Arc<Error>> {
-    let inner = upgrade(&self.inner)?;
-    inner.screen_tap(x, y).await
+  #[track_caller]
+  pub fn tap<'a>(&'a self, x: f64, y: f64) -> impl Future<Output = Result<(), Arc<Error>>> + 'a {
+    api_call!("touch_screen.tap", {
+      let inner = upgrade(&self.inner)?;
+      inner.screen_tap(x, y).await
+    })
   }
 }
 
@@ -235,10 +294,13 @@ macro_rules! clicker {
         Self { inner, args }
       }
 
-      pub async fn $f(self) -> Result<(), Arc<Error>> {
-        let Self { inner, args } = self;
-        let _ = upgrade(&inner)?.$mf(args).await?;
-        Ok(())
+      #[track_caller]
+      pub fn $f(self) -> impl Future<Output = Result<(), Arc<Error>>> {
+        api_call!(concat!("mouse.", stringify!($f)), {
+          let Self { inner, args } = self;
+          let _ = upgrade(&inner)?.$mf(args).await?;
+          Ok(())
+        })
       }
     }
   };
@@ -1,6 +1,12 @@
+use std::cell::RefCell;
 use std::fmt;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::task::Context as TaskContext;
 
+use crate::api::ElementHandle;
 use crate::imp::core::*;
+use crate::imp::js_handle::Handle;
 use crate::imp::js_handle::JsHandle as Impl;
 use crate::imp::prelude::*;
 
@@ -16,37 +22,172 @@ use crate::imp::prelude::*;
 /// [`method: JsHandle.dispose`]. JsHandles are auto-disposed when their origin frame gets navigated or the parent context
 /// gets destroyed.
 ///
+/// Clones share the remote object, which is disposed in the background when
+/// the last clone is dropped unless [`JsHandle::keep`] was called. See also
+/// [`with_handles`].
+///
 /// JsHandle instances can be used as an argument in [`method: Page.evalOnSelector`], [`method: Page.evaluate`] and
 /// [`method: Page.evaluateHandle`] methods.
+#[derive(Clone)]
 pub struct JsHandle {
-  inner: Weak<Impl>,
+  inner: Handle,
+  owner: Arc<Owner>,
+}
+
+/// Disposes the remote handle when the last [`JsHandle`] clone or
+/// [`ElementHandle`] drops
+#[derive(Debug)]
+pub(crate) struct Owner {
+  handle: Handle,
+  keep: AtomicBool,
+  /// Runtime the handle was created in. Disposal is sent from a task of it
+  /// since a handle may be dropped anywhere, even with the connection locked.
+  runtime: Option<tokio::runtime::Handle>,
+}
+
+impl Owner {
+  pub(crate) fn new(handle: Handle) -> Arc<Self> {
+    SCOPE.with(|s| {
+      if let Some(created) = &*s.borrow() {
+        created.lock().unwrap().push(handle.clone());
+      }
+    });
+    Arc::new(Self {
+      handle,
+      keep: AtomicBool::new(false),
+      runtime: tokio::runtime::Handle::try_current().ok(),
+    })
+  }
+
+  /// For a handle owned by another remote object, which is neither disposed
+  /// on drop nor collected by [`with_handles`]
+  pub(crate) fn borrowed(handle: Handle) -> Arc<Self> {
+    Arc::new(Self {
+      handle,
+      keep: AtomicBool::new(true),
+      runtime: None,
+    })
+  }
+}
+
+impl Drop for Owner {
+  fn drop(&mut self) {
+    if self.keep.load(Ordering::Relaxed) {
+      return;
+    }
+    let handle = self.handle.clone();
+    match &self.runtime {
+      Some(rt) => {
+        rt.spawn(async move {
+          if let Err(e) = handle.dispose().await {
+            log::trace!("failed to dispose a handle: {:?}", e);
+          }
+        });
+      }
+      None => log::trace!("handle created outside of a runtime is not disposed"),
+    }
+  }
+}
+
+thread_local! {
+  static SCOPE: RefCell<Option<Arc<Mutex<Vec<Handle>>>>> = RefCell::new(None);
+}
+
+/// Runs `fut` and then disposes every handle created while it was polled,
+/// including clones still alive and handles opted out with
+/// [`JsHandle::keep`]. Handles created by tasks spawned from `fut` are not
+/// collected since they are polled elsewhere, nor are those owned by another
+/// object like the arguments of a console message.
+pub async fn with_handles<F: Future>(fut: F) -> F::Output {
+  let created = Arc::new(Mutex::new(Vec::new()));
+  let out = Collect {
+    created: created.clone(),
+    fut: Box::pin(fut),
+  }
+  .await;
+  let handles = std::mem::take(&mut *created.lock().unwrap());
+  for h in handles {
+    let _ = h.dispose().await;
+  }
+  out
+}
+
+struct Collect<F> {
+  created: Arc<Mutex<Vec<Handle>>>,
+  fut: Pin<Box<F>>,
+}
+
+impl<F: Future> Future for Collect<F> {
+  type Output = F::Output;
+
+  fn poll(mut self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<Self::Output> {
+    let outer = SCOPE.with(|s| s.replace(Some(self.created.clone())));
+    let res = self.fut.as_mut().poll(cx);
+    SCOPE.with(|s| *s.borrow_mut() = outer);
+    res
+  }
 }
 
 impl PartialEq for JsHandle {
   fn eq(&self, other: &Self) -> bool {
-    let a = self.inner.upgrade();
-    let b = other.inner.upgrade();
+    let a = self.guid();
+    let b = other.guid();
     a.and_then(|a| b.map(|b| (a, b)))
-      .map(|(a, b)| a.guid() == b.guid())
+      .map(|(a, b)| a == b)
       .unwrap_or_default()
   }
 }
 
 impl JsHandle {
   pub(crate) fn new(inner: Weak<Impl>) -> Self {
-    Self { inner }
+    Self::from_handle(Handle::Js(inner))
+  }
+
+  pub(crate) fn from_handle(inner: Handle) -> Self {
+    let owner = Owner::new(inner.clone());
+    Self { inner, owner }
+  }
+
+  /// A handle to an object owned by another remote object, such as the
+  /// arguments of a [`ConsoleMessage`](crate::api::ConsoleMessage)
+  pub(crate) fn borrowed(inner: Weak<Impl>) -> Self {
+    let inner = Handle::Js(inner);
+    let owner = Owner::borrowed(inner.clone());
+    Self { inner, owner }
+  }
+
+  /// Opts out of disposal on drop. The remote object then lives until
+  /// [`JsHandle::dispose`] is called or its frame navigates.
+  pub fn keep(&self) {
+    self.owner.keep.store(true, Ordering::Relaxed);
   }
 
   pub(crate) fn guid(&self) -> Result<Str<Guid>, Error> {
-    Ok(upgrade(&self.inner)?.guid().to_owned())
+    self.inner.guid()
+  }
+
+  /// Returns the handle as an [`ElementHandle`] if it references a DOM
+  /// element
+  pub fn as_element(&self) -> Option<ElementHandle> {
+    match &self.inner {
+      Handle::Element(x) => Some(ElementHandle::owned_by(x.clone(), self.owner.clone())),
+      Handle::Js(_) => None,
+    }
   }
 
   /// Fetches a single property from the referenced object.
-  pub async fn get_property(&mut self, name: &str) -> ArcResult<JsHandle> {
-    upgrade(&self.inner)?
-      .get_property(name)
-      .await
-      .map(JsHandle::new)
+  #[track_caller]
+  pub fn get_property<'a>(
+    &'a self,
+    name: &'a str,
+  ) -> impl Future<Output = ArcResult<JsHandle>> + 'a {
+    api_call!("js_handle.get_property", {
+      self
+        .inner
+        .get_property(name)
+        .await
+        .map(JsHandle::from_handle)
+    })
   }
 
   /// The method returns a map with **own property names** as keys and JsHandle
@@ -59,13 +200,26 @@ impl JsHandle {
   /// const documentHandle = properties.get('document');
   /// await handle.dispose();
   /// ```
-  pub async fn get_properties(&mut self) -> ArcResult<HashMap<String, JsHandle>> {
-    let m = upgrade(&self.inner)?.get_properties().await?;
-    Ok(m.into_iter().map(|(k, v)| (k, JsHandle::new(v))).collect())
+  #[track_caller]
+  pub fn get_properties<'a>(
+    &'a self,
+  ) -> impl Future<Output = ArcResult<HashMap<String, JsHandle>>> + 'a {
+    api_call!("js_handle.get_properties", {
+      let m = self.inner.get_properties().await?;
+      Ok(
+        m.into_iter()
+          .map(|(k, v)| (k, JsHandle::from_handle(v)))
+          .collect(),
+      )
+    })
   }
 
-  pub async fn dispose(&mut self) -> ArcResult<()> {
-    upgrade(&self.inner)?.dispose().await
+  #[track_caller]
+  pub fn dispose<'a>(&'a self) -> impl Future<Output = ArcResult<()>> + 'a {
+    api_call!("js_handle.dispose", {
+      self.keep();
+      self.inner.dispose().await
+    })
   }
 
   /// Returns a JSON representation of the object. If the object has a `toJSON`
@@ -74,22 +228,78 @@ impl JsHandle {
   /// > NOTE: The method will return an empty JSON object if the referenced
   /// object is not stringifiable. It will throw an error if the object has
   /// circular references.
-  pub async fn json_value<U>(&mut self) -> ArcResult<U>
+  #[track_caller]
+  pub fn json_value<'a, U>(&'a self) -> impl Future<Output = ArcResult<U>> + 'a
   where
     U: DeserializeOwned,
+    U: 'a,
   {
-    upgrade(&self.inner)?.json_value().await
+    api_call!("js_handle.json_value", { self.inner.json_value().await })
   }
 
-  // evaluate
+  /// Evaluates `expression` in the browser with the referenced object as
+  /// the first argument and `arg` as the second. If the expression returns a
+  /// promise it is awaited.
+  ///
+  /// ```js
+  /// const tweetHandle = await page.$('.tweet .retweets');
+  /// expect(await tweetHandle.evaluate(node => node.innerText)).toBe('10 retweets');
+  /// ```
+  #[track_caller]
+  pub fn evaluate<'a, T, U>(
+    &'a self,
+    expression: &'a str,
+    arg: Option<T>,
+  ) -> impl Future<Output = ArcResult<U>> + 'a
+  where
+    T: Serialize,
+    U: DeserializeOwned,
+    T: 'a,
+    U: 'a,
+  {
+    api_call!("js_handle.evaluate", {
+      self.inner.evaluate(expression, arg).await
+    })
+  }
+
+  /// Like [`JsHandle::evaluate`] but returns the result as a handle
+  #[track_caller]
+  pub fn evaluate_handle<'a, T>(
+    &'a self,
+    expression: &'a str,
+    arg: Option<T>,
+  ) -> impl Future<Output = ArcResult<JsHandle>> + 'a
+  where
+    T: Serialize,
+    T: 'a,
+  {
+    api_call!("js_handle.evaluate_handle", {
+      self
+        .inner
+        .evaluate_handle(expression, arg)
+        .await
+        .map(JsHandle::from_handle)
+    })
+  }
 }
 
 impl fmt::Display for JsHandle {
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-    if let Some(inner) = self.inner.upgrade() {
-      inner.fmt(f)
-    } else {
-      write!(f, "")
+    match &self.inner {
+      Handle::Js(x) => {
+        match x.upgrade() {
+          Some(inner) => inner.fmt(f),
+          None => write!(f, ""),
+        }
+      }
+      Handle::Element(x) => {
+        let preview = x.upgrade().and_then(|e| {
+          e.channel().initializer["preview"]
+            .as_str()
+            .map(ToOwned::to_owned)
+        });
+        write!(f, "{}", preview.unwrap_or_default())
+      }
     }
   }
 }
@@ -1,13 +1,17 @@
+use futures::TryFutureExt;
+
 pub use crate::api::frame::AddScriptTagBuilder;
 pub use crate::api::frame::CheckBuilder;
 pub use crate::api::frame::ClickBuilder;
 pub use crate::api::frame::DblClickBuilder;
+pub use crate::api::frame::DragAndDropBuilder;
 pub use crate::api::frame::FillBuilder;
 pub use crate::api::frame::GotoBuilder;
 pub use crate::api::frame::HoverBuilder;
 pub use crate::api::frame::PressBuilder;
 pub use crate::api::frame::SelectOptionBuilder;
 pub use crate::api::frame::SetContentBuilder;
+pub use crate::api::frame::SetInputFilePathsBuilder;
 pub use crate::api::frame::SetInputFilesBuilder;
 pub use crate::api::frame::TapBuilder;
 pub use crate::api::frame::TypeBuilder;
@@ -31,6 +35,7 @@ use crate::api::Video;
 use crate::api::WebSocket;
 use crate::api::Worker;
 use crate::imp::core::*;
+use crate::imp::element_handle::Mask;
 use crate::imp::frame::Frame as FrameImpl;
 use crate::imp::page::EmulateMediaArgs;
 pub use crate::imp::page::EventType;
@@ -45,9 +50,15 @@ use crate::imp::utils::ColorScheme;
 use crate::imp::utils::DocumentLoadState;
 use crate::imp::utils::File;
 use crate::imp::utils::FloatRect;
+use crate::imp::utils::JsError;
 use crate::imp::utils::Length;
+use crate::imp::utils::PaperFormat;
 use crate::imp::utils::PdfMargins;
+use crate::imp::utils::ScreenshotAnimations;
+use crate::imp::utils::ScreenshotCaret;
+use crate::imp::utils::ScreenshotScale;
 use crate::imp::utils::ScreenshotType;
+use crate::imp::utils::UrlMatcher;
 use crate::imp::utils::Viewport;
 use crate::protocol::generated::LifecycleEvent;
 use crate::Error;
@@ -114,6 +125,12 @@ impl PartialEq for Page {
 impl Page {
   subscribe_event! {}
 
+  event_handlers! {}
+
+  pub(crate) fn inner(&self) -> Weak<Impl> {
+    self.inner.clone()
+  }
+
   pub(crate) fn new(inner: Weak<Impl>) -> Self {
     Self {
       inner: inner.clone(),
@@ -187,14 +204,26 @@ impl Page {
     GoForwardBuilder::new(self.inner.clone())
   }
 
-  pub async fn set_default_navigation_timeout(&self, timeout: u32) -> ArcResult<()> {
-    upgrade(&self.inner)?
-      .set_default_navigation_timeout(timeout)
-      .await
+  #[track_caller]
+  pub fn set_default_navigation_timeout<'a>(
+    &'a self,
+    timeout: u32,
+  ) -> impl Future<Output = ArcResult<()>> + 'a {
+    api_call!("page.set_default_navigation_timeout", {
+      upgrade(&self.inner)?
+        .set_default_navigation_timeout(timeout)
+        .await
+    })
   }
 
-  pub async fn set_default_timeout(&self, timeout: u32) -> ArcResult<()> {
-    upgrade(&self.inner)?.set_default_timeout(timeout).await
+  #[track_caller]
+  pub fn set_default_timeout<'a>(
+    &'a self,
+    timeout: u32,
+  ) -> impl Future<Output = ArcResult<()>> + 'a {
+    api_call!("page.set_default_timeout", {
+      upgrade(&self.inner)?.set_default_timeout(timeout).await
+    })
   }
 
   pub fn viewport_size(&self) -> Result<Option<Viewport>, Error> {
@@ -208,8 +237,14 @@ impl Page {
   /// `page.setViewportSize` will resize the page. A lot of websites don't
   /// expect phones to change size, so you should set the viewport size before
   /// navigating to the page.
-  pub async fn set_viewport_size(&self, viewport_size: Viewport) -> ArcResult<()> {
-    upgrade(&self.inner)?.set_viewport_size(viewport_size).await
+  #[track_caller]
+  pub fn set_viewport_size<'a>(
+    &'a self,
+    viewport_size: Viewport,
+  ) -> impl Future<Output = ArcResult<()>> + 'a {
+    api_call!("page.set_viewport_size", {
+      upgrade(&self.inner)?.set_viewport_size(viewport_size).await
+    })
   }
 
   /// Video object associated with this page.
@@ -217,13 +252,25 @@ impl Page {
     Ok(upgrade(&self.inner)?.video().map(Video::new))
   }
 
+  /// Flags the video of this page to be kept when the context was built with
+  /// [`ContextBuilder::keep_flagged_videos_only`](crate::api::browser::ContextBuilder::keep_flagged_videos_only)
+  /// or launched with
+  /// [`PersistentContextLauncher::keep_flagged_videos_only`](crate::api::browser_type::PersistentContextLauncher::keep_flagged_videos_only).
+  pub fn keep_video(&self) -> Result<(), Error> {
+    upgrade(&self.inner)?.keep_video();
+    Ok(())
+  }
+
   ///// Returns frame matching the specified criteria. Either `name` or `url` must
   ///// be specified.
   // fn frame(&self) -> Option<Frame> { unimplemented!() }
 
   /// Brings page to front (activates tab).
-  pub async fn bring_to_front(&self) -> ArcResult<()> {
-    upgrade(&self.inner)?.bring_to_front().await
+  #[track_caller]
+  pub fn bring_to_front<'a>(&'a self) -> impl Future<Output = ArcResult<()>> + 'a {
+    api_call!("page.bring_to_front", {
+      upgrade(&self.inner)?.bring_to_front().await
+    })
   }
 
   /// Adds a script which would be evaluated in one of the following scenarios:
@@ -249,9 +296,15 @@ impl Page {
   /// 
   /// > NOTE: The order of evaluation of multiple scripts installed via [`method: BrowserContext.addInitScript`] and
   /// [`method: Page.addInitScript`] is not defined.
-  pub async fn add_init_script(&self, source: &str) -> ArcResult<()> {
-    // arg not supported
-    upgrade(&self.inner)?.add_init_script(source).await
+  #[track_caller]
+  pub fn add_init_script<'a>(
+    &'a self,
+    source: &'a str,
+  ) -> impl Future<Output = ArcResult<()>> + 'a {
+    api_call!("page.add_init_script", {
+      // arg not supported
+      upgrade(&self.inner)?.add_init_script(source).await
+    })
   }
 
   /// Returns the PDF buffer.
@@ -300,7 +353,7 @@ impl Page {
   ///
   /// > NOTE: `headerTemplate` and `footerTemplate` markup have the following limitations: > 1. Script tags inside templates
   /// are not evaluated. > 2. Page styles are not visible inside templates.
-  pub fn pdf_builder(&self) -> PdfBuilder<'_, '_, '_, '_, '_, '_, '_, '_, '_, '_> {
+  pub fn pdf_builder(&self) -> PdfBuilder {
     PdfBuilder::new(self.inner.clone())
   }
 
@@ -316,12 +369,18 @@ impl Page {
   /// > NOTE: if `runBeforeUnload` is passed as true, a `beforeunload` dialog
   /// might be summoned and should be handled manually via [`event:
   /// Page.dialog`] event.
-  pub async fn close(&self, run_before_unload: Option<bool>) -> ArcResult<()> {
-    let inner = match self.inner.upgrade() {
-      None => return Ok(()),
-      Some(inner) => inner,
-    };
-    inner.close(run_before_unload).await
+  #[track_caller]
+  pub fn close<'a>(
+    &'a self,
+    run_before_unload: Option<bool>,
+  ) -> impl Future<Output = ArcResult<()>> + 'a {
+    api_call!("page.close", {
+      let inner = match self.inner.upgrade() {
+        None => return Ok(()),
+        Some(inner) => inner,
+      };
+      inner.close(run_before_unload).await
+    })
   }
 
   pub fn screenshot_builder(&self) -> ScreenshotBuilder {
@@ -366,25 +425,251 @@ impl Page {
 
   /// Returns the opener for popup pages and `null` for others. If the opener
   /// has been closed already the returns `null`.
-  pub async fn opener(&self) -> ArcResult<Option<Page>> {
-    Ok(upgrade(&self.inner)?.opener().await?.map(Page::new))
+  #[track_caller]
+  pub fn opener<'a>(&'a self) -> impl Future<Output = ArcResult<Option<Page>>> + 'a {
+    api_call!("page.opener", {
+      Ok(upgrade(&self.inner)?.opener().await?.map(Page::new))
+    })
   }
 
   /// The extra HTTP headers will be sent with every request the page initiates.
   ///
   /// > NOTE: [`method: Page.setExtraHTTPHeaders`] does not guarantee the order
   /// of headers in the outgoing requests.
-  pub async fn set_extra_http_headers<T>(&self, headers: T) -> ArcResult<()>
+  #[track_caller]
+  pub fn set_extra_http_headers<'a, T>(
+    &'a self,
+    headers: T,
+  ) -> impl Future<Output = ArcResult<()>> + 'a
   where
     T: IntoIterator<Item = (String, String)>,
+    T: 'a,
+  {
+    api_call!("page.set_extra_http_headers", {
+      upgrade(&self.inner)?.set_extra_http_headers(headers).await
+    })
+  }
+
+  #[track_caller]
+  pub fn expect_event<'a>(
+    &'a self,
+    evt: EventType,
+  ) -> impl Future<Output = Result<Event, Error>> + 'a {
+    api_call!("page.expect_event", {
+      let stream = upgrade(&self.inner)?.subscribe_event();
+      let timeout = upgrade(&self.inner)?.default_timeout();
+      expect_event(stream, evt, timeout).await.map(Event::from)
+    })
+  }
+
+  /// Waits for an event of type `evt` for which `predicate` returns true.
+  /// Defaults to the page's default timeout, pass `Some(0.)` to disable it.
+  #[track_caller]
+  pub fn wait_for_event<'a, P>(
+    &'a self,
+    evt: EventType,
+    predicate: P,
+    timeout: Option<f64>,
+  ) -> impl Future<Output = Result<Event, Error>> + 'a
+  where
+    P: Fn(&Event) -> bool,
+    P: 'a,
   {
-    upgrade(&self.inner)?.set_extra_http_headers(headers).await
+    api_call!("page.wait_for_event", {
+      let inner = upgrade(&self.inner)?;
+      let stream = inner.subscribe_event();
+      let timeout = timeout.map_or_else(|| inner.default_timeout(), |t| t as u32);
+      let predicate = |e: &Evt| predicate(&Event::from(e.clone()));
+      wait_for_event(stream, evt, predicate, timeout)
+        .await
+        .map(Event::from)
+    })
+  }
+
+  /// Subscribes to `evt`, runs `action` and then waits for the first event
+  /// matching `predicate`. Since the subscription precedes the action an event
+  /// fired while the action runs is not missed.
+  async fn expect_with<P, F, T, E>(
+    &self,
+    evt: EventType,
+    predicate: P,
+    action: F,
+  ) -> ArcResult<Event>
+  where
+    P: Fn(&Event) -> bool,
+    F: Future<Output = Result<T, E>>,
+    E: Into<Arc<Error>>,
+  {
+    let inner = upgrade(&self.inner)?;
+    let stream = inner.subscribe_event();
+    let timeout = inner.default_timeout();
+    let predicate = |e: &Evt| predicate(&Event::from(e.clone()));
+    let wait = wait_for_event(stream, evt, predicate, timeout).map_err(Arc::new);
+    // Fails as soon as the action does instead of waiting for the timeout
+    let action = ApiCall::unscoped(action.map_err(Into::into));
+    let (event, _) = futures::try_join!(wait, action)?;
+    Ok(Event::from(event))
+  }
+
+  /// Runs `action` and returns the popup page it opened
+  #[track_caller]
+  pub fn expect_popup<'a, F, T, E>(
+    &'a self,
+    action: F,
+  ) -> impl Future<Output = ArcResult<Page>> + 'a
+  where
+    F: Future<Output = Result<T, E>>,
+    E: Into<Arc<Error>>,
+    F: 'a,
+    T: 'a,
+    E: 'a,
+  {
+    api_call!("page.expect_popup", {
+      match self.expect_with(EventType::Popup, |_| true, action).await? {
+        Event::Popup(x) => Ok(x),
+        _ => unreachable!(),
+      }
+    })
   }
 
-  pub async fn expect_event(&self, evt: EventType) -> Result<Event, Error> {
-    let stream = upgrade(&self.inner)?.subscribe_event();
-    let timeout = upgrade(&self.inner)?.default_timeout();
-    expect_event(stream, evt, timeout).await.map(Event::from)
+  /// Runs `action` and returns the download it started
+  #[track_caller]
+  pub fn expect_download<'a, F, T, E>(
+    &'a self,
+    action: F,
+  ) -> impl Future<Output = ArcResult<Download>> + 'a
+  where
+    F: Future<Output = Result<T, E>>,
+    E: Into<Arc<Error>>,
+    F: 'a,
+    T: 'a,
+    E: 'a,
+  {
+    api_call!("page.expect_download", {
+      match self
+        .expect_with(EventType::Download, |_| true, action)
+        .await?
+      {
+        Event::Download(x) => Ok(x),
+        _ => unreachable!(),
+      }
+    })
+  }
+
+  /// Runs `action` and returns the first request that matches `matcher`
+  #[track_caller]
+  pub fn expect_request<'a, F, T, E>(
+    &'a self,
+    matcher: impl Into<UrlOrPredicate<Request>> + 'a,
+    action: F,
+  ) -> impl Future<Output = ArcResult<Request>> + 'a
+  where
+    F: Future<Output = Result<T, E>>,
+    E: Into<Arc<Error>>,
+    F: 'a,
+    T: 'a,
+    E: 'a,
+  {
+    api_call!("page.expect_request", {
+      let matcher = matcher.into();
+      let predicate = |e: &Event| {
+        match e {
+          Event::Request(x) => matcher.matches(x, x.url().ok().as_deref()),
+          _ => false,
+        }
+      };
+      match self
+        .expect_with(EventType::Request, predicate, action)
+        .await?
+      {
+        Event::Request(x) => Ok(x),
+        _ => unreachable!(),
+      }
+    })
+  }
+
+  /// Runs `action` and returns the first response that matches `matcher`
+  #[track_caller]
+  pub fn expect_response<'a, F, T, E>(
+    &'a self,
+    matcher: impl Into<UrlOrPredicate<Response>> + 'a,
+    action: F,
+  ) -> impl Future<Output = ArcResult<Response>> + 'a
+  where
+    F: Future<Output = Result<T, E>>,
+    E: Into<Arc<Error>>,
+    F: 'a,
+    T: 'a,
+    E: 'a,
+  {
+    api_call!("page.expect_response", {
+      let matcher = matcher.into();
+      let predicate = |e: &Event| {
+        match e {
+          Event::Response(x) => matcher.matches(x, x.url().ok().as_deref()),
+          _ => false,
+        }
+      };
+      match self
+        .expect_with(EventType::Response, predicate, action)
+        .await?
+      {
+        Event::Response(x) => Ok(x),
+        _ => unreachable!(),
+      }
+    })
+  }
+
+  /// Runs `action` and returns the first console message it logged
+  #[track_caller]
+  pub fn expect_console_message<'a, F, T, E>(
+    &'a self,
+    action: F,
+  ) -> impl Future<Output = ArcResult<ConsoleMessage>> + 'a
+  where
+    F: Future<Output = Result<T, E>>,
+    E: Into<Arc<Error>>,
+    F: 'a,
+    T: 'a,
+    E: 'a,
+  {
+    api_call!("page.expect_console_message", {
+      match self
+        .expect_with(EventType::Console, |_| true, action)
+        .await?
+      {
+        Event::Console(x) => Ok(x),
+        _ => unreachable!(),
+      }
+    })
+  }
+
+  /// Runs `action` and returns the file chooser it opened. The native dialog
+  /// is not shown while waiting.
+  #[track_caller]
+  pub fn expect_file_chooser<'a, F, T, E>(
+    &'a self,
+    action: F,
+  ) -> impl Future<Output = ArcResult<FileChooser>> + 'a
+  where
+    F: Future<Output = Result<T, E>>,
+    E: Into<Arc<Error>>,
+    F: 'a,
+    T: 'a,
+    E: 'a,
+  {
+    api_call!("page.expect_file_chooser", {
+      let inner = upgrade(&self.inner)?;
+      inner.set_file_chooser_intercepted(true).await?;
+      let result = self
+        .expect_with(EventType::FileChooser, |_| true, action)
+        .await;
+      inner.set_file_chooser_intercepted(false).await?;
+      match result? {
+        Event::FileChooser(x) => Ok(x),
+        _ => unreachable!(),
+      }
+    })
   }
 
   // coverage
@@ -394,8 +679,11 @@ impl Page {
   // unroute
   // once_dialog
 
-  pub async fn wait_for_timeout(&self, timeout: f64) {
-    sleep(std::time::Duration::from_millis(timeout as u64)).await
+  #[track_caller]
+  pub fn wait_for_timeout<'a>(&'a self, timeout: f64) -> impl Future<Output = ()> + 'a {
+    api_call!("page.wait_for_timeout", {
+      sleep(std::time::Duration::from_millis(timeout as u64)).await
+    })
   }
 }
 
@@ -449,12 +737,23 @@ pub enum Event {
   ///  await fileChooser.setFiles('/tmp/myfile.pdf');
   /// });
   /// ```
-  // FileChooser(FileChooser),
+  FileChooser(FileChooser),
   FrameAttached(Frame),
   FrameDetached(Frame),
   FrameNavigated(Frame),
   Load,
-  PageError,
+  /// Emitted when an uncaught exception happens within the page.
+  ///
+  /// ```js
+  /// // Log all uncaught errors to the terminal
+  /// page.on('pageerror', exception => {
+  ///  console.log(`Uncaught exception: "${exception}"`);
+  /// });
+  ///
+  /// // Navigate to a page with an exception.
+  /// await page.goto('data:text/html,<script>throw new Error("Test")</script>');
+  /// ```
+  PageError(JsError),
   /// Emitted when the page opens a new tab or window. This event is emitted in
   /// addition to the [`event: BrowserContext.page`], but only for popups
   /// relevant to this page.
@@ -505,9 +804,9 @@ impl From<Evt> for Event {
       Evt::Console(x) => Event::Console(ConsoleMessage::new(x)),
       Evt::Dialog => Event::Dialog,
       Evt::Download(x) => Event::Download(Download::new(x)),
-      // Evt::FileChooser(x) => Event::FileChooser(x),
+      Evt::FileChooser(x) => Event::FileChooser(x),
       Evt::DomContentLoaded => Event::DomContentLoaded,
-      Evt::PageError => Event::PageError,
+      Evt::PageError(x) => Event::PageError(x),
       Evt::Request(x) => Event::Request(Request::new(x)),
       Evt::Response(x) => Event::Response(Response::new(x)),
       Evt::RequestFailed(x) => Event::RequestFailed(Request::new(x)),
@@ -534,9 +833,9 @@ impl IsEvent for Event {
       Self::Console(_) => EventType::Console,
       Self::Dialog => EventType::Dialog,
       Self::Download(_) => EventType::Download,
-      // Self::FileChooser(_) => EventType::FileChooser,
+      Self::FileChooser(_) => EventType::FileChooser,
       Self::DomContentLoaded => EventType::DomContentLoaded,
-      Self::PageError => EventType::PageError,
+      Self::PageError(_) => EventType::PageError,
       Self::Request(_) => EventType::Request,
       Self::Response(_) => EventType::Response,
       Self::RequestFailed(_) => EventType::RequestFailed,
@@ -553,11 +852,53 @@ impl IsEvent for Event {
   }
 }
 
+/// Matches a [`Request`] or [`Response`] either by its exact URL or by a
+/// predicate
+pub enum UrlOrPredicate<T> {
+  Url(String),
+  Predicate(Box<dyn Fn(&T) -> bool + Send + Sync>),
+}
+
+impl<T> UrlOrPredicate<T> {
+  pub fn predicate<F>(f: F) -> Self
+  where
+    F: Fn(&T) -> bool + Send + Sync + 'static,
+  {
+    Self::Predicate(Box::new(f))
+  }
+
+  fn matches(&self, x: &T, url: Option<&str>) -> bool {
+    match self {
+      Self::Url(u) => url == Some(u.as_str()),
+      Self::Predicate(f) => f(x),
+    }
+  }
+}
+
+impl<T> From<&str> for UrlOrPredicate<T> {
+  fn from(url: &str) -> Self {
+    Self::Url(url.to_owned())
+  }
+}
+
+impl<T> From<String> for UrlOrPredicate<T> {
+  fn from(url: String) -> Self {
+    Self::Url(url)
+  }
+}
+
 macro_rules! is_checked {
   ($f:ident, $c:meta) => {
     #[$c]
-    pub async fn $f(&self, selector: &str, timeout: Option<f64>) -> ArcResult<bool> {
-      self.main_frame().$f(selector, timeout).await
+    #[track_caller]
+    pub fn $f<'a>(
+      &'a self,
+      selector: &'a str,
+      timeout: Option<f64>,
+    ) -> impl Future<Output = ArcResult<bool>> + 'a {
+      api_call!(concat!("page.", stringify!($f)), {
+        self.main_frame().$f(selector, timeout).await
+      })
     }
   };
 }
@@ -576,108 +917,167 @@ impl Page {
 
   is_checked! {is_visible, doc =""}
 
-  pub async fn query_selector(&self, selector: &str) -> ArcResult<Option<ElementHandle>> {
-    self.main_frame().query_selector(selector).await
+  #[track_caller]
+  pub fn query_selector<'a>(
+    &'a self,
+    selector: &'a str,
+  ) -> impl Future<Output = ArcResult<Option<ElementHandle>>> + 'a {
+    api_call!("page.query_selector", {
+      self.main_frame().query_selector(selector).await
+    })
   }
 
-  pub async fn query_selector_all(&self, selector: &str) -> ArcResult<Vec<ElementHandle>> {
-    self.main_frame().query_selector_all(selector).await
+  #[track_caller]
+  pub fn query_selector_all<'a>(
+    &'a self,
+    selector: &'a str,
+  ) -> impl Future<Output = ArcResult<Vec<ElementHandle>>> + 'a {
+    api_call!("page.query_selector_all", {
+      self.main_frame().query_selector_all(selector).await
+    })
   }
 
-  pub fn wait_for_selector_builder<'a>(&self, selector: &'a str) -> WaitForSelectorBuilder<'a> {
-    self.main_frame().wait_for_selector_builder(selector)
+  pub fn wait_for_selector_builder(&self, selector: &str) -> WaitForSelectorBuilder {
+    self
+      .main_frame()
+      .wait_for_selector_builder(selector)
+      .api_name("page.wait_for_selector")
   }
 
-  pub async fn dispatch_event<T>(
-    &self,
-    selector: &str,
-    r#type: &str,
+  #[track_caller]
+  pub fn dispatch_event<'a, T>(
+    &'a self,
+    selector: &'a str,
+    r#type: &'a str,
     event_init: Option<T>,
-  ) -> ArcResult<()>
+  ) -> impl Future<Output = ArcResult<()>> + 'a
   where
     T: Serialize,
+    T: 'a,
   {
-    // timeout not supported
-    self
-      .main_frame()
-      .dispatch_event(selector, r#type, event_init)
-      .await
-  }
-
-  pub async fn evaluate_js_handle<T>(&self, expression: &str, arg: Option<T>) -> ArcResult<JsHandle>
+    api_call!("page.dispatch_event", {
+      // timeout not supported
+      self
+        .main_frame()
+        .dispatch_event(selector, r#type, event_init)
+        .await
+    })
+  }
+
+  #[track_caller]
+  pub fn evaluate_js_handle<'a, T>(
+    &'a self,
+    expression: &'a str,
+    arg: Option<T>,
+  ) -> impl Future<Output = ArcResult<JsHandle>> + 'a
   where
     T: Serialize,
+    T: 'a,
   {
-    self.main_frame().evaluate_js_handle(expression, arg).await
+    api_call!("page.evaluate_js_handle", {
+      self.main_frame().evaluate_js_handle(expression, arg).await
+    })
   }
 
-  pub async fn evaluate_element_handle<T>(
-    &self,
-    expression: &str,
+  #[track_caller]
+  pub fn evaluate_element_handle<'a, T>(
+    &'a self,
+    expression: &'a str,
     arg: Option<T>,
-  ) -> ArcResult<ElementHandle>
+  ) -> impl Future<Output = ArcResult<ElementHandle>> + 'a
   where
     T: Serialize,
+    T: 'a,
   {
-    self
-      .main_frame()
-      .evaluate_element_handle(expression, arg)
-      .await
+    api_call!("page.evaluate_element_handle", {
+      self
+        .main_frame()
+        .evaluate_element_handle(expression, arg)
+        .await
+    })
   }
 
-  pub async fn eval<U>(&self, expression: &str) -> ArcResult<U>
+  #[track_caller]
+  pub fn eval<'a, U>(&'a self, expression: &'a str) -> impl Future<Output = ArcResult<U>> + 'a
   where
     U: DeserializeOwned,
+    U: 'a,
   {
-    self.main_frame().eval(expression).await
+    api_call!("page.eval", { self.main_frame().eval(expression).await })
   }
 
-  pub async fn evaluate<T, U>(&self, expression: &str, arg: T) -> ArcResult<U>
+  #[track_caller]
+  pub fn evaluate<'a, T, U>(
+    &'a self,
+    expression: &'a str,
+    arg: T,
+  ) -> impl Future<Output = ArcResult<U>> + 'a
   where
     T: Serialize,
     U: DeserializeOwned,
+    T: 'a,
+    U: 'a,
   {
-    self.main_frame().evaluate(expression, arg).await
+    api_call!("page.evaluate", {
+      self.main_frame().evaluate(expression, arg).await
+    })
   }
 
-  pub async fn evaluate_on_selector<T, U>(
-    &self,
-    selector: &str,
-    expression: &str,
+  #[track_caller]
+  pub fn evaluate_on_selector<'a, T, U>(
+    &'a self,
+    selector: &'a str,
+    expression: &'a str,
     arg: Option<T>,
-  ) -> ArcResult<U>
+  ) -> impl Future<Output = ArcResult<U>> + 'a
   where
     T: Serialize,
     U: DeserializeOwned,
+    T: 'a,
+    U: 'a,
   {
-    self
-      .main_frame()
-      .evaluate_on_selector(selector, expression, arg)
-      .await
+    api_call!("page.evaluate_on_selector", {
+      self
+        .main_frame()
+        .evaluate_on_selector(selector, expression, arg)
+        .await
+    })
   }
 
-  pub async fn evaluate_on_selector_all<T, U>(
-    &self,
-    selector: &str,
-    expression: &str,
+  #[track_caller]
+  pub fn evaluate_on_selector_all<'a, T, U>(
+    &'a self,
+    selector: &'a str,
+    expression: &'a str,
     arg: Option<T>,
-  ) -> ArcResult<U>
+  ) -> impl Future<Output = ArcResult<U>> + 'a
   where
     T: Serialize,
     U: DeserializeOwned,
+    T: 'a,
+    U: 'a,
   {
-    self
-      .main_frame()
-      .evaluate_on_selector_all(selector, expression, arg)
-      .await
+    api_call!("page.evaluate_on_selector_all", {
+      self
+        .main_frame()
+        .evaluate_on_selector_all(selector, expression, arg)
+        .await
+    })
   }
 
-  pub fn add_script_tag_builder<'a>(&self, content: &'a str) -> AddScriptTagBuilder<'a, '_, '_> {
-    AddScriptTagBuilder::new(self.main_frame_weak(), content)
+  pub fn add_script_tag_builder(&self, content: &str) -> AddScriptTagBuilder {
+    AddScriptTagBuilder::new(self.main_frame_weak(), content).api_name("page.add_script_tag")
   }
 
-  pub async fn add_style_tag(&self, content: &str, url: Option<&str>) -> ArcResult<ElementHandle> {
-    self.main_frame().add_style_tag(content, url).await
+  #[track_caller]
+  pub fn add_style_tag<'a>(
+    &'a self,
+    content: &'a str,
+    url: Option<&'a str>,
+  ) -> impl Future<Output = ArcResult<ElementHandle>> + 'a {
+    api_call!("page.add_style_tag", {
+      self.main_frame().add_style_tag(content, url).await
+    })
   }
 
   pub fn url(&self) -> Result<String, Error> {
@@ -685,12 +1085,16 @@ impl Page {
   }
 
   /// Gets the full HTML contents of the page, including the doctype.
-  pub async fn content<'a>(&self) -> ArcResult<String> {
-    self.main_frame().content().await
+  #[track_caller]
+  pub fn content<'a>(&'a self) -> impl Future<Output = ArcResult<String>> + 'a {
+    api_call!("page.content", { self.main_frame().content().await })
   }
 
-  pub fn set_content_builder<'a>(&self, html: &'a str) -> SetContentBuilder<'a> {
-    self.main_frame().set_content_builder(html)
+  pub fn set_content_builder(&self, html: &str) -> SetContentBuilder {
+    self
+      .main_frame()
+      .set_content_builder(html)
+      .api_name("page.set_content")
   }
 
   /// Returns the main resource response. In case of multiple redirects, the
@@ -715,104 +1119,261 @@ impl Page {
   /// document. See the [upstream issue](https://bugs.chromium.org/p/chromium/issues/detail?id=761295).
   ///
   /// Shortcut for main frame's [`method: Frame.goto`]
-  pub fn goto_builder<'a>(&self, url: &'a str) -> GotoBuilder<'a, '_> {
-    GotoBuilder::new(self.main_frame_weak(), url)
+  pub fn goto_builder(&self, url: &str) -> GotoBuilder {
+    GotoBuilder::new(self.main_frame_weak(), url).api_name("page.goto")
   }
 
-  // wait_for_load_state
+  /// Waits for the main frame to reach `state`, `load` by default. Returns
+  /// immediately when the state has already been reached.
+  ///
+  /// ```js
+  /// await page.click('button'); // Click triggers navigation.
+  /// await page.waitForLoadState(); // The promise resolves after 'load' event.
+  /// ```
+  #[track_caller]
+  pub fn wait_for_load_state<'a>(
+    &'a self,
+    state: Option<DocumentLoadState>,
+    timeout: Option<f64>,
+  ) -> impl Future<Output = ArcResult<()>> + 'a {
+    api_call!("page.wait_for_load_state", {
+      self.main_frame().wait_for_load_state(state, timeout).await
+    })
+  }
+
+  /// Waits for the main frame to navigate to a URL matching `url`.
+  /// See [`Frame::wait_for_url`].
+  #[track_caller]
+  pub fn wait_for_url<'a, U>(
+    &'a self,
+    url: U,
+    wait_until: Option<DocumentLoadState>,
+    timeout: Option<f64>,
+  ) -> impl Future<Output = ArcResult<()>> + 'a
+  where
+    U: Into<UrlMatcher>,
+    U: 'a,
+  {
+    api_call!("page.wait_for_url", {
+      self
+        .main_frame()
+        .wait_for_url(url, wait_until, timeout)
+        .await
+    })
+  }
+
+  /// Highlights the elements matching `selector` in the main frame. See
+  /// [`Frame::highlight`].
+  #[track_caller]
+  pub fn highlight<'a>(&'a self, selector: &'a str) -> impl Future<Output = ArcResult<()>> + 'a {
+    api_call!("page.highlight", {
+      self.main_frame().highlight(selector).await
+    })
+  }
+
+  /// Pauses script execution. Playwright will stop executing the script and
+  /// wait for the user to either press 'Resume' button in the page overlay or
+  /// to call `playwright.resume()` in the DevTools console.
+  ///
+  /// User can inspect selectors or perform manual steps while paused. Resume
+  /// will continue running the original script from the place it was paused.
+  ///
+  /// > NOTE: This method requires Playwright to be started in a headed mode,
+  /// with a falsy `headless` value in the launch options.
+  #[track_caller]
+  pub fn pause<'a>(&'a self) -> impl Future<Output = ArcResult<()>> + 'a {
+    api_call!("page.pause", {
+      let context = upgrade(&self.inner)?.browser_context();
+      upgrade(&context)?.pause().await
+    })
+  }
 
-  pub async fn title(&self) -> ArcResult<String> {
-    self.main_frame().title().await
+  #[track_caller]
+  pub fn title<'a>(&'a self) -> impl Future<Output = ArcResult<String>> + 'a {
+    api_call!("page.title", { self.main_frame().title().await })
   }
 
-  pub fn click_builder<'a>(&self, selector: &'a str) -> ClickBuilder<'a> {
-    self.main_frame().click_builder(selector)
+  pub fn click_builder(&self, selector: &str) -> ClickBuilder {
+    self
+      .main_frame()
+      .click_builder(selector)
+      .api_name("page.click")
   }
 
-  pub fn dblclick_builder<'a>(&self, selector: &'a str) -> DblClickBuilder<'a> {
-    self.main_frame().dblclick_builder(selector)
+  pub fn dblclick_builder(&self, selector: &str) -> DblClickBuilder {
+    self
+      .main_frame()
+      .dblclick_builder(selector)
+      .api_name("page.dblclick")
   }
 
-  pub fn tap_builder<'a>(&self, selector: &'a str) -> TapBuilder<'a> {
-    self.main_frame().tap_builder(selector)
+  pub fn tap_builder(&self, selector: &str) -> TapBuilder {
+    self.main_frame().tap_builder(selector).api_name("page.tap")
   }
 
-  pub fn fill_builder<'a, 'b>(&self, selector: &'a str, value: &'b str) -> FillBuilder<'a, 'b> {
-    self.main_frame().fill_builder(selector, value)
+  pub fn fill_builder(&self, selector: &str, value: &str) -> FillBuilder {
+    self
+      .main_frame()
+      .fill_builder(selector, value)
+      .api_name("page.fill")
   }
 
-  pub async fn focus(&self, selector: &str, timeout: Option<f64>) -> ArcResult<()> {
-    self.main_frame().focus(selector, timeout).await
+  #[track_caller]
+  pub fn focus<'a>(
+    &'a self,
+    selector: &'a str,
+    timeout: Option<f64>,
+  ) -> impl Future<Output = ArcResult<()>> + 'a {
+    api_call!("page.focus", {
+      self.main_frame().focus(selector, timeout).await
+    })
   }
 
-  pub async fn text_content(
-    &self,
-    selector: &str,
+  #[track_caller]
+  pub fn text_content<'a>(
+    &'a self,
+    selector: &'a str,
     timeout: Option<f64>,
-  ) -> ArcResult<Option<String>> {
-    self.main_frame().text_content(selector, timeout).await
+  ) -> impl Future<Output = ArcResult<Option<String>>> + 'a {
+    api_call!("page.text_content", {
+      self.main_frame().text_content(selector, timeout).await
+    })
   }
 
-  pub async fn inner_text(&self, selector: &str, timeout: Option<f64>) -> ArcResult<String> {
-    self.main_frame().inner_text(selector, timeout).await
+  #[track_caller]
+  pub fn inner_text<'a>(
+    &'a self,
+    selector: &'a str,
+    timeout: Option<f64>,
+  ) -> impl Future<Output = ArcResult<String>> + 'a {
+    api_call!("page.inner_text", {
+      self.main_frame().inner_text(selector, timeout).await
+    })
   }
 
-  pub async fn inner_html(&self, selector: &str, timeout: Option<f64>) -> ArcResult<String> {
-    self.main_frame().inner_html(selector, timeout).await
+  #[track_caller]
+  pub fn inner_html<'a>(
+    &'a self,
+    selector: &'a str,
+    timeout: Option<f64>,
+  ) -> impl Future<Output = ArcResult<String>> + 'a {
+    api_call!("page.inner_html", {
+      self.main_frame().inner_html(selector, timeout).await
+    })
   }
 
-  pub async fn get_attribute(
-    &self,
-    selector: &str,
-    name: &str,
+  #[track_caller]
+  pub fn get_attribute<'a>(
+    &'a self,
+    selector: &'a str,
+    name: &'a str,
     timeout: Option<f64>,
-  ) -> ArcResult<Option<String>> {
+  ) -> impl Future<Output = ArcResult<Option<String>>> + 'a {
+    api_call!("page.get_attribute", {
+      self
+        .main_frame()
+        .get_attribute(selector, name, timeout)
+        .await
+    })
+  }
+
+  pub fn hover_builder(&self, selector: &str) -> HoverBuilder {
+    self
+      .main_frame()
+      .hover_builder(selector)
+      .api_name("page.hover")
+  }
+
+  pub fn drag_and_drop_builder(&self, source: &str, target: &str) -> DragAndDropBuilder {
     self
       .main_frame()
-      .get_attribute(selector, name, timeout)
-      .await
+      .drag_and_drop_builder(source, target)
+      .api_name("page.drag_and_drop")
   }
 
-  pub fn hover_builder<'a>(&self, selector: &'a str) -> HoverBuilder<'a> {
-    self.main_frame().hover_builder(selector)
+  pub fn select_option_builder(&self, selector: &str) -> SelectOptionBuilder {
+    self
+      .main_frame()
+      .select_option_builder(selector)
+      .api_name("page.select_option")
   }
 
-  pub fn select_option_builder<'a>(&self, selector: &'a str) -> SelectOptionBuilder<'a> {
-    self.main_frame().select_option_builder(selector)
+  pub fn set_input_files_builder(&self, selector: &str, file: File) -> SetInputFilesBuilder {
+    self
+      .main_frame()
+      .set_input_files_builder(selector, file)
+      .api_name("page.set_input_files")
   }
 
-  pub fn set_input_files_builder<'a>(
+  pub fn set_input_file_paths_builder<P: AsRef<Path>>(
     &self,
-    selector: &'a str,
-    file: File,
-  ) -> SetInputFilesBuilder<'a> {
-    self.main_frame().set_input_files_builder(selector, file)
+    selector: &str,
+    path: P,
+  ) -> SetInputFilePathsBuilder {
+    self
+      .main_frame()
+      .set_input_file_paths_builder(selector, path)
+      .api_name("page.set_input_file_paths")
   }
 
-  pub fn type_builer<'a, 'b>(&self, selector: &'a str, text: &'b str) -> TypeBuilder<'a, 'b> {
-    self.main_frame().type_builder(selector, text)
+  pub fn type_builer(&self, selector: &str, text: &str) -> TypeBuilder {
+    self
+      .main_frame()
+      .type_builder(selector, text)
+      .api_name("page.type")
   }
 
-  pub fn press_builder<'a, 'b>(&self, selector: &'a str, key: &'b str) -> PressBuilder<'a, 'b> {
-    self.main_frame().press_builder(selector, key)
+  pub fn press_builder(&self, selector: &str, key: &str) -> PressBuilder {
+    self
+      .main_frame()
+      .press_builder(selector, key)
+      .api_name("page.press")
   }
 
-  pub fn check_builder<'a>(&self, selector: &'a str) -> CheckBuilder<'a> {
-    self.main_frame().check_builder(selector)
+  pub fn check_builder(&self, selector: &str) -> CheckBuilder {
+    self
+      .main_frame()
+      .check_builder(selector)
+      .api_name("page.check")
   }
 
-  pub fn uncheck_builder<'a>(&self, selector: &'a str) -> UncheckBuilder<'a> {
-    self.main_frame().uncheck_builder(selector)
+  pub fn uncheck_builder(&self, selector: &str) -> UncheckBuilder {
+    self
+      .main_frame()
+      .uncheck_builder(selector)
+      .api_name("page.uncheck")
   }
 
-  pub fn wait_for_function_builder<'a>(&self, expression: &'a str) -> WaitForFunctionBuilder<'a> {
-    self.main_frame().wait_for_function_builder(expression)
+  pub fn wait_for_function_builder(&self, expression: &str) -> WaitForFunctionBuilder {
+    self
+      .main_frame()
+      .wait_for_function_builder(expression)
+      .api_name("page.wait_for_function")
+  }
+
+  /// Runs `action` and waits for the main frame navigation it causes.
+  /// See [`Frame::expect_navigation`].
+  #[track_caller]
+  pub fn expect_navigation<'a, F, T, E>(
+    &'a self,
+    action: F,
+  ) -> impl Future<Output = ArcResult<Option<Response>>> + 'a
+  where
+    F: Future<Output = Result<T, E>>,
+    E: Into<Arc<Error>>,
+    F: 'a,
+    T: 'a,
+    E: 'a,
+  {
+    api_call!("page.expect_navigation", {
+      self.main_frame().expect_navigation(action).await
+    })
   }
-  // expect_navigation
 }
 
 macro_rules! navigation {
   ($t:ident, $f:ident) => {
+    #[derive(Clone)]
     pub struct $t {
       inner: Weak<Impl>,
       args: ReloadArgs,
@@ -833,12 +1394,17 @@ macro_rules! navigation {
         Self { inner, args }
       }
 
-      pub async fn $f(self) -> ArcResult<Option<Response>> {
-        let Self { inner, args } = self;
-        let r = upgrade(&inner)?.$f(args).await?;
-        Ok(r.map(Response::new))
+      #[track_caller]
+      pub fn $f(self) -> impl Future<Output = ArcResult<Option<Response>>> {
+        api_call!(concat!("page.", stringify!($f)), {
+          let Self { inner, args } = self;
+          let r = upgrade(&inner)?.$f(args).await?;
+          Ok(r.map(Response::new))
+        })
       }
     }
+
+    into_future!($t, $f, ArcResult<Option<Response>>);
   };
 }
 
@@ -846,12 +1412,13 @@ navigation!(ReloadBuilder, reload);
 navigation!(GoBackBuilder, go_back);
 navigation!(GoForwardBuilder, go_forward);
 
-pub struct PdfBuilder<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j> {
+#[derive(Clone)]
+pub struct PdfBuilder {
   inner: Weak<Impl>,
-  args: PdfArgs<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j>,
+  args: PdfArgs,
 }
 
-impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j> PdfBuilder<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j> {
+impl PdfBuilder {
   setter! {
       /// Scale of the webpage rendering. Defaults to `1`. Scale amount must be between 0.1 and 2.
       scale: Option<f64>,
@@ -864,26 +1431,26 @@ impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j> PdfBuilder<'a, 'b, 'c, 'd, 'e, 'f,
       /// - `'url'` document location
       /// - `'pageNumber'` current page number
       /// - `'totalPages'` total pages in the document
-      header_template: Option<&'a str>,
+      header_template: Option<String>,
       /// HTML template for the print footer. Should use the same format as the `headerTemplate`.
-      footer_template: Option<&'b str>,
+      footer_template: Option<String>,
       /// Print background graphics. Defaults to `false`.
       print_background: Option<bool>,
       /// Paper orientation. Defaults to `false`.
       landscape: Option<bool>,
       /// Paper ranges to print, e.g., '1-5, 8, 11-13'. Defaults to the empty string, which means print all pages.
-      page_ranges: Option<&'c str>,
+      page_ranges: Option<String>,
       /// Paper format. If set, takes priority over `width` or `height` options. Defaults to 'Letter'.
-      format: Option<&'d str>,
+      format: Option<PaperFormat>,
       /// Paper width, accepts values labeled with units.
-      width: Option<Length<'e>>,
+      width: Option<Length>,
       /// Paper height, accepts values labeled with units.
-      height: Option<Length<'f>>,
+      height: Option<Length>,
       /// Give any CSS `@page` size declared in the page priority over what is declared in `width` and `height` or `format`
       /// options. Defaults to `false`, which will scale the content to fit the paper size.
       prefer_css_page_size: Option<bool>,
       /// Paper margins, defaults to none.
-      margin: Option<PdfMargins<'g, 'h, 'i, 'j>>,
+      margin: Option<PdfMargins>,
       /// The file path to save the PDF to. If `path` is a relative path, then it is resolved relative to the current working
       /// directory. If no path is provided, the PDF won't be saved to the disk.
       path: Option<PathBuf>
@@ -894,13 +1461,18 @@ impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j> PdfBuilder<'a, 'b, 'c, 'd, 'e, 'f,
     Self { inner, args }
   }
 
-  pub async fn pdf(self) -> Result<(), Arc<Error>> {
-    let Self { inner, args } = self;
-    let _ = upgrade(&inner)?.pdf(args).await?;
-    Ok(())
+  #[track_caller]
+  pub fn pdf(self) -> impl Future<Output = ArcResult<Vec<u8>>> {
+    api_call!("page.pdf", {
+      let Self { inner, args } = self;
+      upgrade(&inner)?.pdf(args).await
+    })
   }
 }
 
+into_future!(PdfBuilder, pdf, ArcResult<Vec<u8>>);
+
+#[derive(Clone)]
 pub struct ScreenshotBuilder {
   inner: Weak<Impl>,
   args: ScreenshotArgs,
@@ -908,6 +1480,13 @@ pub struct ScreenshotBuilder {
 
 impl ScreenshotBuilder {
   setter! {
+      /// When set to `disabled`, stops CSS animations, CSS transitions and Web Animations. Finite animations are fast-forwarded to
+      /// completion, so they'll fire `transitionend` event. Infinite animations are canceled to initial state, and then played
+      /// over after the screenshot. Defaults to `allow` that leaves animations untouched.
+      animations: Option<ScreenshotAnimations>,
+      /// When set to `hide`, screenshot will hide text caret. When set to `initial`, text caret behavior will not be changed.
+      /// Defaults to `hide`.
+      caret: Option<ScreenshotCaret>,
       /// An object which specifies clipping of the resulting image. Should have the following fields:
       clip: Option<FloatRect>,
       /// When true, takes a screenshot of the full scrollable page, instead of the currently visible viewport. Defaults to
@@ -917,6 +1496,10 @@ impl ScreenshotBuilder {
       /// Defaults to `false`.
       omit_background: Option<bool>,
       quality: Option<i32>,
+      /// When set to `css`, screenshot will have a single pixel per each css pixel on the page. For high-dpi devices, this will
+      /// keep screenshots small. Using `device` option will produce a single pixel per each device pixel, so screenshots of
+      /// high-dpi devices will be twice as large or even larger. Defaults to `device`.
+      scale: Option<ScreenshotScale>,
       /// Maximum time in milliseconds, defaults to 30 seconds, pass `0` to disable timeout. The default value can be changed by
       /// using the [`method: BrowserContext.setDefaultTimeout`] or [`method: Page.setDefaultTimeout`] methods.
       timeout: Option<f64>,
@@ -931,9 +1514,34 @@ impl ScreenshotBuilder {
     Self { inner, args }
   }
 
-  pub async fn screenshot(self) -> ArcResult<Vec<u8>> {
-    let Self { inner, args } = self;
-    upgrade(&inner)?.screenshot(args).await
+  #[track_caller]
+  pub fn screenshot(self) -> impl Future<Output = ArcResult<Vec<u8>>> {
+    api_call!("page.screenshot", {
+      let Self { inner, args } = self;
+      upgrade(&inner)?.screenshot(args).await
+    })
+  }
+
+  /// Covers the elements matching `selector` in `frame` with a pink box
+  /// `#FF00FF` that completely covers their bounding box.
+  pub fn mask_selector(mut self, frame: &Frame, selector: &str) -> Self {
+    self
+      .args
+      .masks
+      .push(Mask::Selector(frame.inner(), selector.to_owned()));
+    self
+  }
+
+  /// Covers `element` with a pink box `#FF00FF` that completely covers its
+  /// bounding box.
+  pub fn mask_element(mut self, element: &ElementHandle) -> Self {
+    self.args.masks.push(Mask::Element(element.inner()));
+    self
+  }
+
+  pub fn clear_mask(mut self) -> Self {
+    self.args.masks = vec![];
+    self
   }
 
   pub fn r#type(mut self, x: ScreenshotType) -> Self {
@@ -947,6 +1555,9 @@ impl ScreenshotBuilder {
   }
 }
 
+into_future!(ScreenshotBuilder, screenshot, ArcResult<Vec<u8>>);
+
+#[derive(Clone)]
 pub struct EmulateMediaBuilder {
   inner: Weak<Impl>,
   args: EmulateMediaArgs,
@@ -967,8 +1578,13 @@ impl EmulateMediaBuilder {
     Self { inner, args }
   }
 
-  pub async fn emulate_media(self) -> ArcResult<()> {
-    let Self { inner, args } = self;
-    upgrade(&inner)?.emulate_media(args).await
+  #[track_caller]
+  pub fn emulate_media(self) -> impl Future<Output = ArcResult<()>> {
+    api_call!("page.emulate_media", {
+      let Self { inner, args } = self;
+      upgrade(&inner)?.emulate_media(args).await
+    })
   }
 }
+
+into_future!(EmulateMediaBuilder, emulate_media, ArcResult<()>);
@@ -2,6 +2,7 @@ use std::io;
 use std::process::Command;
 
 use crate::api::browser_type::BrowserType;
+use crate::api::electron::Electron;
 use crate::api::selectors::Selectors;
 use crate::imp::core::*;
 pub use crate::imp::playwright::DeviceDescriptor;
@@ -86,6 +87,24 @@ impl Playwright {
     &mut self.driver
   }
 
+  /// **Experimental** Electron automation
+  pub fn electron(&self) -> Electron {
+    let inner = weak_and_then(&self.inner, |rc| rc.electron());
+    Electron::new(inner)
+  }
+
+  /// Removes the highlights added by [`Frame::highlight`] and
+  /// [`Page::highlight`].
+  ///
+  /// [`Frame::highlight`]: crate::api::Frame::highlight
+  /// [`Page::highlight`]: crate::api::Page::highlight
+  #[track_caller]
+  pub fn hide_highlight<'a>(&'a self) -> impl Future<Output = ArcResult<()>> + 'a {
+    api_call!("playwright.hide_highlight", {
+      upgrade(&self.inner)?.hide_highlight().await
+    })
+  }
+
   pub fn selectors(&self) -> Selectors {
     let inner = weak_and_then(&self.inner, |rc| rc.selectors());
     Selectors::new(inner)
@@ -3,6 +3,8 @@ use crate::api::Response;
 use crate::imp::core::*;
 use crate::imp::prelude::*;
 use crate::imp::request::Request as Impl;
+use crate::imp::utils::Header;
+use crate::imp::utils::RequestSizes;
 use crate::imp::utils::ResponseTiming;
 
 /// Whenever the page sends a request for a network resource the following
@@ -86,6 +88,71 @@ impl Request {
     Ok(upgrade(&self.inner)?.headers().clone())
   }
 
+  /// An object with all the request HTTP headers associated with this request,
+  /// including ones added by the browser. Header names are lower-cased.
+  #[track_caller]
+  pub fn all_headers<'a>(
+    &'a self,
+  ) -> impl Future<Output = ArcResult<HashMap<String, String>>> + 'a {
+    api_call!("request.all_headers", {
+      Ok(upgrade(&self.inner)?.raw_headers().await?.to_map())
+    })
+  }
+
+  /// An array with all the request HTTP headers associated with this request.
+  /// Unlike [`Request::all_headers`], header names are not lower-cased.
+  /// Headers with multiple entries, such as `Set-Cookie`, appear in the array
+  /// multiple times.
+  #[track_caller]
+  pub fn headers_array<'a>(&'a self) -> impl Future<Output = ArcResult<Vec<Header>>> + 'a {
+    api_call!("request.headers_array", {
+      Ok(
+        upgrade(&self.inner)?
+          .raw_headers()
+          .await?
+          .headers_array()
+          .to_vec(),
+      )
+    })
+  }
+
+  /// Returns the value of the header matching the name. The name is
+  /// case-insensitive.
+  #[track_caller]
+  pub fn header_value<'a>(
+    &'a self,
+    name: &'a str,
+  ) -> impl Future<Output = ArcResult<Option<String>>> + 'a {
+    api_call!("request.header_value", {
+      Ok(upgrade(&self.inner)?.raw_headers().await?.get(name))
+    })
+  }
+
+  /// Returns all values of the header matching the name. The name is
+  /// case-insensitive.
+  #[track_caller]
+  pub fn header_values<'a>(
+    &'a self,
+    name: &'a str,
+  ) -> impl Future<Output = ArcResult<Vec<String>>> + 'a {
+    api_call!("request.header_values", {
+      let headers = upgrade(&self.inner)?.raw_headers().await?;
+      Ok(headers.get_all(name).into_iter().map(Into::into).collect())
+    })
+  }
+
+  /// Returns resource size information for given request.
+  #[track_caller]
+  pub fn sizes<'a>(&'a self) -> impl Future<Output = ArcResult<RequestSizes>> + 'a {
+    api_call!("request.sizes", {
+      let response = upgrade(&self.inner)?
+        .response()
+        .await?
+        .ok_or(Error::ResponseNotFound)?;
+      upgrade(&response)?.sizes().await
+    })
+  }
+
   /// Request that was redirected by the server to this one, if any.
   ///
   /// When the server responds with a redirect, Playwright creates a new
@@ -111,14 +178,20 @@ impl Request {
     Ok(upgrade(&self.inner)?.redirected_from().map(Request::new))
   }
 
-  pub async fn redirected_to(&self) -> Result<Option<Request>, Error> {
-    Ok(upgrade(&self.inner)?.redirected_to().map(Request::new))
+  #[track_caller]
+  pub fn redirected_to<'a>(&'a self) -> impl Future<Output = Result<Option<Request>, Error>> + 'a {
+    api_call!("request.redirected_to", {
+      Ok(upgrade(&self.inner)?.redirected_to().map(Request::new))
+    })
   }
 
   /// Returns the matching `Response` object, or `null` if the response was not
   /// received due to error.
-  pub async fn response(&self) -> Result<Option<Response>, Arc<Error>> {
-    Ok(upgrade(&self.inner)?.response().await?.map(Response::new))
+  #[track_caller]
+  pub fn response<'a>(&'a self) -> impl Future<Output = Result<Option<Response>, Arc<Error>>> + 'a {
+    api_call!("request.response", {
+      Ok(upgrade(&self.inner)?.response().await?.map(Response::new))
+    })
   }
 
   /// The method returns `null` unless this request has failed, as reported by
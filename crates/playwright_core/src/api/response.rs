@@ -4,6 +4,9 @@ use crate::imp::core::*;
 use crate::imp::prelude::*;
 use crate::imp::response::Response as Impl;
 use crate::imp::utils::Header;
+use crate::imp::utils::RemoteAddr;
+use crate::imp::utils::RequestSizes;
+use crate::imp::utils::SecurityDetails;
 
 #[derive(Debug, Clone)]
 pub struct Response {
@@ -53,23 +56,111 @@ impl Response {
 
   /// Waits for this response to finish, returns failure error if request
   /// failed.
-  pub async fn finished(&self) -> ArcResult<Option<String>> {
-    upgrade(&self.inner)?.finished().await
+  #[track_caller]
+  pub fn finished<'a>(&'a self) -> impl Future<Output = ArcResult<Option<String>>> + 'a {
+    api_call!("response.finished", {
+      upgrade(&self.inner)?.finished().await
+    })
   }
 
-  pub async fn body(&self) -> ArcResult<Vec<u8>> {
-    upgrade(&self.inner)?.body().await
+  #[track_caller]
+  pub fn body<'a>(&'a self) -> impl Future<Output = ArcResult<Vec<u8>>> + 'a {
+    api_call!("response.body", { upgrade(&self.inner)?.body().await })
   }
 
   /// Returns the text representation of response body.
-  pub async fn text(&self) -> ArcResult<String> {
-    upgrade(&self.inner)?.text().await
+  #[track_caller]
+  pub fn text<'a>(&'a self) -> impl Future<Output = ArcResult<String>> + 'a {
+    api_call!("response.text", { upgrade(&self.inner)?.text().await })
   }
 
   /// Returns the object with HTTP headers associated with the response. All
   /// header names are lower-case.
-  pub async fn headers(&self) -> ArcResult<Vec<Header>> {
-    upgrade(&self.inner)?.headers().await
+  #[track_caller]
+  pub fn headers<'a>(&'a self) -> impl Future<Output = ArcResult<Vec<Header>>> + 'a {
+    api_call!("response.headers", {
+      Ok(upgrade(&self.inner)?.headers().to_vec())
+    })
+  }
+
+  /// An object with all the response HTTP headers associated with this
+  /// response. Header names are lower-cased and the values of `Set-Cookie`s are
+  /// joined with `\n`.
+  #[track_caller]
+  pub fn all_headers<'a>(
+    &'a self,
+  ) -> impl Future<Output = ArcResult<HashMap<String, String>>> + 'a {
+    api_call!("response.all_headers", {
+      Ok(upgrade(&self.inner)?.raw_headers().await?.to_map())
+    })
+  }
+
+  /// An array with all the response HTTP headers associated with this
+  /// response. Unlike [`Response::all_headers`], header names are not
+  /// lower-cased. Headers with multiple entries, such as `Set-Cookie`, appear
+  /// in the array multiple times.
+  #[track_caller]
+  pub fn headers_array<'a>(&'a self) -> impl Future<Output = ArcResult<Vec<Header>>> + 'a {
+    api_call!("response.headers_array", {
+      Ok(
+        upgrade(&self.inner)?
+          .raw_headers()
+          .await?
+          .headers_array()
+          .to_vec(),
+      )
+    })
+  }
+
+  /// Returns the value of the header matching the name. The name is
+  /// case-insensitive. If multiple headers have the same name (except
+  /// `set-cookie`), they are returned as a list separated by `, `. For
+  /// `set-cookie`, the `\n` separator is used.
+  #[track_caller]
+  pub fn header_value<'a>(
+    &'a self,
+    name: &'a str,
+  ) -> impl Future<Output = ArcResult<Option<String>>> + 'a {
+    api_call!("response.header_value", {
+      Ok(upgrade(&self.inner)?.raw_headers().await?.get(name))
+    })
+  }
+
+  /// Returns all values of the headers matching the name, for example
+  /// `set-cookie`. The name is case-insensitive.
+  #[track_caller]
+  pub fn header_values<'a>(
+    &'a self,
+    name: &'a str,
+  ) -> impl Future<Output = ArcResult<Vec<String>>> + 'a {
+    api_call!("response.header_values", {
+      let headers = upgrade(&self.inner)?.raw_headers().await?;
+      Ok(headers.get_all(name).into_iter().map(Into::into).collect())
+    })
+  }
+
+  /// Returns SSL and other security information.
+  #[track_caller]
+  pub fn security_details<'a>(
+    &'a self,
+  ) -> impl Future<Output = ArcResult<Option<SecurityDetails>>> + 'a {
+    api_call!("response.security_details", {
+      upgrade(&self.inner)?.security_details().await
+    })
+  }
+
+  /// Returns the IP address and port of the server.
+  #[track_caller]
+  pub fn server_addr<'a>(&'a self) -> impl Future<Output = ArcResult<Option<RemoteAddr>>> + 'a {
+    api_call!("response.server_addr", {
+      upgrade(&self.inner)?.server_addr().await
+    })
+  }
+
+  /// Returns resource size information for the request of this response.
+  #[track_caller]
+  pub fn sizes<'a>(&'a self) -> impl Future<Output = ArcResult<RequestSizes>> + 'a {
+    api_call!("response.sizes", { upgrade(&self.inner)?.sizes().await })
   }
 
   /// Shortcut for [`Response::request`]'s  [`Request::frame`]
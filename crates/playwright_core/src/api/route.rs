@@ -1,3 +1,6 @@
+use futures::future::BoxFuture;
+use futures::FutureExt;
+
 use crate::api::Header;
 use crate::api::Request;
 use crate::imp::core::*;
@@ -58,9 +61,15 @@ impl Route {
   /// - `'namenotresolved'` - The host name could not be resolved.
   /// - `'timedout'` - An operation timed out.
   /// - `'failed'` - A generic failure occurred.
-  pub async fn abort(&self, err_code: Option<&str>) -> Result<(), Arc<Error>> {
-    let inner = upgrade(&self.inner)?;
-    inner.abort(err_code).await
+  #[track_caller]
+  pub fn abort<'a>(
+    &'a self,
+    err_code: Option<&'a str>,
+  ) -> impl Future<Output = Result<(), Arc<Error>>> + 'a {
+    api_call!("route.abort", {
+      let inner = upgrade(&self.inner)?;
+      inner.abort(err_code).await
+    })
   }
 
   /// Fulfills route's request with given response.
@@ -119,9 +128,17 @@ impl<'a, 'b> FulfillBuilder<'a, 'b> {
     Self { inner, args }
   }
 
-  pub async fn fulfill(self) -> Result<(), Arc<Error>> {
-    let Self { inner, args } = self;
-    upgrade(&inner)?.fulfill(args).await
+  #[track_caller]
+  pub fn fulfill<'c>(self) -> BoxFuture<'c, Result<(), Arc<Error>>>
+  where
+    'a: 'c,
+    'b: 'c,
+  {
+    api_call!("route.fulfill", {
+      let Self { inner, args } = self;
+      upgrade(&inner)?.fulfill(args).await
+    })
+    .boxed()
   }
 
   /// Response headers. Header values will be converted to a string.
@@ -159,9 +176,18 @@ impl<'a, 'b, 'c> ContinueBuilder<'a, 'b, 'c> {
     Self { inner, args }
   }
 
-  pub async fn r#continue(self) -> Result<(), Arc<Error>> {
-    let Self { inner, args } = self;
-    upgrade(&inner)?.r#continue(args).await
+  #[track_caller]
+  pub fn r#continue<'d>(self) -> BoxFuture<'d, Result<(), Arc<Error>>>
+  where
+    'a: 'd,
+    'b: 'd,
+    'c: 'd,
+  {
+    api_call!("route.continue", {
+      let Self { inner, args } = self;
+      upgrade(&inner)?.r#continue(args).await
+    })
+    .boxed()
   }
 
   /// If set changes the request HTTP headers. Header values will be converted
@@ -62,13 +62,16 @@ impl Selectors {
   /// objects from the frame's scripts. Defaults to `false`. Note that running
   /// as a content script is not guaranteed when this engine is used together
   /// with other registered engines.
-  pub async fn register(
-    &self,
-    name: &str,
-    script: &str,
+  #[track_caller]
+  pub fn register<'a>(
+    &'a self,
+    name: &'a str,
+    script: &'a str,
     content_script: bool,
-  ) -> Result<(), Arc<Error>> {
-    let inner = upgrade(&self.inner)?;
-    inner.register(name, script, content_script).await
+  ) -> impl Future<Output = Result<(), Arc<Error>>> + 'a {
+    api_call!("selectors.register", {
+      let inner = upgrade(&self.inner)?;
+      inner.register(name, script, content_script).await
+    })
   }
 }
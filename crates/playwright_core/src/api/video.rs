@@ -16,13 +16,21 @@ impl Video {
     self.inner.path()
   }
 
-  // doesn't work with this version
-  async fn save_as<P: AsRef<Path>>(&self, path: P) -> ArcResult<()> {
-    self.inner.save_as(path).await
+  /// Saves the video to a user-specified path. It is safe to call this
+  /// method while the video is still in progress, or after the page has
+  /// closed. This method waits until the page is closed and the video is
+  /// fully saved.
+  #[track_caller]
+  pub fn save_as<'a, P: AsRef<Path>>(&'a self, path: P) -> impl Future<Output = ArcResult<()>> + 'a
+  where
+    P: 'a,
+  {
+    api_call!("video.save_as", { self.inner.save_as(path).await })
   }
 
-  // doesn't work with this version
-  async fn delete(&self) -> ArcResult<()> {
-    self.inner.delete().await
+  /// Deletes the video file. Will wait for the video to finish if necessary.
+  #[track_caller]
+  pub fn delete<'a>(&'a self) -> impl Future<Output = ArcResult<()>> + 'a {
+    api_call!("video.delete", { self.inner.delete().await })
   }
 }
@@ -1,6 +1,7 @@
 use crate::imp::core::*;
 use crate::imp::prelude::*;
 pub use crate::imp::websocket::Buffer;
+pub use crate::imp::websocket::EventType;
 use crate::imp::websocket::Evt;
 use crate::imp::websocket::WebSocket as Impl;
 
@@ -22,6 +23,8 @@ impl PartialEq for WebSocket {
 impl WebSocket {
   subscribe_event! {}
 
+  event_handlers! {}
+
   pub(crate) fn new(inner: Weak<Impl>) -> Self {
     Self { inner }
   }
@@ -1,6 +1,7 @@
 use crate::api::JsHandle;
 use crate::imp::core::*;
 use crate::imp::prelude::*;
+pub use crate::imp::worker::EventType;
 use crate::imp::worker::Evt;
 use crate::imp::worker::Worker as Impl;
 
@@ -18,7 +19,7 @@ use crate::imp::worker::Worker as Impl;
 /// for (const worker of page.workers())
 ///  console.log('  ' + worker.url());
 /// ```
-#[derive(Clone)]
+#[derive(Debug, Clone)]
 pub struct Worker {
   inner: Weak<Impl>,
 }
@@ -36,6 +37,8 @@ impl PartialEq for Worker {
 impl Worker {
   subscribe_event! {}
 
+  event_handlers! {}
+
   pub(crate) fn new(inner: Weak<Impl>) -> Self {
     Self { inner }
   }
@@ -44,36 +47,63 @@ impl Worker {
     Ok(upgrade(&self.inner)?.url().to_owned())
   }
 
-  pub async fn eval_handle(&self, expression: &str) -> ArcResult<JsHandle> {
-    upgrade(&self.inner)?
-      .eval_handle(expression)
-      .await
-      .map(JsHandle::new)
+  #[track_caller]
+  pub fn eval_handle<'a>(
+    &'a self,
+    expression: &'a str,
+  ) -> impl Future<Output = ArcResult<JsHandle>> + 'a {
+    api_call!("worker.eval_handle", {
+      upgrade(&self.inner)?
+        .eval_handle(expression)
+        .await
+        .map(JsHandle::new)
+    })
   }
 
-  pub async fn evaluate_handle<T>(&self, expression: &str, arg: Option<T>) -> ArcResult<JsHandle>
+  #[track_caller]
+  pub fn evaluate_handle<'a, T>(
+    &'a self,
+    expression: &'a str,
+    arg: Option<T>,
+  ) -> impl Future<Output = ArcResult<JsHandle>> + 'a
   where
     T: Serialize,
+    T: 'a,
   {
-    upgrade(&self.inner)?
-      .evaluate_handle(expression, arg)
-      .await
-      .map(JsHandle::new)
+    api_call!("worker.evaluate_handle", {
+      upgrade(&self.inner)?
+        .evaluate_handle(expression, arg)
+        .await
+        .map(JsHandle::new)
+    })
   }
 
-  pub async fn eval<U>(&self, expression: &str) -> ArcResult<U>
+  #[track_caller]
+  pub fn eval<'a, U>(&'a self, expression: &'a str) -> impl Future<Output = ArcResult<U>> + 'a
   where
     U: DeserializeOwned,
+    U: 'a,
   {
-    upgrade(&self.inner)?.eval(expression).await
+    api_call!("worker.eval", {
+      upgrade(&self.inner)?.eval(expression).await
+    })
   }
 
-  pub async fn evaluate<T, U>(&self, expression: &str, arg: Option<T>) -> ArcResult<U>
+  #[track_caller]
+  pub fn evaluate<'a, T, U>(
+    &'a self,
+    expression: &'a str,
+    arg: Option<T>,
+  ) -> impl Future<Output = ArcResult<U>> + 'a
   where
     T: Serialize,
     U: DeserializeOwned,
+    T: 'a,
+    U: 'a,
   {
-    upgrade(&self.inner)?.evaluate(expression, arg).await
+    api_call!("worker.evaluate", {
+      upgrade(&self.inner)?.evaluate(expression, arg).await
+    })
   }
 }
 
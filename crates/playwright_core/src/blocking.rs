@@ -0,0 +1,921 @@
+//! Synchronous facade over [`crate::api`]
+//!
+//! Every object owns a handle to an internal tokio runtime whose worker thread
+//! drives the connection to the driver, so these types can be used from plain
+//! `fn main()` without any executor. Methods block the calling thread until the
+//! underlying call resolves.
+//!
+//! The async object is always reachable with `as_async()`, and `block_on` runs
+//! any future (for example a builder with non-default options) to completion.
+//! Calling into this module from inside an async context panics, like
+//! [`tokio::runtime::Runtime::block_on`].
+//!
+//! ```no_run
+//! use playwright_core::blocking::Playwright;
+//!
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!   let playwright = Playwright::initialize()?;
+//!   playwright.prepare()?;
+//!   let browser = playwright.chromium().launch()?;
+//!   let context = browser.new_context()?;
+//!   let page = context.new_page()?;
+//!   page.goto("https://example.com/")?;
+//!   println!("{}", page.title()?);
+//!   browser.close()?;
+//!   Ok(())
+//! }
+//! ```
+use std::io;
+
+use futures::executor::block_on_stream;
+use futures::executor::BlockingStream;
+use futures::stream::Stream;
+use tokio::runtime::Runtime;
+use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
+
+use crate::api;
+use crate::api::Response;
+use crate::imp::core::*;
+use crate::imp::prelude::*;
+use crate::Driver;
+use crate::Error;
+
+fn runtime() -> io::Result<Runtime> {
+  tokio::runtime::Builder::new_multi_thread()
+    .worker_threads(1)
+    .thread_name("playwright-blocking")
+    .enable_all()
+    .build()
+}
+
+/// Blocking iterator over the events of an object.
+///
+/// Each call to `next` parks the current thread until the next event arrives.
+/// Returns `None` once the object is closed and its channel dropped. The
+/// iterator is `Send`, so it can be handed to a thread that waits for events.
+pub struct Events<E> {
+  inner: BlockingStream<Pin<Box<dyn Stream<Item = Result<E, BroadcastStreamRecvError>> + Send>>>,
+}
+
+impl<E> Events<E> {
+  fn new<S>(stream: S) -> Self
+  where
+    S: Stream<Item = Result<E, BroadcastStreamRecvError>> + Send + 'static,
+  {
+    let stream: Pin<Box<dyn Stream<Item = _> + Send>> = Box::pin(stream);
+    Self {
+      inner: block_on_stream(stream),
+    }
+  }
+}
+
+impl<E> Iterator for Events<E> {
+  type Item = Result<E, BroadcastStreamRecvError>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    self.inner.next()
+  }
+}
+
+macro_rules! blocking_common {
+  ($t:ident) => {
+    /// Returns the wrapped async object
+    pub fn as_async(&self) -> &api::$t {
+      &self.inner
+    }
+
+    /// Runs a future on the internal runtime and waits for its output
+    pub fn block_on<F>(&self, f: F) -> F::Output
+    where
+      F: Future,
+    {
+      self.rt.block_on(f)
+    }
+  };
+}
+
+/// Entry point. Clones share the connection to the driver.
+#[derive(Clone)]
+pub struct Playwright {
+  rt: Arc<Runtime>,
+  inner: Arc<api::Playwright>,
+}
+
+impl Playwright {
+  blocking_common! {Playwright}
+
+  /// Installs playwright driver to
+  /// "$CACHE_DIR/.ms-playwright/playwright-rust/driver"
+  pub fn initialize() -> Result<Playwright, Error> {
+    let driver = Driver::install()?;
+    Self::with_driver(driver)
+  }
+
+  /// Constructs from installed playwright driver
+  #[track_caller]
+  pub fn with_driver(driver: Driver) -> Result<Playwright, Error> {
+    let rt = Arc::new(runtime()?);
+    let inner = Arc::new(rt.block_on(api::Playwright::with_driver(driver))?);
+    Ok(Self { rt, inner })
+  }
+
+  /// Runs $ playwright install
+  pub fn prepare(&self) -> io::Result<()> {
+    self.inner.prepare()
+  }
+
+  /// Runs $ playwright install chromium
+  pub fn install_chromium(&self) -> io::Result<()> {
+    self.inner.install_chromium()
+  }
+
+  pub fn install_firefox(&self) -> io::Result<()> {
+    self.inner.install_firefox()
+  }
+
+  pub fn install_webkit(&self) -> io::Result<()> {
+    self.inner.install_webkit()
+  }
+
+  /// Launcher
+  pub fn chromium(&self) -> BrowserType {
+    BrowserType::new(self.rt.clone(), self.inner.chromium())
+  }
+
+  /// Launcher
+  pub fn firefox(&self) -> BrowserType {
+    BrowserType::new(self.rt.clone(), self.inner.firefox())
+  }
+
+  /// Launcher
+  pub fn webkit(&self) -> BrowserType {
+    BrowserType::new(self.rt.clone(), self.inner.webkit())
+  }
+
+  pub fn devices(&self) -> Vec<api::playwright::DeviceDescriptor> {
+    self.inner.devices()
+  }
+
+  pub fn device(&self, name: &str) -> Option<api::playwright::DeviceDescriptor> {
+    self.inner.device(name)
+  }
+
+  #[track_caller]
+  pub fn hide_highlight(&self) -> ArcResult<()> {
+    self.rt.block_on(self.inner.hide_highlight())
+  }
+}
+
+#[derive(Clone)]
+pub struct BrowserType {
+  rt: Arc<Runtime>,
+  inner: api::BrowserType,
+}
+
+impl BrowserType {
+  blocking_common! {BrowserType}
+
+  fn new(rt: Arc<Runtime>, inner: api::BrowserType) -> Self {
+    Self { rt, inner }
+  }
+
+  /// Returns browser name. For example: `'chromium'`, `'webkit'` or
+  /// `'firefox'`.
+  pub fn name(&self) -> Result<String, Error> {
+    self.inner.name()
+  }
+
+  /// A path where Playwright expects to find a bundled browser executable.
+  pub fn executable(&self) -> Result<PathBuf, Error> {
+    self.inner.executable()
+  }
+
+  /// Launches a browser with the default options
+  pub fn launch(&self) -> ArcResult<Browser> {
+    self.launch_with(|l| l)
+  }
+
+  /// Launches a browser with the options set on the given launcher
+  ///
+  /// ```no_run
+  /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+  /// # let playwright = playwright_core::blocking::Playwright::initialize()?;
+  /// let browser = playwright
+  ///   .firefox()
+  ///   .launch_with(|l| l.headless(false).slowmo(100.))?;
+  /// # Ok(())
+  /// # }
+  /// ```
+  #[track_caller]
+  pub fn launch_with<F>(&self, f: F) -> ArcResult<Browser>
+  where
+    F: FnOnce(api::browser_type::Launcher) -> api::browser_type::Launcher,
+  {
+    let b = self.rt.block_on(f(self.inner.launcher()).launch())?;
+    Ok(Browser::new(self.rt.clone(), b))
+  }
+}
+
+#[derive(Clone)]
+pub struct Browser {
+  rt: Arc<Runtime>,
+  inner: api::Browser,
+}
+
+impl Browser {
+  blocking_common! {Browser}
+
+  fn new(rt: Arc<Runtime>, inner: api::Browser) -> Self {
+    Self { rt, inner }
+  }
+
+  /// Returns an array of all open browser contexts.
+  pub fn contexts(&self) -> Result<Vec<BrowserContext>, Error> {
+    let cs = self.inner.contexts()?;
+    Ok(
+      cs.into_iter()
+        .map(|c| BrowserContext::new(self.rt.clone(), c))
+        .collect(),
+    )
+  }
+
+  /// Returns the browser version.
+  pub fn version(&self) -> Result<String, Error> {
+    self.inner.version()
+  }
+
+  pub fn exists(&self) -> bool {
+    self.inner.exists()
+  }
+
+  /// Creates a new browser context with the default options
+  pub fn new_context(&self) -> ArcResult<BrowserContext> {
+    self.new_context_with(|b| b)
+  }
+
+  /// Creates a new browser context with the options set on the given builder
+  #[track_caller]
+  pub fn new_context_with<F>(&self, f: F) -> ArcResult<BrowserContext>
+  where
+    F: FnOnce(api::browser::ContextBuilder) -> api::browser::ContextBuilder,
+  {
+    let c = self.rt.block_on(f(self.inner.context_builder()).build())?;
+    Ok(BrowserContext::new(self.rt.clone(), c))
+  }
+
+  /// In case this browser is obtained using [`method: BrowserType.launch`],
+  /// closes the browser and all of its pages (if any were opened).
+  #[track_caller]
+  pub fn close(&self) -> ArcResult<()> {
+    self.rt.block_on(self.inner.close())
+  }
+
+  /// Blocking iterator over the events of this browser
+  pub fn events(&self) -> Result<Events<api::browser::Event>, Error> {
+    Ok(Events::new(self.inner.subscribe_event()?))
+  }
+}
+
+#[derive(Clone)]
+pub struct BrowserContext {
+  rt: Arc<Runtime>,
+  inner: api::BrowserContext,
+}
+
+impl BrowserContext {
+  blocking_common! {BrowserContext}
+
+  fn new(rt: Arc<Runtime>, inner: api::BrowserContext) -> Self {
+    Self { rt, inner }
+  }
+
+  /// Returns all open pages in the context.
+  pub fn pages(&self) -> Result<Vec<Page>, Error> {
+    let ps = self.inner.pages()?;
+    Ok(
+      ps.into_iter()
+        .map(|p| Page::new(self.rt.clone(), p))
+        .collect(),
+    )
+  }
+
+  /// All existing background pages in the context.
+  pub fn background_pages(&self) -> Result<Vec<Page>, Error> {
+    let ps = self.inner.background_pages()?;
+    Ok(
+      ps.into_iter()
+        .map(|p| Page::new(self.rt.clone(), p))
+        .collect(),
+    )
+  }
+
+  /// Returns the browser instance of the context. If it was launched as a
+  /// persistent context None gets returned.
+  pub fn browser(&self) -> Result<Option<Browser>, Error> {
+    let b = self.inner.browser()?;
+    Ok(b.map(|b| Browser::new(self.rt.clone(), b)))
+  }
+
+  /// Creates a new page in the browser context.
+  #[track_caller]
+  pub fn new_page(&self) -> ArcResult<Page> {
+    let p = self.rt.block_on(self.inner.new_page())?;
+    Ok(Page::new(self.rt.clone(), p))
+  }
+
+  #[track_caller]
+  pub fn set_default_navigation_timeout(&self, timeout: u32) -> ArcResult<()> {
+    self
+      .rt
+      .block_on(self.inner.set_default_navigation_timeout(timeout))
+  }
+
+  #[track_caller]
+  pub fn set_default_timeout(&self, timeout: u32) -> ArcResult<()> {
+    self.rt.block_on(self.inner.set_default_timeout(timeout))
+  }
+
+  #[track_caller]
+  pub fn cookies(&self, urls: &[String]) -> ArcResult<Vec<api::Cookie>> {
+    self.rt.block_on(self.inner.cookies(urls))
+  }
+
+  #[track_caller]
+  pub fn add_cookies(&self, cookies: &[api::Cookie]) -> ArcResult<()> {
+    self.rt.block_on(self.inner.add_cookies(cookies))
+  }
+
+  #[track_caller]
+  pub fn add_init_script(&self, script: &str) -> ArcResult<()> {
+    self.rt.block_on(self.inner.add_init_script(script))
+  }
+
+  #[track_caller]
+  pub fn storage_state(&self) -> ArcResult<api::StorageState> {
+    self.rt.block_on(self.inner.storage_state())
+  }
+
+  #[track_caller]
+  pub fn pause(&self) -> ArcResult<()> {
+    self.rt.block_on(self.inner.pause())
+  }
+
+  #[track_caller]
+  pub fn storage_state_to_path(&self, path: &Path) -> ArcResult<api::StorageState> {
+    self.rt.block_on(self.inner.storage_state_to_path(path))
+  }
+
+  /// Waits for the given event to be emitted
+  #[track_caller]
+  pub fn expect_event(
+    &self,
+    evt: api::browser_context::EventType,
+  ) -> Result<api::browser_context::Event, Error> {
+    self.rt.block_on(self.inner.expect_event(evt))
+  }
+
+  /// Blocking iterator over the events of this context
+  pub fn events(&self) -> Result<Events<api::browser_context::Event>, Error> {
+    Ok(Events::new(self.inner.subscribe_event()?))
+  }
+
+  /// Closes the browser context. All the pages that belong to the browser
+  /// context will be closed.
+  #[track_caller]
+  pub fn close(&self) -> ArcResult<()> {
+    self.rt.block_on(self.inner.close())
+  }
+}
+
+#[derive(Clone)]
+pub struct Page {
+  rt: Arc<Runtime>,
+  inner: api::Page,
+}
+
+impl Page {
+  blocking_common! {Page}
+
+  fn new(rt: Arc<Runtime>, inner: api::Page) -> Self {
+    Self { rt, inner }
+  }
+
+  pub fn context(&self) -> BrowserContext {
+    BrowserContext::new(self.rt.clone(), self.inner.context())
+  }
+
+  /// The page's main frame. Page is guaranteed to have a main frame which
+  /// persists during navigations.
+  pub fn main_frame(&self) -> Frame {
+    Frame::new(self.rt.clone(), self.inner.main_frame())
+  }
+
+  /// An array of all frames attached to the page.
+  pub fn frames(&self) -> Result<Vec<Frame>, Error> {
+    let fs = self.inner.frames()?;
+    Ok(
+      fs.into_iter()
+        .map(|f| Frame::new(self.rt.clone(), f))
+        .collect(),
+    )
+  }
+
+  pub fn url(&self) -> Result<String, Error> {
+    self.inner.url()
+  }
+
+  #[track_caller]
+  pub fn goto(&self, url: &str) -> ArcResult<Option<Response>> {
+    self.rt.block_on(self.inner.goto_builder(url).goto())
+  }
+
+  #[track_caller]
+  pub fn reload(&self) -> ArcResult<Option<Response>> {
+    self.rt.block_on(self.inner.reload_builder().reload())
+  }
+
+  #[track_caller]
+  pub fn go_back(&self) -> ArcResult<Option<Response>> {
+    self.rt.block_on(self.inner.go_back_builder().go_back())
+  }
+
+  #[track_caller]
+  pub fn go_forward(&self) -> ArcResult<Option<Response>> {
+    self
+      .rt
+      .block_on(self.inner.go_forward_builder().go_forward())
+  }
+
+  #[track_caller]
+  pub fn title(&self) -> ArcResult<String> {
+    self.rt.block_on(self.inner.title())
+  }
+
+  #[track_caller]
+  pub fn highlight(&self, selector: &str) -> ArcResult<()> {
+    self.rt.block_on(self.inner.highlight(selector))
+  }
+
+  #[track_caller]
+  pub fn pause(&self) -> ArcResult<()> {
+    self.rt.block_on(self.inner.pause())
+  }
+
+  /// Gets the full HTML contents of the page, including the doctype.
+  #[track_caller]
+  pub fn content(&self) -> ArcResult<String> {
+    self.rt.block_on(self.inner.content())
+  }
+
+  #[track_caller]
+  pub fn set_content(&self, html: &str) -> ArcResult<()> {
+    self
+      .rt
+      .block_on(self.inner.set_content_builder(html).set_content())
+  }
+
+  #[track_caller]
+  pub fn click(&self, selector: &str) -> ArcResult<()> {
+    self.rt.block_on(self.inner.click_builder(selector).click())
+  }
+
+  #[track_caller]
+  pub fn dblclick(&self, selector: &str) -> ArcResult<()> {
+    self
+      .rt
+      .block_on(self.inner.dblclick_builder(selector).dblclick())
+  }
+
+  #[track_caller]
+  pub fn fill(&self, selector: &str, value: &str) -> ArcResult<()> {
+    self
+      .rt
+      .block_on(self.inner.fill_builder(selector, value).fill())
+  }
+
+  #[track_caller]
+  pub fn r#type(&self, selector: &str, text: &str) -> ArcResult<()> {
+    self
+      .rt
+      .block_on(self.inner.type_builer(selector, text).r#type())
+  }
+
+  #[track_caller]
+  pub fn press(&self, selector: &str, key: &str) -> ArcResult<()> {
+    self
+      .rt
+      .block_on(self.inner.press_builder(selector, key).press())
+  }
+
+  #[track_caller]
+  pub fn hover(&self, selector: &str) -> ArcResult<()> {
+    self.rt.block_on(self.inner.hover_builder(selector).hover())
+  }
+
+  #[track_caller]
+  pub fn check(&self, selector: &str) -> ArcResult<()> {
+    self.rt.block_on(self.inner.check_builder(selector).check())
+  }
+
+  #[track_caller]
+  pub fn uncheck(&self, selector: &str) -> ArcResult<()> {
+    self
+      .rt
+      .block_on(self.inner.uncheck_builder(selector).uncheck())
+  }
+
+  #[track_caller]
+  pub fn focus(&self, selector: &str) -> ArcResult<()> {
+    self.rt.block_on(self.inner.focus(selector, None))
+  }
+
+  #[track_caller]
+  pub fn text_content(&self, selector: &str) -> ArcResult<Option<String>> {
+    self.rt.block_on(self.inner.text_content(selector, None))
+  }
+
+  #[track_caller]
+  pub fn inner_text(&self, selector: &str) -> ArcResult<String> {
+    self.rt.block_on(self.inner.inner_text(selector, None))
+  }
+
+  #[track_caller]
+  pub fn inner_html(&self, selector: &str) -> ArcResult<String> {
+    self.rt.block_on(self.inner.inner_html(selector, None))
+  }
+
+  #[track_caller]
+  pub fn get_attribute(&self, selector: &str, name: &str) -> ArcResult<Option<String>> {
+    self
+      .rt
+      .block_on(self.inner.get_attribute(selector, name, None))
+  }
+
+  #[track_caller]
+  pub fn query_selector(&self, selector: &str) -> ArcResult<Option<ElementHandle>> {
+    let e = self.rt.block_on(self.inner.query_selector(selector))?;
+    Ok(e.map(|e| ElementHandle::new(self.rt.clone(), e)))
+  }
+
+  #[track_caller]
+  pub fn query_selector_all(&self, selector: &str) -> ArcResult<Vec<ElementHandle>> {
+    let es = self.rt.block_on(self.inner.query_selector_all(selector))?;
+    Ok(
+      es.into_iter()
+        .map(|e| ElementHandle::new(self.rt.clone(), e))
+        .collect(),
+    )
+  }
+
+  #[track_caller]
+  pub fn wait_for_selector(&self, selector: &str) -> ArcResult<Option<ElementHandle>> {
+    let e = self.rt.block_on(
+      self
+        .inner
+        .wait_for_selector_builder(selector)
+        .wait_for_selector(),
+    )?;
+    Ok(e.map(|e| ElementHandle::new(self.rt.clone(), e)))
+  }
+
+  #[track_caller]
+  pub fn eval<U>(&self, expression: &str) -> ArcResult<U>
+  where
+    U: DeserializeOwned,
+  {
+    self.rt.block_on(self.inner.eval(expression))
+  }
+
+  #[track_caller]
+  pub fn evaluate<T, U>(&self, expression: &str, arg: T) -> ArcResult<U>
+  where
+    T: Serialize,
+    U: DeserializeOwned,
+  {
+    self.rt.block_on(self.inner.evaluate(expression, arg))
+  }
+
+  /// Returns the buffer with the captured screenshot.
+  #[track_caller]
+  pub fn screenshot(&self) -> ArcResult<Vec<u8>> {
+    self
+      .rt
+      .block_on(self.inner.screenshot_builder().screenshot())
+  }
+
+  #[track_caller]
+  pub fn set_default_navigation_timeout(&self, timeout: u32) -> ArcResult<()> {
+    self
+      .rt
+      .block_on(self.inner.set_default_navigation_timeout(timeout))
+  }
+
+  #[track_caller]
+  pub fn set_default_timeout(&self, timeout: u32) -> ArcResult<()> {
+    self.rt.block_on(self.inner.set_default_timeout(timeout))
+  }
+
+  #[track_caller]
+  pub fn set_viewport_size(&self, viewport_size: api::Viewport) -> ArcResult<()> {
+    self
+      .rt
+      .block_on(self.inner.set_viewport_size(viewport_size))
+  }
+
+  #[track_caller]
+  pub fn bring_to_front(&self) -> ArcResult<()> {
+    self.rt.block_on(self.inner.bring_to_front())
+  }
+
+  #[track_caller]
+  pub fn wait_for_timeout(&self, timeout: f64) {
+    self.rt.block_on(self.inner.wait_for_timeout(timeout))
+  }
+
+  /// Waits for the given event to be emitted
+  #[track_caller]
+  pub fn expect_event(&self, evt: api::page::EventType) -> Result<api::page::Event, Error> {
+    self.rt.block_on(self.inner.expect_event(evt))
+  }
+
+  /// Blocking iterator over the events of this page
+  pub fn events(&self) -> Result<Events<api::page::Event>, Error> {
+    Ok(Events::new(self.inner.subscribe_event()?))
+  }
+
+  #[track_caller]
+  pub fn close(&self, run_before_unload: Option<bool>) -> ArcResult<()> {
+    self.rt.block_on(self.inner.close(run_before_unload))
+  }
+}
+
+#[derive(Clone)]
+pub struct Frame {
+  rt: Arc<Runtime>,
+  inner: api::Frame,
+}
+
+impl Frame {
+  blocking_common! {Frame}
+
+  fn new(rt: Arc<Runtime>, inner: api::Frame) -> Self {
+    Self { rt, inner }
+  }
+
+  pub fn url(&self) -> Result<String, Error> {
+    self.inner.url()
+  }
+
+  pub fn name(&self) -> Result<String, Error> {
+    self.inner.name()
+  }
+
+  pub fn page(&self) -> Result<Option<Page>, Error> {
+    let p = self.inner.page()?;
+    Ok(p.map(|p| Page::new(self.rt.clone(), p)))
+  }
+
+  pub fn parent_frame(&self) -> Result<Option<Frame>, Error> {
+    let f = self.inner.parent_frame()?;
+    Ok(f.map(|f| Frame::new(self.rt.clone(), f)))
+  }
+
+  pub fn child_frames(&self) -> Result<Vec<Frame>, Error> {
+    let fs = self.inner.child_frames()?;
+    Ok(
+      fs.into_iter()
+        .map(|f| Frame::new(self.rt.clone(), f))
+        .collect(),
+    )
+  }
+
+  #[track_caller]
+  pub fn goto(&self, url: &str) -> ArcResult<Option<Response>> {
+    self.rt.block_on(self.inner.goto_builder(url).goto())
+  }
+
+  #[track_caller]
+  pub fn title(&self) -> ArcResult<String> {
+    self.rt.block_on(self.inner.title())
+  }
+
+  #[track_caller]
+  pub fn highlight(&self, selector: &str) -> ArcResult<()> {
+    self.rt.block_on(self.inner.highlight(selector))
+  }
+
+  #[track_caller]
+  pub fn content(&self) -> ArcResult<String> {
+    self.rt.block_on(self.inner.content())
+  }
+
+  #[track_caller]
+  pub fn set_content(&self, html: &str) -> ArcResult<()> {
+    self
+      .rt
+      .block_on(self.inner.set_content_builder(html).set_content())
+  }
+
+  #[track_caller]
+  pub fn click(&self, selector: &str) -> ArcResult<()> {
+    self.rt.block_on(self.inner.click_builder(selector).click())
+  }
+
+  #[track_caller]
+  pub fn fill(&self, selector: &str, value: &str) -> ArcResult<()> {
+    self
+      .rt
+      .block_on(self.inner.fill_builder(selector, value).fill())
+  }
+
+  #[track_caller]
+  pub fn r#type(&self, selector: &str, text: &str) -> ArcResult<()> {
+    self
+      .rt
+      .block_on(self.inner.type_builder(selector, text).r#type())
+  }
+
+  #[track_caller]
+  pub fn press(&self, selector: &str, key: &str) -> ArcResult<()> {
+    self
+      .rt
+      .block_on(self.inner.press_builder(selector, key).press())
+  }
+
+  #[track_caller]
+  pub fn text_content(&self, selector: &str) -> ArcResult<Option<String>> {
+    self.rt.block_on(self.inner.text_content(selector, None))
+  }
+
+  #[track_caller]
+  pub fn inner_text(&self, selector: &str) -> ArcResult<String> {
+    self.rt.block_on(self.inner.inner_text(selector, None))
+  }
+
+  #[track_caller]
+  pub fn inner_html(&self, selector: &str) -> ArcResult<String> {
+    self.rt.block_on(self.inner.inner_html(selector, None))
+  }
+
+  #[track_caller]
+  pub fn query_selector(&self, selector: &str) -> ArcResult<Option<ElementHandle>> {
+    let e = self.rt.block_on(self.inner.query_selector(selector))?;
+    Ok(e.map(|e| ElementHandle::new(self.rt.clone(), e)))
+  }
+
+  #[track_caller]
+  pub fn query_selector_all(&self, selector: &str) -> ArcResult<Vec<ElementHandle>> {
+    let es = self.rt.block_on(self.inner.query_selector_all(selector))?;
+    Ok(
+      es.into_iter()
+        .map(|e| ElementHandle::new(self.rt.clone(), e))
+        .collect(),
+    )
+  }
+
+  #[track_caller]
+  pub fn wait_for_selector(&self, selector: &str) -> ArcResult<Option<ElementHandle>> {
+    let e = self.rt.block_on(
+      self
+        .inner
+        .wait_for_selector_builder(selector)
+        .wait_for_selector(),
+    )?;
+    Ok(e.map(|e| ElementHandle::new(self.rt.clone(), e)))
+  }
+
+  #[track_caller]
+  pub fn eval<U>(&self, expression: &str) -> ArcResult<U>
+  where
+    U: DeserializeOwned,
+  {
+    self.rt.block_on(self.inner.eval(expression))
+  }
+
+  #[track_caller]
+  pub fn evaluate<T, U>(&self, expression: &str, arg: T) -> ArcResult<U>
+  where
+    T: Serialize,
+    U: DeserializeOwned,
+  {
+    self.rt.block_on(self.inner.evaluate(expression, arg))
+  }
+
+  /// Blocking iterator over the events of this frame
+  pub fn events(&self) -> Result<Events<api::frame::Event>, Error> {
+    Ok(Events::new(self.inner.subscribe_event()?))
+  }
+}
+
+#[derive(Clone)]
+pub struct ElementHandle {
+  rt: Arc<Runtime>,
+  inner: api::ElementHandle,
+}
+
+impl ElementHandle {
+  blocking_common! {ElementHandle}
+
+  fn new(rt: Arc<Runtime>, inner: api::ElementHandle) -> Self {
+    Self { rt, inner }
+  }
+
+  #[track_caller]
+  pub fn query_selector(&self, selector: &str) -> ArcResult<Option<ElementHandle>> {
+    let e = self.rt.block_on(self.inner.query_selector(selector))?;
+    Ok(e.map(|e| ElementHandle::new(self.rt.clone(), e)))
+  }
+
+  #[track_caller]
+  pub fn query_selector_all(&self, selector: &str) -> ArcResult<Vec<ElementHandle>> {
+    let es = self.rt.block_on(self.inner.query_selector_all(selector))?;
+    Ok(
+      es.into_iter()
+        .map(|e| ElementHandle::new(self.rt.clone(), e))
+        .collect(),
+    )
+  }
+
+  #[track_caller]
+  pub fn inner_text(&self) -> ArcResult<String> {
+    self.rt.block_on(self.inner.inner_text())
+  }
+
+  #[track_caller]
+  pub fn inner_html(&self) -> ArcResult<String> {
+    self.rt.block_on(self.inner.inner_html())
+  }
+
+  #[track_caller]
+  pub fn text_content(&self) -> ArcResult<Option<String>> {
+    self.rt.block_on(self.inner.text_content())
+  }
+
+  #[track_caller]
+  pub fn get_attribute(&self, name: &str) -> ArcResult<Option<String>> {
+    self.rt.block_on(self.inner.get_attribute(name))
+  }
+
+  #[track_caller]
+  pub fn owner_frame(&self) -> ArcResult<Option<Frame>> {
+    let f = self.rt.block_on(self.inner.owner_frame())?;
+    Ok(f.map(|f| Frame::new(self.rt.clone(), f)))
+  }
+
+  #[track_caller]
+  pub fn content_frame(&self) -> ArcResult<Option<Frame>> {
+    let f = self.rt.block_on(self.inner.content_frame())?;
+    Ok(f.map(|f| Frame::new(self.rt.clone(), f)))
+  }
+
+  #[track_caller]
+  pub fn click(&self) -> ArcResult<()> {
+    self.rt.block_on(self.inner.click_builder().click())
+  }
+
+  #[track_caller]
+  pub fn hover(&self) -> ArcResult<()> {
+    self.rt.block_on(self.inner.hover_builder().hover())
+  }
+
+  #[track_caller]
+  pub fn fill(&self, value: &str) -> ArcResult<()> {
+    self.rt.block_on(self.inner.fill_builder(value).fill())
+  }
+
+  #[track_caller]
+  pub fn r#type(&self, text: &str) -> ArcResult<()> {
+    self.rt.block_on(self.inner.type_builder(text).r#type())
+  }
+
+  #[track_caller]
+  pub fn press(&self, key: &str) -> ArcResult<()> {
+    self.rt.block_on(self.inner.press_builder(key).press())
+  }
+
+  #[track_caller]
+  pub fn check(&self) -> ArcResult<()> {
+    self.rt.block_on(self.inner.check_builder().check())
+  }
+
+  #[track_caller]
+  pub fn uncheck(&self) -> ArcResult<()> {
+    self.rt.block_on(self.inner.uncheck_builder().uncheck())
+  }
+
+  #[track_caller]
+  pub fn focus(&self) -> ArcResult<()> {
+    self.rt.block_on(self.inner.focus())
+  }
+
+  #[track_caller]
+  pub fn bounding_box(&self) -> ArcResult<Option<api::FloatRect>> {
+    self.rt.block_on(self.inner.bounding_box())
+  }
+
+  #[track_caller]
+  pub fn screenshot(&self) -> ArcResult<Vec<u8>> {
+    let builder = self.rt.block_on(self.inner.screenshot_builder());
+    self.rt.block_on(builder.screenshot())
+  }
+}
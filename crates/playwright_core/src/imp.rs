@@ -89,12 +89,16 @@ mod macros {
 }
 
 pub(crate) mod core {
+  mod api_call;
   mod connection;
   mod driver;
   mod event_emitter;
+  pub(crate) mod fs;
   mod message;
+  pub(crate) mod net;
   mod remote_object;
   mod transport;
+  pub(crate) use api_call::*;
   pub use connection::*;
   pub use driver::*;
   pub use event_emitter::*;
@@ -114,19 +118,25 @@ pub(crate) mod browser;
 pub(crate) mod browser_context;
 pub(crate) mod console_message;
 pub(crate) mod dialog;
+pub(crate) mod electron;
+pub(crate) mod electron_application;
 pub(crate) mod download;
 pub(crate) mod element_handle;
 pub(crate) mod file_hooser;
 pub(crate) mod frame;
 pub(crate) mod js_handle;
+pub(crate) mod json_pipe;
 pub(crate) mod page;
 pub(crate) mod request;
 pub(crate) mod response;
 pub(crate) mod route;
+pub(crate) mod socks_support;
 pub(crate) mod stream;
+pub(crate) mod trace_summary;
 pub(crate) mod video;
 pub(crate) mod websocket;
 pub(crate) mod worker;
+pub(crate) mod writable_stream;
 
 //_accessibility.py
 //_api_structures.py
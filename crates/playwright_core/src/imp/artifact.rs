@@ -1,5 +1,6 @@
 use crate::imp::core::*;
 use crate::imp::prelude::*;
+use crate::imp::stream::Stream;
 
 #[derive(Debug)]
 pub(crate) struct Artifact {
@@ -45,11 +46,26 @@ impl Artifact {
     let res = send_message!(self, "saveAsStream", Map::new());
     let guid = only_guid(&res)?;
     let stream = get_object!(self.context()?.lock().unwrap(), guid, Stream)?;
-    std::fs::create_dir_all(dir).map_err(Error::from)?;
+    fs::create_dir_all(dir).await.map_err(Error::from)?;
     upgrade(&stream)?.save_as(path).await?;
     Ok(())
   }
 
+  pub(crate) async fn create_read_stream(&self) -> ArcResult<Option<Weak<Stream>>> {
+    let res = send_message!(self, "stream", Map::new());
+    let guid = match as_only_guid(&res) {
+      Some(g) => g,
+      None => return Ok(None),
+    };
+    let stream = get_object!(self.context()?.lock().unwrap(), guid, Stream)?;
+    Ok(Some(stream))
+  }
+
+  pub(crate) async fn cancel(&self) -> ArcResult<()> {
+    let _ = send_message!(self, "cancel", Map::new());
+    Ok(())
+  }
+
   pub(crate) async fn failure(&self) -> ArcResult<Option<String>> {
     let v = send_message!(self, "failure", Map::new());
     let msg = maybe_only_str(&v)?;
@@ -15,6 +15,8 @@ pub(crate) struct Browser {
   channel: ChannelOwner,
   version: String,
   var: Mutex<Variable>,
+  tx: Mutex<Option<broadcast::Sender<Evt>>>,
+  listeners: Listeners<Evt>,
 }
 
 #[derive(Debug, Default)]
@@ -33,6 +35,8 @@ impl Browser {
         contexts: Vec::new(),
         is_remote: false,
       }),
+      tx: Mutex::default(),
+      listeners: Listeners::default(),
     })
   }
 
@@ -45,6 +49,18 @@ impl Browser {
     Ok(())
   }
 
+  pub(crate) async fn start_tracing(&self, args: StartTracingArgs) -> ArcResult<()> {
+    let _ = send_message!(self, "startTracing", args);
+    Ok(())
+  }
+
+  pub(crate) async fn stop_tracing(&self) -> ArcResult<Vec<u8>> {
+    let v = send_message!(self, "stopTracing", Map::new());
+    let b64 = only_str(&v)?;
+    let bytes = base64::decode(b64).map_err(Error::InvalidBase64)?;
+    Ok(bytes)
+  }
+
   // Responds newtype `OwnerPage` of `SinglePageBrowserContext`.
   // There are different behavior in BrowserContext::new_page
   // async fn new_page(
@@ -75,12 +91,19 @@ impl Browser {
 
   pub(crate) async fn new_context(
     &self,
-    args: NewContextArgs<'_, '_, '_, '_, '_, '_, '_>,
+    mut args: NewContextArgs,
   ) -> Result<Weak<BrowserContext>, Arc<Error>> {
+    if let Some(path) = &args.storage_state_path {
+      args.storage_state = Some(StorageState::read(path).await?);
+    }
+    let keep_flagged_videos_only = args.keep_flagged_videos_only.unwrap_or_default();
     let res = send_message!(self, "newContext", args);
     let guid = only_guid(&res)?;
     let c = get_object!(self.context()?.lock().unwrap(), guid, BrowserContext)?;
     self.register_new_context(c.clone())?;
+    if keep_flagged_videos_only {
+      upgrade(&c)?.keep_flagged_videos_only();
+    }
     Ok(c)
   }
 
@@ -102,6 +125,54 @@ impl RemoteObject for Browser {
   fn channel_mut(&mut self) -> &mut ChannelOwner {
     &mut self.channel
   }
+
+  fn handle_event(
+    &self,
+    _ctx: &Context,
+    method: Str<Method>,
+    _params: Map<String, Value>,
+  ) -> Result<(), Error> {
+    if method.as_str() == "close" {
+      self.emit_event(Evt::Disconnected);
+    }
+    Ok(())
+  }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum Evt {
+  Disconnected,
+}
+
+impl EventEmitter for Browser {
+  type Event = Evt;
+
+  fn tx(&self) -> Option<broadcast::Sender<Self::Event>> {
+    self.tx.lock().unwrap().clone()
+  }
+
+  fn set_tx(&self, tx: broadcast::Sender<Self::Event>) {
+    *self.tx.lock().unwrap() = Some(tx);
+  }
+
+  fn listeners(&self) -> &Listeners<Self::Event> {
+    &self.listeners
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EventType {
+  Disconnected,
+}
+
+impl IsEvent for Evt {
+  type EventType = EventType;
+
+  fn event_type(&self) -> Self::EventType {
+    match self {
+      Self::Disconnected => EventType::Disconnected,
+    }
+  }
 }
 
 #[derive(Debug, Deserialize)]
@@ -111,9 +182,9 @@ struct Initializer {
 }
 
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Default)]
+#[derive(Debug, Serialize, Default, Clone)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct NewContextArgs<'e, 'f, 'g, 'h, 'i, 'j, 'k> {
+pub(crate) struct NewContextArgs {
   sdk_language: &'static str,
 
   pub(crate) proxy: Option<ProxySettings>,
@@ -127,25 +198,38 @@ pub(crate) struct NewContextArgs<'e, 'f, 'g, 'h, 'i, 'j, 'k> {
   pub(crate) js_enabled: Option<bool>,
   #[serde(rename = "bypassCSP")]
   pub(crate) bypass_csp: Option<bool>,
-  pub(crate) user_agent: Option<&'e str>,
-  pub(crate) locale: Option<&'f str>,
-  pub(crate) timezone_id: Option<&'g str>,
+  pub(crate) user_agent: Option<String>,
+  pub(crate) locale: Option<String>,
+  pub(crate) timezone_id: Option<String>,
   pub(crate) geolocation: Option<Geolocation>,
-  pub(crate) permissions: Option<&'h [String]>,
+  pub(crate) permissions: Option<Vec<String>>,
   #[serde(rename = "extraHTTPHeaders")]
   pub(crate) extra_http_headers: Option<HashMap<String, String>>,
   pub(crate) offline: Option<bool>,
-  pub(crate) http_credentials: Option<&'i HttpCredentials>,
+  pub(crate) http_credentials: Option<HttpCredentials>,
   pub(crate) device_scale_factor: Option<f64>,
   pub(crate) is_mobile: Option<bool>,
   pub(crate) has_touch: Option<bool>,
   pub(crate) color_scheme: Option<ColorScheme>,
   pub(crate) accept_downloads: Option<bool>,
   pub(crate) chromium_sandbox: Option<bool>,
-  pub(crate) record_video: Option<RecordVideo<'j>>,
-  pub(crate) record_har: Option<RecordHar<'k>>,
+  pub(crate) record_video: Option<RecordVideo>,
+  #[serde(skip)]
+  pub(crate) keep_flagged_videos_only: Option<bool>,
+  pub(crate) record_har: Option<RecordHar>,
 
   pub(crate) storage_state: Option<StorageState>,
+  #[serde(skip)]
+  pub(crate) storage_state_path: Option<PathBuf>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct StartTracingArgs {
+  pub(crate) page: Option<OnlyGuid>,
+  pub(crate) screenshots: Option<bool>,
+  pub(crate) categories: Option<Vec<String>>,
 }
 
 #[cfg(test)]
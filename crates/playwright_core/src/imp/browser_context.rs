@@ -1,29 +1,41 @@
 use crate::imp::browser::Browser;
 use crate::imp::core::*;
+use crate::imp::electron_application::ElectronApplication;
 use crate::imp::page::Page;
 use crate::imp::prelude::*;
 use crate::imp::utils::Cookie;
 use crate::imp::utils::Geolocation;
 use crate::imp::utils::Header;
+use crate::imp::utils::JsError;
 use crate::imp::utils::StorageState;
+use crate::imp::worker::Worker;
+use crate::imp::writable_stream::WritableStream;
 
 #[derive(Debug)]
 pub(crate) struct BrowserContext {
   channel: ChannelOwner,
   var: Mutex<Variable>,
   tx: Mutex<Option<broadcast::Sender<Evt>>>,
+  listeners: Listeners<Evt>,
 }
 
 #[derive(Debug, Default)]
 pub(crate) struct Variable {
   browser: Option<Weak<Browser>>,
   pages: Vec<Weak<Page>>,
+  background_pages: Vec<Weak<Page>>,
+  service_workers: Vec<Weak<Worker>>,
+  electron_application: Option<Weak<ElectronApplication>>,
   timeout: Option<u32>,
   navigation_timeout: Option<u32>,
+  /// Set when only flagged videos are kept, to the runtime deleting the
+  /// others since pages close outside of it
+  discard_videos_in: Option<tokio::runtime::Handle>,
 }
 
 impl BrowserContext {
   const DEFAULT_TIMEOUT: u32 = 30000;
+  const UPLOAD_CHUNK_SIZE: usize = 1024 * 1024;
 
   pub(crate) fn try_new(channel: ChannelOwner) -> Result<Self, Error> {
     let Initializer {} = serde_json::from_value(channel.initializer.clone())?;
@@ -39,6 +51,7 @@ impl BrowserContext {
       channel,
       var,
       tx: Mutex::default(),
+      listeners: Listeners::default(),
     })
   }
 
@@ -54,12 +67,52 @@ impl BrowserContext {
     Ok(())
   }
 
+  /// Resolves when resumed from the inspector of a headed browser
+  pub(crate) async fn pause(&self) -> ArcResult<()> {
+    let _ = send_message!(self, "pause", Map::new());
+    Ok(())
+  }
+
   pub(crate) async fn storage_state(&self) -> ArcResult<StorageState> {
     let v = send_message!(self, "storageState", Map::new());
     let s = serde_json::from_value((*v).clone()).map_err(Error::Serde)?;
     Ok(s)
   }
 
+  pub(crate) async fn storage_state_to_path(&self, path: &Path) -> ArcResult<StorageState> {
+    let s = self.storage_state().await?;
+    s.write(path).await?;
+    Ok(s)
+  }
+
+  /// Applies a saved state to a context that can't take it on creation. Cookies
+  /// are added once and local storage entries missing from an origin are put
+  /// back before any script of each document of the origin runs. Nothing is
+  /// left behind for the page to see: the document is marked as restored with
+  /// a non-enumerable symbol property of its window.
+  pub(crate) async fn restore_storage_state(&self, state: StorageState) -> ArcResult<()> {
+    let StorageState { cookies, origins } = state;
+    if let Some(cookies) = cookies.filter(|c| !c.is_empty()) {
+      self.add_cookies(&cookies).await?;
+    }
+    if let Some(origins) = origins.filter(|o| !o.is_empty()) {
+      let origins = serde_json::to_string(&origins).map_err(Error::Serde)?;
+      let script = format!(
+        "(() => {{
+  const restored = Symbol.for('playwright.storageState');
+  const origin = {}.find(o => o.origin === location.origin);
+  if (!origin || window[restored]) return;
+  Object.defineProperty(window, restored, {{ value: true }});
+  for (const {{ name, value }} of origin.localStorage)
+    if (localStorage.getItem(name) === null) localStorage.setItem(name, value);
+}})();",
+        origins
+      );
+      self.add_init_script(&script).await?;
+    }
+    Ok(())
+  }
+
   pub(crate) async fn clear_cookies(&self) -> ArcResult<()> {
     let _ = send_message!(self, "clearCookies", Map::new());
     Ok(())
@@ -156,6 +209,61 @@ impl BrowserContext {
     Ok(())
   }
 
+  pub(crate) async fn create_temp_file(&self, name: &str) -> ArcResult<Weak<WritableStream>> {
+    let mut args = Map::new();
+    args.insert("name".into(), name.into());
+    let v = send_message!(self, "createTempFile", args);
+    let guid = only_guid(&v)?;
+    let s = get_object!(self.context()?.lock().unwrap(), guid, WritableStream)?;
+    Ok(s)
+  }
+
+  /// Local drivers read `paths` themselves. Remote ones get the files
+  /// streamed into temporary files chunk by chunk.
+  pub(crate) async fn input_file_paths(&self, paths: &[PathBuf]) -> ArcResult<InputFilePaths> {
+    let is_remote = self
+      .browser()
+      .and_then(|b| b.upgrade())
+      .map(|b| b.is_remote())
+      .unwrap_or_default();
+    if !is_remote {
+      let cwd = std::env::current_dir().map_err(Error::from)?;
+      let local_paths = paths
+        .iter()
+        .map(|p| cwd.join(p).to_string_lossy().into_owned())
+        .collect();
+      return Ok(InputFilePaths {
+        local_paths: Some(local_paths),
+        streams: None,
+      });
+    }
+    let mut streams = Vec::with_capacity(paths.len());
+    for path in paths {
+      let name = path
+        .file_name()
+        .ok_or_else(|| Error::ResolvePath(path.clone()))?
+        .to_string_lossy();
+      let stream = upgrade(&self.create_temp_file(&name).await?)?;
+      let mut file = fs::File::open(path).await.map_err(Error::from)?;
+      let mut buf = vec![0; Self::UPLOAD_CHUNK_SIZE];
+      loop {
+        let n = file.read(&mut buf).await.map_err(Error::from)?;
+        if n == 0 {
+          break;
+        }
+        stream.write(&buf[..n]).await?;
+      }
+      stream.close().await?;
+      streams.push(OnlyGuid {
+        guid: stream.channel().guid.clone(),
+      });
+    }
+    Ok(InputFilePaths {
+      local_paths: None,
+      streams: Some(streams),
+    })
+  }
+
   // async def expose_binding(
   // async def expose_function(self, name: str, callback: Callable) -> None:
   // async def route(self, url: URLMatch, handler: RouteHandler) -> None:
@@ -181,13 +289,40 @@ impl BrowserContext {
     self.var.lock().unwrap().pages.clone()
   }
 
+  pub(crate) fn background_pages(&self) -> Vec<Weak<Page>> {
+    self.var.lock().unwrap().background_pages.clone()
+  }
+
+  pub(crate) fn service_workers(&self) -> Vec<Weak<Worker>> {
+    self.var.lock().unwrap().service_workers.clone()
+  }
+
+  pub(crate) fn set_electron_application(&self, app: Weak<ElectronApplication>) {
+    self.var.lock().unwrap().electron_application = Some(app);
+  }
+
+  pub(super) fn remove_service_worker(&self, worker: &Weak<Worker>) {
+    let workers = &mut self.var.lock().unwrap().service_workers;
+    workers.remove_one(|w| w.ptr_eq(worker));
+  }
+
   pub(super) fn push_page(&self, p: Weak<Page>) {
     self.var.lock().unwrap().pages.push(p);
   }
 
   pub(super) fn remove_page(&self, page: &Weak<Page>) {
-    let pages = &mut self.var.lock().unwrap().pages;
-    pages.remove_one(|p| p.ptr_eq(page));
+    let mut var = self.var.lock().unwrap();
+    var.pages.remove_one(|p| p.ptr_eq(page));
+    var.background_pages.remove_one(|p| p.ptr_eq(page));
+  }
+
+  pub(crate) fn video_discarder(&self) -> Option<tokio::runtime::Handle> {
+    self.var.lock().unwrap().discard_videos_in.clone()
+  }
+
+  /// Must be called within the async runtime, which deletes the videos
+  pub(crate) fn keep_flagged_videos_only(&self) {
+    self.var.lock().unwrap().discard_videos_in = tokio::runtime::Handle::try_current().ok();
   }
 
   pub(crate) fn default_timeout(&self) -> u32 {
@@ -278,7 +413,27 @@ impl RemoteObject for BrowserContext {
         let OnlyGuid { guid } = serde_json::from_value((*first).clone())?;
         let p = get_object!(ctx, &guid, Page)?;
         self.push_page(p.clone());
-        self.emit_event(Evt::Page(p));
+        self.emit_event(Evt::Page(p.clone()));
+        let app = self.var.lock().unwrap().electron_application.clone();
+        if let Some(app) = app.as_ref().and_then(Weak::upgrade) {
+          app.on_window(p);
+        }
+      }
+      "backgroundPage" => {
+        let first = first_object(&params).ok_or(Error::InvalidParams)?;
+        let OnlyGuid { guid } = serde_json::from_value((*first).clone())?;
+        let p = get_object!(ctx, &guid, Page)?;
+        self.var.lock().unwrap().background_pages.push(p.clone());
+        self.emit_event(Evt::BackgroundPage(p));
+      }
+      "serviceWorker" => {
+        let first = first_object(&params).ok_or(Error::InvalidParams)?;
+        let OnlyGuid { guid } = serde_json::from_value((*first).clone())?;
+        let w = get_object!(ctx, &guid, Worker)?;
+        let this = get_object!(ctx, self.guid(), BrowserContext)?;
+        upgrade(&w)?.set_browser_context(this);
+        self.var.lock().unwrap().service_workers.push(w.clone());
+        self.emit_event(Evt::ServiceWorker(w));
       }
       "close" => self.on_close(ctx)?,
       "bindingCall" => {}
@@ -293,6 +448,9 @@ impl RemoteObject for BrowserContext {
 pub(crate) enum Evt {
   Close,
   Page(Weak<Page>),
+  WebError(Weak<Page>, JsError),
+  BackgroundPage(Weak<Page>),
+  ServiceWorker(Weak<Worker>),
 }
 
 impl EventEmitter for BrowserContext {
@@ -305,12 +463,19 @@ impl EventEmitter for BrowserContext {
   fn set_tx(&self, tx: broadcast::Sender<Self::Event>) {
     *self.tx.lock().unwrap() = Some(tx);
   }
+
+  fn listeners(&self) -> &Listeners<Self::Event> {
+    &self.listeners
+  }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum EventType {
   Close,
   Page,
+  WebError,
+  BackgroundPage,
+  ServiceWorker,
 }
 
 impl IsEvent for Evt {
@@ -320,6 +485,9 @@ impl IsEvent for Evt {
     match self {
       Self::Close => EventType::Close,
       Self::Page(_) => EventType::Page,
+      Self::WebError(..) => EventType::WebError,
+      Self::BackgroundPage(_) => EventType::BackgroundPage,
+      Self::ServiceWorker(_) => EventType::ServiceWorker,
     }
   }
 }
@@ -328,6 +496,15 @@ impl IsEvent for Evt {
 #[serde(rename_all = "camelCase")]
 struct Initializer {}
 
+/// The files of `setInputFilePaths`, only one of the fields is set
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct InputFilePaths {
+  local_paths: Option<Vec<String>>,
+  streams: Option<Vec<OnlyGuid>>,
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
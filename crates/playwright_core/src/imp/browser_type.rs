@@ -1,12 +1,16 @@
 use crate::imp::browser::Browser;
 use crate::imp::browser_context::BrowserContext;
 use crate::imp::core::*;
+use crate::imp::json_pipe::JsonPipe;
+use crate::imp::playwright::Playwright;
 use crate::imp::prelude::*;
+use crate::imp::socks_support::SocksSupport;
 use crate::imp::utils::BrowserChannel;
 use crate::imp::utils::ColorScheme;
 use crate::imp::utils::Geolocation;
 use crate::imp::utils::HttpCredentials;
 use crate::imp::utils::ProxySettings;
+use crate::imp::utils::StorageState;
 use crate::imp::utils::Viewport;
 use crate::protocol::generated::browser_type as protocol;
 
@@ -38,10 +42,8 @@ impl BrowserType {
     &self.executable
   }
 
-  pub(crate) async fn launch(
-    &self,
-    args: LaunchArgs<'_, '_, '_>,
-  ) -> Result<Weak<Browser>, Arc<Error>> {
+  pub(crate) async fn launch(&self, mut args: LaunchArgs) -> Result<Weak<Browser>, Arc<Error>> {
+    apply_debug_mode(&mut args.headless, &mut args.slowmo);
     let res = send_message!(self, "launch", args);
     let guid = only_guid(&res)?;
     let b = get_object!(self.context()?.lock().unwrap(), guid, Browser)?;
@@ -50,17 +52,30 @@ impl BrowserType {
 
   pub(crate) async fn launch_persistent_context(
     &self,
-    args: LaunchPersistentContextArgs<'_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_>,
+    mut args: LaunchPersistentContextArgs,
   ) -> Result<Weak<BrowserContext>, Arc<Error>> {
+    apply_debug_mode(&mut args.headless, &mut args.slowmo);
+    // The protocol doesn't take a storage state for persistent contexts
+    let storage_state = match &args.storage_state_path {
+      Some(path) => Some(StorageState::read(path).await?),
+      None => None,
+    };
+    let keep_flagged_videos_only = args.keep_flagged_videos_only.unwrap_or_default();
     let res = send_message!(self, "launchPersistentContext", args);
     let guid = only_guid(&res)?;
     let b = get_object!(self.context()?.lock().unwrap(), guid, BrowserContext)?;
+    if keep_flagged_videos_only {
+      upgrade(&b)?.keep_flagged_videos_only();
+    }
+    if let Some(s) = storage_state {
+      upgrade(&b)?.restore_storage_state(s).await?;
+    }
     Ok(b)
   }
 
   pub(crate) async fn connect_over_cdp(
     &self,
-    args: ConnectOverCdpArgs<'_>,
+    args: ConnectOverCdpArgs,
   ) -> ArcResult<Weak<Browser>> {
     let res = send_message!(self, "connectOverCDP", args);
     #[derive(Deserialize)]
@@ -85,18 +100,66 @@ impl BrowserType {
     Ok(browser)
   }
 
-  pub(crate) async fn connect(&self, args: ConnectArgs<'_>) -> ArcResult<Weak<Browser>> {
-    todo!()
+  pub(crate) async fn connect(&self, mut args: ConnectArgs) -> ArcResult<Weak<Browser>> {
+    let expose_network = args.expose_network.take();
+    let headers = args.headers.get_or_insert_with(HashMap::new);
+    headers.insert("x-playwright-browser".into(), self.name.clone());
+    if expose_network.is_some() {
+      headers.insert("x-playwright-proxy".into(), "*".into());
+    }
+    let res = send_message!(self, "connect", args);
+    let guid = only_guid(&res)?;
+    let pipe = get_object!(self.context()?.lock().unwrap(), guid, JsonPipe)?;
+    let remote = JsonPipe::open(pipe.clone())?;
+    let playwright = match Playwright::initialize(remote).await {
+      Ok(p) => upgrade(&p)?,
+      Err(e) => {
+        upgrade(&pipe)?.close().await?;
+        return Err(e.into());
+      }
+    };
+    let browser = match playwright.pre_launched_browser() {
+      Some(b) => b,
+      None => {
+        upgrade(&pipe)?.close().await?;
+        return Err(Error::MalformedEndpoint.into());
+      }
+    };
+    upgrade(&browser)?.set_is_remote_true();
+    if let (Some(expose_network), Some(socks_support)) =
+      (expose_network, playwright.socks_support())
+    {
+      SocksSupport::serve(socks_support, expose_network)?;
+    }
+    Ok(browser)
+  }
+}
+
+/// Milliseconds of slow motion in the debug mode unless `slowmo` is given
+const DEBUG_SLOWMO: f64 = 100.;
+
+/// Whether `PWDEBUG` asks for a debugging session. The driver inherits the
+/// variable and with `PWDEBUG=1` opens the inspector, pausing before each
+/// action.
+pub(crate) fn is_debug_mode() -> bool {
+  matches!(std::env::var("PWDEBUG").as_deref(), Ok(v) if !v.is_empty() && v != "0")
+}
+
+/// Shows the browser and slows it down in the debug mode
+fn apply_debug_mode(headless: &mut Option<bool>, slowmo: &mut Option<f64>) {
+  if is_debug_mode() {
+    *headless = Some(false);
+    slowmo.get_or_insert(DEBUG_SLOWMO);
   }
 }
 
 #[skip_serializing_none]
-#[derive(Serialize, Default)]
+#[derive(Serialize, Default, Clone)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct LaunchArgs<'a, 'b, 'c> {
+pub(crate) struct LaunchArgs {
   #[serde(rename = "executablePath")]
-  pub(crate) executable: Option<&'a Path>,
-  pub(crate) args: Option<&'b [String]>,
+  pub(crate) executable: Option<PathBuf>,
+  pub(crate) args: Option<Vec<String>>,
   pub(crate) ignore_all_default_args: Option<bool>,
   #[serde(rename = "handleSIGINT")]
   pub(crate) handle_sigint: Option<bool>,
@@ -108,7 +171,7 @@ pub(crate) struct LaunchArgs<'a, 'b, 'c> {
   pub(crate) devtools: Option<bool>,
   pub(crate) proxy: Option<ProxySettings>,
   #[serde(rename = "downloadsPath")]
-  pub(crate) downloads: Option<&'c Path>,
+  pub(crate) downloads: Option<PathBuf>,
   #[serde(rename = "slowMo")]
   pub(crate) slowmo: Option<f64>,
   pub(crate) env: Option<Map<String, Value>>,
@@ -138,15 +201,15 @@ struct Initializer {
 
 // launch args | context args | {user_data_dir: }
 #[skip_serializing_none]
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct LaunchPersistentContextArgs<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k> {
-  user_data_dir: &'a Path,
+pub(crate) struct LaunchPersistentContextArgs {
+  user_data_dir: PathBuf,
   sdk_language: &'static str,
 
   #[serde(rename = "executablePath")]
-  pub(crate) executable: Option<&'b Path>,
-  pub(crate) args: Option<&'c [String]>,
+  pub(crate) executable: Option<PathBuf>,
+  pub(crate) args: Option<Vec<String>>,
   pub(crate) ignore_all_default_args: Option<bool>,
   #[serde(rename = "handleSIGINT")]
   pub(crate) handle_sigint: Option<bool>,
@@ -160,7 +223,7 @@ pub(crate) struct LaunchPersistentContextArgs<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i
   pub(crate) devtools: Option<bool>,
   pub(crate) proxy: Option<ProxySettings>,
   #[serde(rename = "downloadsPath")]
-  pub(crate) downloads: Option<&'d Path>,
+  pub(crate) downloads: Option<PathBuf>,
   #[serde(rename = "slowMo")]
   pub(crate) slowmo: Option<f64>,
 
@@ -173,23 +236,27 @@ pub(crate) struct LaunchPersistentContextArgs<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i
   pub(crate) js_enabled: Option<bool>,
   #[serde(rename = "bypassCSP")]
   pub(crate) bypass_csp: Option<bool>,
-  pub(crate) user_agent: Option<&'e str>,
-  pub(crate) locale: Option<&'f str>,
-  pub(crate) timezone_id: Option<&'g str>,
+  pub(crate) user_agent: Option<String>,
+  pub(crate) locale: Option<String>,
+  pub(crate) timezone_id: Option<String>,
   pub(crate) geolocation: Option<Geolocation>,
-  pub(crate) permissions: Option<&'h [String]>,
+  pub(crate) permissions: Option<Vec<String>>,
   #[serde(rename = "extraHTTPHeaders")]
   pub(crate) extra_http_headers: Option<HashMap<String, String>>,
   pub(crate) offline: Option<bool>,
-  pub(crate) http_credentials: Option<&'i HttpCredentials>,
+  pub(crate) http_credentials: Option<HttpCredentials>,
   pub(crate) device_scale_factor: Option<f64>,
   pub(crate) is_mobile: Option<bool>,
   pub(crate) has_touch: Option<bool>,
   pub(crate) color_scheme: Option<ColorScheme>,
   pub(crate) accept_downloads: Option<bool>,
   pub(crate) chromium_sandbox: Option<bool>,
-  pub(crate) record_video: Option<RecordVideo<'j>>,
-  pub(crate) record_har: Option<RecordHar<'k>>,
+  pub(crate) record_video: Option<RecordVideo>,
+  #[serde(skip)]
+  pub(crate) keep_flagged_videos_only: Option<bool>,
+  pub(crate) record_har: Option<RecordHar>,
+  #[serde(skip)]
+  pub(crate) storage_state_path: Option<PathBuf>,
 
   pub(crate) channel: Option<BrowserChannel>,
 }
@@ -197,24 +264,24 @@ pub(crate) struct LaunchPersistentContextArgs<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
-pub struct RecordVideo<'a> {
-  pub dir: &'a Path,
+pub struct RecordVideo {
+  pub dir: PathBuf,
   pub size: Option<Viewport>,
 }
 
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
-pub struct RecordHar<'a> {
-  pub path: &'a Path,
+pub struct RecordHar {
+  pub path: PathBuf,
   pub omit_content: Option<bool>,
 }
 
-impl<'a> LaunchPersistentContextArgs<'a, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_> {
-  pub(crate) fn new(user_data_dir: &'a Path) -> Self {
+impl LaunchPersistentContextArgs {
+  pub(crate) fn new(user_data_dir: &Path) -> Self {
     let sdk_language = "rust";
     Self {
-      user_data_dir,
+      user_data_dir: user_data_dir.to_owned(),
       sdk_language,
       executable: None,
       args: None,
@@ -250,50 +317,57 @@ impl<'a> LaunchPersistentContextArgs<'a, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_>
       accept_downloads: None,
       chromium_sandbox: None,
       record_video: None,
+      keep_flagged_videos_only: None,
       record_har: None,
+      storage_state_path: None,
       channel: None,
     }
   }
 }
 
 #[skip_serializing_none]
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct ConnectArgs<'a> {
-  ws_endpoint: &'a str,
+pub(crate) struct ConnectArgs {
+  ws_endpoint: String,
+  pub(crate) headers: Option<HashMap<String, String>>,
   pub(crate) timeout: Option<f64>,
   #[serde(rename = "slowMo")]
   pub(crate) slowmo: Option<f64>,
+  #[serde(skip)]
+  pub(crate) expose_network: Option<String>,
 }
 
-impl<'a> ConnectArgs<'a> {
-  pub(crate) fn new(ws_endpoint: &'a str) -> Self {
+impl ConnectArgs {
+  pub(crate) fn new(ws_endpoint: &str) -> Self {
     Self {
-      ws_endpoint,
+      ws_endpoint: ws_endpoint.to_owned(),
+      headers: None,
       timeout: None,
       slowmo: None,
+      expose_network: None,
     }
   }
 }
 
 #[skip_serializing_none]
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct ConnectOverCdpArgs<'a> {
+pub(crate) struct ConnectOverCdpArgs {
   sdk_language: &'static str,
   #[serde(rename = "endpointURL")]
-  endpoint_url: &'a str,
+  endpoint_url: String,
   pub(crate) headers: Option<HashMap<String, String>>,
   pub(crate) timeout: Option<f64>,
   #[serde(rename = "slowMo")]
   pub(crate) slowmo: Option<f64>,
 }
 
-impl<'a> ConnectOverCdpArgs<'a> {
-  pub(crate) fn new(endpoint_url: &'a str) -> Self {
+impl ConnectOverCdpArgs {
+  pub(crate) fn new(endpoint_url: &str) -> Self {
     Self {
       sdk_language: "rust",
-      endpoint_url,
+      endpoint_url: endpoint_url.to_owned(),
       headers: None,
       timeout: None,
       slowmo: None,
@@ -304,7 +378,6 @@ impl<'a> ConnectOverCdpArgs<'a> {
 #[cfg(test)]
 mod tests {
   use super::*;
-  use crate::imp::playwright::Playwright;
 
   crate::runtime_test!(launch, {
     let driver = Driver::install().unwrap();
@@ -334,4 +407,39 @@ mod tests {
       }
     }
   });
+
+  /// Marks the copy of this test binary spawned by `debug_mode`
+  const DEBUG_MODE_CHILD: &str = "PLAYWRIGHT_DEBUG_MODE_CHILD";
+
+  // `PWDEBUG` is only set in a child process so that the browsers launched by
+  // the other tests stay headless
+  #[test]
+  fn debug_mode() {
+    if std::env::var_os(DEBUG_MODE_CHILD).is_some() {
+      let mut args = LaunchArgs {
+        headless: Some(true),
+        ..LaunchArgs::default()
+      };
+      apply_debug_mode(&mut args.headless, &mut args.slowmo);
+      let args = serde_json::to_value(&args).unwrap();
+      let expected = match std::env::var("PWDEBUG").as_deref() {
+        Ok("0") => serde_json::json!({"headless": true}),
+        _ => serde_json::json!({"headless": false, "slowMo": DEBUG_SLOWMO}),
+      };
+      assert_eq!(args, expected);
+      let mut slowmo = Some(5.);
+      apply_debug_mode(&mut None, &mut slowmo);
+      assert_eq!(slowmo, Some(5.));
+      return;
+    }
+    for value in ["1", "console", "0"] {
+      let status = std::process::Command::new(std::env::current_exe().unwrap())
+        .args(["--exact", "imp::browser_type::tests::debug_mode"])
+        .env(DEBUG_MODE_CHILD, "1")
+        .env("PWDEBUG", value)
+        .status()
+        .unwrap();
+      assert!(status.success(), "PWDEBUG={}", value);
+    }
+  }
 }
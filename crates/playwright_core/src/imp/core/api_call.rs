@@ -0,0 +1,134 @@
+use std::cell::Cell;
+use std::panic::Location;
+use std::task::Context;
+
+use crate::imp::prelude::*;
+use crate::protocol::generated::Metadata;
+use crate::protocol::generated::StackFrame;
+
+thread_local! {
+  static CURRENT: Cell<Option<ApiCall>> = Cell::new(None);
+}
+
+/// A public API method being executed and the place it was called from
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ApiCall {
+  pub(crate) api_name: &'static str,
+  pub(crate) location: &'static Location<'static>,
+}
+
+impl ApiCall {
+  #[track_caller]
+  pub(crate) fn new(api_name: &'static str) -> Self {
+    Self {
+      api_name,
+      location: Location::caller(),
+    }
+  }
+
+  /// The call whose future is being polled on this thread
+  pub(crate) fn current() -> Option<Self> {
+    CURRENT.with(Cell::get)
+  }
+
+  /// Attributes requests sent while polling `fut` to this call.
+  /// An enclosing call takes precedence so nested API methods report the
+  /// outermost one.
+  pub(crate) fn scope<F: Future>(self, fut: F) -> Scoped<F> {
+    Scoped {
+      call: self,
+      fut: Box::pin(fut),
+    }
+  }
+
+  /// Polls `fut` outside of the current call, so that the public methods it
+  /// awaits report themselves. Used for the actions given to the `expect_*`
+  /// methods.
+  pub(crate) fn unscoped<F: Future>(fut: F) -> Unscoped<F> {
+    Unscoped { fut: Box::pin(fut) }
+  }
+
+  pub(crate) fn metadata(&self) -> Metadata {
+    Metadata {
+      api_name: Some(self.api_name.to_owned()),
+      internal: None,
+      stack: Some(vec![StackFrame {
+        file: self.location.file().to_owned(),
+        line: Some(self.location.line().into()),
+        column: Some(self.location.column().into()),
+        function: None,
+      }]),
+    }
+  }
+}
+
+pub(crate) struct Scoped<F> {
+  call: ApiCall,
+  fut: Pin<Box<F>>,
+}
+
+impl<F: Future> Future for Scoped<F> {
+  type Output = F::Output;
+
+  fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+    let outer = CURRENT.with(Cell::get);
+    CURRENT.with(|c| c.set(Some(outer.unwrap_or(self.call))));
+    let res = self.fut.as_mut().poll(cx);
+    CURRENT.with(|c| c.set(outer));
+    res
+  }
+}
+
+pub(crate) struct Unscoped<F> {
+  fut: Pin<Box<F>>,
+}
+
+impl<F: Future> Future for Unscoped<F> {
+  type Output = F::Output;
+
+  fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+    let outer = CURRENT.with(|c| c.replace(None));
+    let res = self.fut.as_mut().poll(cx);
+    CURRENT.with(|c| c.set(outer));
+    res
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::imp::core::*;
+  use crate::Playwright;
+
+  crate::runtime_test!(records_call_site, {
+    let p = Playwright::initialize().await.unwrap();
+    let selectors = p.selectors();
+    let line = line!() + 1;
+    let err = selectors.register("css", "() => ({})", false).await;
+    let e = match err.as_deref() {
+      Err(Error::ErrorResponded(e)) => e,
+      x => panic!("{:?}", x),
+    };
+    assert_eq!(e.api_name(), Some("selectors.register"));
+    let site = e.call_site().unwrap();
+    assert_eq!(site.url, file!());
+    assert_eq!(site.line, line);
+    assert!(ApiCall::current().is_none());
+  });
+
+  #[test]
+  fn unscoped() {
+    let name = || ApiCall::current().map(|c| c.api_name);
+    let names = futures::executor::block_on(ApiCall::new("page.expect_popup").scope(async {
+      let action = ApiCall::unscoped(async {
+        let inner = ApiCall::new("page.click").scope(async { name() }).await;
+        (name(), inner)
+      })
+      .await;
+      (name(), action)
+    }));
+    assert_eq!(
+      names,
+      (Some("page.expect_popup"), (None, Some("page.click")))
+    );
+  }
+}
@@ -14,8 +14,17 @@ pub(crate) struct Context {
   objects: HashMap<Str<Guid>, RemoteArc>,
   ctx: Wm<Context>,
   id: i32,
-  callbacks: HashMap<i32, WaitPlaces<WaitMessageResult>>,
+  callbacks: HashMap<i32, Callback>,
   writer: Writer,
+  /// Runtime the connection was opened in
+  runtime: Option<tokio::runtime::Handle>,
+}
+
+#[derive(Debug)]
+struct Callback {
+  place: WaitPlaces<WaitMessageResult>,
+  api_name: String,
+  call_site: Option<StackFrame>,
 }
 
 #[derive(Debug)]
@@ -68,16 +77,75 @@ pub enum Error {
     "Path is not available when using BrowserType.connect(). Use save_as() to save a local copy."
   )]
   RemoteArtifact,
+  #[error("Malformed endpoint. The server must be started with launchServer")]
+  MalformedEndpoint,
   #[error("Failed to resolve path {0:?}")]
   ResolvePath(PathBuf),
+  #[error("Unable to fetch sizes for failed request")]
+  ResponseNotFound,
   #[error("Timed out")]
   Timeout,
+  #[error("Not within an async runtime")]
+  NoRuntime,
   #[error(transparent)]
   Join(#[from] JoinError),
 }
 
 pub(crate) type ArcResult<T> = Result<T, Arc<Error>>;
 
+impl Error {
+  /// The error reported by the driver, if this error came from it
+  pub fn driver_error(&self) -> Option<&ErrorMessage> {
+    match self {
+      Self::ErrorResponded(e) => Some(e),
+      Self::Arc(e) => e.driver_error(),
+      _ => None,
+    }
+  }
+
+  pub fn kind(&self) -> ErrorKind {
+    match self {
+      Self::Timeout => ErrorKind::Timeout,
+      Self::ReceiverClosed | Self::ObjectNotFound => ErrorKind::TargetClosed,
+      Self::ErrorResponded(e) => e.kind(),
+      Self::Arc(e) => e.kind(),
+      _ => ErrorKind::Other,
+    }
+  }
+
+  /// True for both driver side `TimeoutError`s and local timeouts
+  pub fn is_timeout(&self) -> bool {
+    self.kind() == ErrorKind::Timeout
+  }
+
+  pub fn is_target_closed(&self) -> bool {
+    self.kind() == ErrorKind::TargetClosed
+  }
+
+  /// The `net::ERR_*` code of a failed navigation
+  pub fn navigation_error_code(&self) -> Option<String> {
+    match self.kind() {
+      ErrorKind::Navigation { code } => Some(code),
+      _ => None,
+    }
+  }
+
+  pub fn is_strict_mode_violation(&self) -> bool {
+    self.kind() == ErrorKind::StrictModeViolation
+  }
+
+  /// Parsed JavaScript stack of an exception thrown by evaluated script
+  pub fn js_stack(&self) -> Option<Vec<StackFrame>> {
+    let e = self.driver_error()?;
+    (e.kind() == ErrorKind::Evaluation).then(|| e.stack_frames())
+  }
+
+  /// Name of the API call that failed
+  pub fn api_name(&self) -> Option<&str> {
+    self.driver_error()?.api_name()
+  }
+}
+
 impl Drop for Connection {
   fn drop(&mut self) {
     self.notify_closed(Error::ReceiverClosed);
@@ -199,23 +267,42 @@ impl Context {
         id: 0,
         callbacks: HashMap::new(),
         writer,
+        runtime: tokio::runtime::Handle::try_current().ok(),
       })
     })
   }
 
-  fn notify_closed(&mut self, e: Error) {
+  /// Context of a connection to a remote server that is tunneled through a
+  /// `JsonPipe` of the local connection
+  pub(crate) fn new_piped(tx: tokio::sync::mpsc::UnboundedSender<Value>) -> Am<Context> {
+    Self::new(Writer::Pipe(tx))
+  }
+
+  pub(crate) fn runtime(&self) -> Option<tokio::runtime::Handle> {
+    self.runtime.clone()
+  }
+
+  pub(in crate::imp) fn notify_closed(&mut self, e: Error) {
     let err = Arc::new(e);
-    for p in self.callbacks.iter().map(|(_, v)| v) {
+    for p in self.callbacks.iter().map(|(_, v)| &v.place) {
       Context::respond_wait(p, Err(err.clone()));
     }
     self.objects = HashMap::new();
   }
 
-  fn dispatch(&mut self, msg: Res) -> Result<(), Error> {
+  pub(in crate::imp) fn dispatch(&mut self, msg: Res) -> Result<(), Error> {
     match msg {
       Res::Result(msg) => {
-        let p = self.callbacks.get(&msg.id).ok_or(Error::CallbackNotFound)?;
-        Self::respond_wait(p, Ok(msg.body.map(Arc::new).map_err(Arc::new)));
+        let Callback {
+          place,
+          api_name,
+          call_site,
+        } = self.callbacks.get(&msg.id).ok_or(Error::CallbackNotFound)?;
+        let body = msg
+          .body
+          .map(Arc::new)
+          .map_err(|e| Arc::new(e.with_call(api_name.clone(), call_site.clone())));
+        Self::respond_wait(place, Ok(body));
         return Ok(());
       }
       Res::Initial(msg) => {
@@ -323,7 +410,37 @@ impl Context {
       metadata,
       place,
     } = r;
-    self.callbacks.insert(self.id, place);
+    let api_name = match metadata.api_name.as_deref() {
+      Some(x) if !x.is_empty() => x.to_owned(),
+      _ => {
+        let typ = self
+          .objects
+          .get(&guid)
+          .map(|r| r.channel().typ.as_str().to_owned())
+          .unwrap_or_default();
+        format!("{}.{}", typ, method.as_str())
+      }
+    };
+    let call_site = metadata.stack.as_ref().and_then(|s| s.first()).map(|f| {
+      StackFrame {
+        function: f.function.clone(),
+        url: f.file.clone(),
+        line: f.line.as_ref().and_then(|n| n.as_u64()).unwrap_or_default() as u32,
+        column: f
+          .column
+          .as_ref()
+          .and_then(|n| n.as_u64())
+          .unwrap_or_default() as u32,
+      }
+    });
+    self.callbacks.insert(
+      self.id,
+      Callback {
+        place,
+        api_name,
+        call_site,
+      },
+    );
     let req = Req {
       guid: &guid,
       method: &method,
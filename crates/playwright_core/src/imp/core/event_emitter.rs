@@ -1,23 +1,30 @@
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+use futures::channel::mpsc;
+use futures::future::BoxFuture;
+use futures::stream::StreamExt;
 pub(crate) use tokio::sync::broadcast;
-pub(crate) use tokio::sync::broadcast::error::TryRecvError;
 
 use crate::imp::core::*;
 use crate::imp::prelude::*;
 
 pub trait EventEmitter {
-  type Event: Clone;
+  type Event: IsEvent + Send + 'static;
 
   fn tx(&self) -> Option<broadcast::Sender<Self::Event>>;
 
   fn set_tx(&self, tx: broadcast::Sender<Self::Event>);
 
+  fn listeners(&self) -> &Listeners<Self::Event>;
+
   fn new_tx(
     &self,
   ) -> (
     broadcast::Sender<Self::Event>,
     broadcast::Receiver<Self::Event>,
   ) {
-    broadcast::channel(64)
+    broadcast::channel(self.listeners().capacity())
   }
 
   fn subscribe_event(&self) -> broadcast::Receiver<Self::Event> {
@@ -31,85 +38,233 @@ pub trait EventEmitter {
   }
 
   fn emit_event<E: Into<Self::Event>>(&self, e: E) {
-    self.tx().map(|tx| tx.send(e.into()).ok());
+    let e = e.into();
+    self.listeners().dispatch(&e);
+    self.tx().map(|tx| tx.send(e).ok());
   }
 }
 
-pub(crate) trait IsEvent: Clone {
-  type EventType: Clone + Copy + PartialEq;
+pub trait IsEvent: Clone {
+  type EventType: Clone + Copy + PartialEq + Send + Sync;
 
   fn event_type(&self) -> Self::EventType;
 }
 
-#[cfg(any(feature = "rt-tokio", feature = "rt-actix"))]
-pub(crate) async fn expect_event<E>(
-  mut rx: broadcast::Receiver<E>,
-  evt: E::EventType,
-  timeout: u32,
-) -> Result<E, Error>
+/// Identifies a handler registered with `on` or `once`, to be passed to `off`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ListenerId(usize);
+
+impl ListenerId {
+  fn next() -> Self {
+    static NEXT: AtomicUsize = AtomicUsize::new(0);
+    Self(NEXT.fetch_add(1, Ordering::Relaxed))
+  }
+}
+
+pub(crate) type Handler<E> = Arc<dyn Fn(E) -> BoxFuture<'static, ()> + Send + Sync>;
+
+struct Listener<E: IsEvent> {
+  id: ListenerId,
+  event_type: E::EventType,
+  once: bool,
+  handler: Handler<E>,
+}
+
+/// Adapts a handler taking the public event type
+pub(crate) fn handler<E, T, F, Fut>(f: F) -> Handler<E>
 where
-  E: IsEvent + Send + Sync + 'static,
-  <E as event_emitter::IsEvent>::EventType: Send + Sync,
+  E: 'static,
+  T: From<E> + 'static,
+  F: Fn(T) -> Fut + Send + Sync + 'static,
+  Fut: Future<Output = ()> + Send + 'static,
 {
-  consume(&mut rx).await?;
-  let sleep = sleep(Duration::from_millis(timeout as u64));
-  let event = spawn(async move {
-    loop {
-      match rx.recv().await {
-        Ok(x) if x.event_type() == evt => break Ok(x),
-        Ok(_) => continue,
-        Err(e) => break Err(e),
+  Arc::new(move |e: E| -> BoxFuture<'static, ()> { Box::pin(f(T::from(e))) })
+}
+
+/// Adapts a handler that can only run once
+pub(crate) fn once_handler<E, T, F, Fut>(f: F) -> Handler<E>
+where
+  E: 'static,
+  T: From<E> + 'static,
+  F: FnOnce(T) -> Fut + Send + 'static,
+  Fut: Future<Output = ()> + Send + 'static,
+{
+  let f = Mutex::new(Some(f));
+  Arc::new(move |e: E| -> BoxFuture<'static, ()> {
+    match f.lock().unwrap().take() {
+      Some(f) => Box::pin(f(T::from(e))),
+      None => Box::pin(futures::future::ready(())),
+    }
+  })
+}
+
+/// Handlers registered on an emitter.
+///
+/// Events are queued on an unbounded channel and a single task awaits the
+/// matching handlers one after another in registration order, so handlers see
+/// every event in the order it was emitted.
+pub struct Listeners<E: IsEvent> {
+  handlers: Arc<Mutex<Vec<Listener<E>>>>,
+  tx: Mutex<Option<mpsc::UnboundedSender<E>>>,
+  /// Of the broadcast channel behind `subscribe_event`
+  capacity: AtomicUsize,
+}
+
+impl<E: IsEvent> Default for Listeners<E> {
+  fn default() -> Self {
+    Self {
+      handlers: Arc::default(),
+      tx: Mutex::default(),
+      capacity: AtomicUsize::new(Self::DEFAULT_CAPACITY),
+    }
+  }
+}
+
+impl<E: IsEvent> std::fmt::Debug for Listeners<E> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("Listeners")
+      .field("len", &self.handlers.lock().unwrap().len())
+      .finish()
+  }
+}
+
+impl<E: IsEvent> Listeners<E> {
+  const DEFAULT_CAPACITY: usize = 64;
+
+  pub(crate) fn capacity(&self) -> usize {
+    self.capacity.load(Ordering::Relaxed)
+  }
+
+  pub(crate) fn set_capacity(&self, capacity: usize) {
+    self.capacity.store(capacity.max(1), Ordering::Relaxed);
+  }
+}
+
+impl<E: IsEvent + Send + 'static> Listeners<E> {
+  /// The first handler spawns the dispatching task on `rt`
+  pub(crate) fn add(
+    &self,
+    rt: &tokio::runtime::Handle,
+    event_type: E::EventType,
+    once: bool,
+    handler: Handler<E>,
+  ) -> ListenerId {
+    let id = ListenerId::next();
+    self.handlers.lock().unwrap().push(Listener {
+      id,
+      event_type,
+      once,
+      handler,
+    });
+    let tx = &mut self.tx.lock().unwrap();
+    if tx.is_none() {
+      let (sender, rx) = mpsc::unbounded();
+      rt.spawn(Self::run(Arc::downgrade(&self.handlers), rx));
+      **tx = Some(sender);
+    }
+    id
+  }
+
+  pub(crate) fn remove(&self, id: ListenerId) {
+    self.handlers.lock().unwrap().retain(|l| l.id != id);
+  }
+
+  fn dispatch(&self, e: &E) {
+    if let Some(tx) = &*self.tx.lock().unwrap() {
+      tx.unbounded_send(e.clone()).ok();
+    }
+  }
+
+  async fn run(handlers: Weak<Mutex<Vec<Listener<E>>>>, mut rx: mpsc::UnboundedReceiver<E>) {
+    while let Some(e) = rx.next().await {
+      let event_type = e.event_type();
+      let matched: Vec<Handler<E>> = {
+        let handlers = match handlers.upgrade() {
+          Some(x) => x,
+          None => break,
+        };
+        let handlers = &mut handlers.lock().unwrap();
+        let matched = handlers
+          .iter()
+          .filter(|l| l.event_type == event_type)
+          .map(|l| l.handler.clone())
+          .collect();
+        handlers.retain(|l| !(l.once && l.event_type == event_type));
+        matched
+      };
+      for handler in matched {
+        handler(e.clone()).await;
       }
     }
-  });
-  tokio::select! {
-      _ = sleep => Err(Error::Timeout),
-      x = event => x?.map_err(Error::Event)
   }
 }
 
-#[cfg(feature = "rt-async-std")]
-pub(crate) async fn expect_event<E>(
+/// Waits for the next event of type `evt` accepted by `predicate`.
+/// `rx` should be subscribed before the action that triggers the event is
+/// started so the event can not be missed. A `timeout` of 0 disables it.
+pub(crate) async fn wait_for_event<E, P>(
   mut rx: broadcast::Receiver<E>,
   evt: E::EventType,
+  predicate: P,
   timeout: u32,
 ) -> Result<E, Error>
 where
-  E: IsEvent + Send + Sync + 'static,
-  <E as event_emitter::IsEvent>::EventType: Send + Sync,
+  E: IsEvent,
+  P: Fn(&E) -> bool,
 {
-  consume(&mut rx).await?;
-  let sleep = sleep(Duration::from_millis(timeout as u64));
-  let event = spawn(async move {
+  let event = async move {
     loop {
       match rx.recv().await {
-        Ok(x) if x.event_type() == evt => break Ok(x),
+        Ok(x) if x.event_type() == evt && predicate(&x) => break Ok(x),
         Ok(_) => continue,
+        // Missed events may have been the awaited one
         Err(e) => break Err(e),
       }
     }
-  });
+  };
+  if timeout == 0 {
+    return event.await.map_err(Error::Event);
+  }
+  let sleep = sleep(Duration::from_millis(timeout as u64));
   tokio::select! {
       _ = sleep => Err(Error::Timeout),
       x = event => x.map_err(Error::Event)
   }
 }
 
-async fn consume<E>(rx: &mut broadcast::Receiver<E>) -> Result<(), Error>
+pub(crate) async fn expect_event<E>(
+  rx: broadcast::Receiver<E>,
+  evt: E::EventType,
+  timeout: u32,
+) -> Result<E, Error>
 where
   E: IsEvent,
 {
-  loop {
-    match rx.try_recv() {
-      Err(TryRecvError::Empty) | Err(TryRecvError::Closed) => break,
-      _ => {}
-    }
-  }
-  Ok(())
+  wait_for_event(rx, evt, |_| true, timeout).await
 }
 
 #[cfg(test)]
 mod tests {
+  #[derive(Debug, Clone)]
+  enum Evt {
+    Close,
+  }
+
+  #[derive(Debug, Clone, Copy, PartialEq)]
+  enum EventType {
+    Close,
+  }
+
+  impl super::IsEvent for Evt {
+    type EventType = EventType;
+
+    fn event_type(&self) -> Self::EventType {
+      match self {
+        Self::Close => EventType::Close,
+      }
+    }
+  }
+
   crate::runtime_test!(select, {
     use crate::imp::prelude::*;
     let first = sleep(Duration::from_millis(200u64));
@@ -119,4 +274,31 @@ mod tests {
         _ = second => unreachable!()
     }
   });
+
+  crate::runtime_test!(lagged_wait_fails, {
+    use super::*;
+    let (tx, rx) = broadcast::channel(1);
+    tx.send(Evt::Close).unwrap();
+    tx.send(Evt::Close).unwrap();
+    let res = wait_for_event(rx, EventType::Close, |_| true, 0).await;
+    assert!(matches!(
+      res,
+      Err(Error::Event(broadcast::error::RecvError::Lagged(1)))
+    ));
+  });
+
+  #[test]
+  fn handlers_outside_runtime() {
+    use super::*;
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let listeners = Listeners::<Evt>::default();
+    let (tx, rx) = std::sync::mpsc::channel();
+    let handler = once_handler(move |_: Evt| {
+      tx.send(()).unwrap();
+      async {}
+    });
+    listeners.add(rt.handle(), EventType::Close, true, handler);
+    listeners.dispatch(&Evt::Close);
+    rx.recv_timeout(Duration::from_secs(5)).unwrap();
+  }
 }
@@ -0,0 +1,53 @@
+//! File access on the runtime chosen by the `rt-*` features. actix runs on
+//! tokio, so only async-std needs its own implementation.
+use std::io;
+use std::path::Path;
+
+#[cfg(feature = "rt-async-std")]
+use async_std::fs as imp;
+#[cfg(feature = "rt-async-std")]
+use futures::io::AsyncReadExt;
+#[cfg(feature = "rt-async-std")]
+use futures::io::AsyncWriteExt;
+#[cfg(not(feature = "rt-async-std"))]
+use tokio::fs as imp;
+#[cfg(not(feature = "rt-async-std"))]
+use tokio::io::AsyncReadExt;
+#[cfg(not(feature = "rt-async-std"))]
+use tokio::io::AsyncWriteExt;
+
+pub(crate) async fn read(path: &Path) -> io::Result<Vec<u8>> {
+  imp::read(path).await
+}
+
+pub(crate) async fn write(path: &Path, bytes: &[u8]) -> io::Result<()> {
+  imp::write(path, bytes).await
+}
+
+pub(crate) async fn create_dir_all(path: &Path) -> io::Result<()> {
+  imp::create_dir_all(path).await
+}
+
+pub(crate) struct File(imp::File);
+
+impl File {
+  pub(crate) async fn open(path: &Path) -> io::Result<Self> {
+    imp::File::open(path).await.map(Self)
+  }
+
+  pub(crate) async fn create(path: &Path) -> io::Result<Self> {
+    imp::File::create(path).await.map(Self)
+  }
+
+  pub(crate) async fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+    self.0.read(buf).await
+  }
+
+  pub(crate) async fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+    self.0.write_all(buf).await
+  }
+
+  pub(crate) async fn flush(&mut self) -> io::Result<()> {
+    self.0.flush().await
+  }
+}
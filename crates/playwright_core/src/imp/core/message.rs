@@ -1,4 +1,5 @@
 pub(crate) mod de;
+mod js_value;
 pub(crate) mod ser;
 
 use std::fmt::Debug;
@@ -9,6 +10,9 @@ use serde_json::map::Map;
 use serde_json::value::Value;
 use strong::*;
 
+pub use self::js_value::js_serde;
+pub use self::js_value::JsValue;
+pub(crate) use self::js_value::JS_VALUE;
 use crate::imp::core::Error;
 
 #[derive(Debug, Serialize)]
@@ -96,15 +100,171 @@ pub(crate) struct ErrorWrap {
   error: ErrorMessage,
 }
 
-#[derive(Debug, Deserialize, Serialize, Clone, thiserror::Error)]
-#[error("{name} {message:?}")]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct ErrorMessage {
   pub(crate) name: String,
   pub(crate) message: String,
+  #[serde(default)]
   pub(crate) stack: String,
+  /// Filled in locally with the call that produced this error
+  #[serde(skip)]
+  pub(crate) api_name: Option<String>,
+  #[serde(skip)]
+  pub(crate) call_site: Option<StackFrame>,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+impl std::fmt::Display for ErrorMessage {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    if let Some(api_name) = &self.api_name {
+      write!(f, "{}: ", api_name)?;
+    }
+    write!(f, "{} {:?}", self.name, self.message)
+  }
+}
+
+impl std::error::Error for ErrorMessage {}
+
+/// Classification of an error reported by the driver
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+  /// The operation did not finish within its timeout
+  Timeout,
+  /// The page, context or browser the call targets has been closed
+  TargetClosed,
+  /// Navigation failed with a network error such as
+  /// `net::ERR_NAME_NOT_RESOLVED`
+  Navigation {
+    code: String,
+  },
+  /// A selector matched more than one element in strict mode
+  StrictModeViolation,
+  /// An exception was thrown by script evaluated in the page
+  Evaluation,
+  Other,
+}
+
+/// One frame of a JavaScript stack trace
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StackFrame {
+  pub function: Option<String>,
+  pub url: String,
+  pub line: u32,
+  pub column: u32,
+}
+
+impl ErrorMessage {
+  pub fn name(&self) -> &str {
+    &self.name
+  }
+
+  pub fn message(&self) -> &str {
+    &self.message
+  }
+
+  pub fn stack(&self) -> &str {
+    &self.stack
+  }
+
+  /// The API call that failed, e.g. `page.click`
+  pub fn api_name(&self) -> Option<&str> {
+    self.api_name.as_deref()
+  }
+
+  /// Where the API call that failed was made in Rust code. `url` is the source
+  /// file.
+  pub fn call_site(&self) -> Option<&StackFrame> {
+    self.call_site.as_ref()
+  }
+
+  pub fn kind(&self) -> ErrorKind {
+    let first_line = self.message.lines().next().unwrap_or_default();
+    if self.name == "TimeoutError" {
+      ErrorKind::Timeout
+    } else if self.name == "TargetClosedError"
+      || TARGET_CLOSED_MESSAGES
+        .iter()
+        .any(|m| first_line.ends_with(m))
+    {
+      ErrorKind::TargetClosed
+    } else if first_line.contains("strict mode violation") {
+      ErrorKind::StrictModeViolation
+    } else if let Some(code) = navigation_error_code(first_line) {
+      ErrorKind::Navigation {
+        code: code.to_owned(),
+      }
+    } else if JS_ERROR_NAMES.contains(&self.name.as_str())
+      || self.stack.contains(EVALUATION_SCRIPT_URL)
+    {
+      ErrorKind::Evaluation
+    } else {
+      ErrorKind::Other
+    }
+  }
+
+  /// Parses the frames of `stack`, skipping lines that are not frames
+  pub fn stack_frames(&self) -> Vec<StackFrame> {
+    self.stack.lines().filter_map(StackFrame::parse).collect()
+  }
+
+  pub(crate) fn with_call(mut self, api_name: String, call_site: Option<StackFrame>) -> Self {
+    self.api_name = Some(api_name);
+    self.call_site = call_site;
+    self
+  }
+}
+
+/// Messages of the driver for calls to a closed page, context or browser
+const TARGET_CLOSED_MESSAGES: &[&str] = &[
+  "Target closed",
+  "Target page, context or browser has been closed",
+];
+
+/// Errors only thrown by scripts, unlike the plain `Error` of the driver
+const JS_ERROR_NAMES: &[&str] = &[
+  "EvalError",
+  "RangeError",
+  "ReferenceError",
+  "SyntaxError",
+  "TypeError",
+  "URIError",
+];
+
+/// `sourceURL` of the scripts the driver evaluates, which shows in the stack
+/// of the errors they throw
+const EVALUATION_SCRIPT_URL: &str = "__playwright_evaluation_script__";
+
+fn navigation_error_code(message: &str) -> Option<&str> {
+  let start = message
+    .find("net::ERR_")
+    .or_else(|| message.find("NS_ERROR_"))?;
+  message[start..].split_whitespace().next()
+}
+
+impl StackFrame {
+  /// Accepts both `at f (url:1:2)` (Chromium, WebKit) and `f@url:1:2` (Firefox)
+  fn parse(line: &str) -> Option<Self> {
+    let line = line.trim();
+    let (function, location) = if let Some(rest) = line.strip_prefix("at ") {
+      match rest.strip_suffix(')').and_then(|r| r.split_once(" (")) {
+        Some((f, loc)) => (Some(f), loc),
+        None => (None, rest),
+      }
+    } else {
+      let (f, loc) = line.split_once('@')?;
+      (Some(f).filter(|f| !f.is_empty()), loc)
+    };
+    let (rest, column) = location.rsplit_once(':')?;
+    let (url, line) = rest.rsplit_once(':')?;
+    Some(Self {
+      function: function.map(str::to_owned),
+      url: url.to_owned(),
+      line: line.parse().ok()?,
+      column: column.parse().ok()?,
+    })
+  }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub(crate) struct OnlyGuid {
   pub(crate) guid: Str<Guid>,
 }
@@ -254,3 +414,103 @@ mod datetime {
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn error(name: &str, message: &str, api_name: Option<&str>) -> ErrorMessage {
+    ErrorMessage {
+      name: name.into(),
+      message: message.into(),
+      stack: String::new(),
+      api_name: api_name.map(Into::into),
+      call_site: None,
+    }
+  }
+
+  #[test]
+  fn kind() {
+    assert_eq!(
+      error("TimeoutError", "Timeout 30000ms exceeded.", None).kind(),
+      ErrorKind::Timeout
+    );
+    assert_eq!(
+      error("Error", "Target closed", None).kind(),
+      ErrorKind::TargetClosed
+    );
+    assert_eq!(
+      error(
+        "Error",
+        "net::ERR_NAME_NOT_RESOLVED at http://nowhere.invalid/",
+        Some("Frame.goto")
+      )
+      .kind(),
+      ErrorKind::Navigation {
+        code: "net::ERR_NAME_NOT_RESOLVED".into()
+      }
+    );
+    assert_eq!(
+      error(
+        "Error",
+        "strict mode violation: \"div\" resolved to 2 elements",
+        None
+      )
+      .kind(),
+      ErrorKind::StrictModeViolation
+    );
+    assert_eq!(
+      error(
+        "Error",
+        "Target page, context or browser has been closed",
+        Some("page.click")
+      )
+      .kind(),
+      ErrorKind::TargetClosed
+    );
+    assert_eq!(
+      error("Error", "Dialog has been closed", Some("page.evaluate")).kind(),
+      ErrorKind::Other
+    );
+    assert_eq!(
+      error("TypeError", "x is not a function", None).kind(),
+      ErrorKind::Evaluation
+    );
+    let mut thrown = error("Error", "boom", None);
+    thrown.stack = "Error: boom\n    at eval (__playwright_evaluation_script__:1:7)".into();
+    assert_eq!(thrown.kind(), ErrorKind::Evaluation);
+    assert_eq!(
+      error("Error", "boom", Some("page.evaluate")).kind(),
+      ErrorKind::Other
+    );
+  }
+
+  #[test]
+  fn stack_frames() {
+    let mut e = error("Error", "boom", None);
+    e.stack = "Error: boom\n    at foo (http://localhost/a.js:10:5)\n    at http://localhost/b.js:1:2\nbar@http://localhost/c.js:3:4".into();
+    assert_eq!(
+      e.stack_frames(),
+      vec![
+        StackFrame {
+          function: Some("foo".into()),
+          url: "http://localhost/a.js".into(),
+          line: 10,
+          column: 5
+        },
+        StackFrame {
+          function: None,
+          url: "http://localhost/b.js".into(),
+          line: 1,
+          column: 2
+        },
+        StackFrame {
+          function: Some("bar".into()),
+          url: "http://localhost/c.js".into(),
+          line: 3,
+          column: 4
+        }
+      ]
+    );
+  }
+}
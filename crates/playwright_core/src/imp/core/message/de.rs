@@ -6,6 +6,8 @@ use serde::de::Visitor;
 use serde_json::value::Map;
 use serde_json::value::Value;
 
+use crate::imp::core::JS_VALUE;
+
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
   #[error("{0:}")]
@@ -66,7 +68,12 @@ macro_rules! int {
                 .and_then(|v| v.[<as_$base>]())
                 .ok_or(Error::TypeMismatch);
             let i2 = v.[<as_$base>]().ok_or(Error::TypeMismatch);
-            let i = i1.or(i2)?;
+            let i3 = v.as_object()
+                .and_then(|m| m.get("bi"))
+                .and_then(|v| v.as_str())
+                .and_then(|s| s.parse::<$base>().ok())
+                .ok_or(Error::TypeMismatch);
+            let i = i1.or(i2).or(i3)?;
             let i = $t::try_from(i).map_err(|_| Error::TypeMismatch)?;
             visitor.[<visit_$t>](i)
         }
@@ -139,6 +146,10 @@ impl<'de, 'a> de::Deserializer<'de> for &'a mut Deserializer<'de> {
           self.deserialize_str(visitor)
         } else if let Some(_b) = m.get("b") {
           self.deserialize_bool(visitor)
+        } else if let Some(bi) = m.get("bi") {
+          let _ = self.pop()?;
+          self.stack.push(bi);
+          self.deserialize_str(visitor)
         } else {
           self.deserialize_map(visitor)
         }
@@ -182,11 +193,11 @@ impl<'de, 'a> de::Deserializer<'de> for &'a mut Deserializer<'de> {
     Err(Error::NotSupported("deserialize_byte_buf"))
   }
 
-  fn deserialize_f32<V>(self, _: V) -> Result<V::Value, Self::Error>
+  fn deserialize_f32<V>(self, visitor: V) -> Result<V::Value, Self::Error>
   where
     V: Visitor<'de>,
   {
-    Err(Error::NotSupported("deserialize_f32"))
+    self.deserialize_f64(visitor)
   }
 
   fn deserialize_f64<V>(self, visitor: V) -> Result<V::Value, Self::Error>
@@ -302,12 +313,17 @@ impl<'de, 'a> de::Deserializer<'de> for &'a mut Deserializer<'de> {
 
   fn deserialize_newtype_struct<V>(
     self,
-    _name: &'static str,
+    name: &'static str,
     visitor: V,
   ) -> Result<V::Value, Self::Error>
   where
     V: Visitor<'de>,
   {
+    if name == JS_VALUE {
+      // hand over the protocol representation as is
+      let v = self.pop()?;
+      return Ok(visitor.visit_newtype_struct(v)?);
+    }
     visitor.visit_newtype_struct(self)
   }
 
@@ -0,0 +1,513 @@
+use std::collections::HashMap;
+
+use serde::de;
+use serde::ser;
+
+use super::de::Error;
+use crate::imp::prelude::*;
+
+/// Name the message serializer and deserializer recognize to pass the
+/// protocol representation of a [`JsValue`] through untouched
+pub(crate) const JS_VALUE: &str = "$playwright::JsValue";
+
+/// A JavaScript value that can be passed to and returned from `evaluate`.
+///
+/// Unlike JSON it keeps `undefined`, `NaN`, `Infinity`, `-0`, dates, regular
+/// expressions, bigints, errors and circular references. With other serde
+/// formats it is written in the driver's `SerializedValue` shape so it
+/// round-trips.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsValue {
+  Undefined,
+  Null,
+  Bool(bool),
+  /// Any number including `NaN`, infinities and `-0`
+  Number(f64),
+  /// Decimal digits of a `BigInt`
+  BigInt(String),
+  String(String),
+  /// ISO 8601 representation of a `Date`
+  Date(String),
+  Url(String),
+  RegExp {
+    source: String,
+    flags: String,
+  },
+  Error {
+    name: String,
+    message: String,
+    stack: String,
+  },
+  Array(Vec<JsValue>),
+  Object(Vec<(String, JsValue)>),
+  /// Points back to the `n`th array or object of the enclosing value,
+  /// counting from 1 in depth-first order. Used for circular references.
+  Ref(u32),
+}
+
+impl JsValue {
+  /// A regular expression with `source` as the pattern and `flags` such as
+  /// `"gi"`
+  pub fn regexp(source: impl Into<String>, flags: impl Into<String>) -> Self {
+    Self::RegExp {
+      source: source.into(),
+      flags: flags.into(),
+    }
+  }
+
+  pub fn is_undefined(&self) -> bool {
+    matches!(self, Self::Undefined)
+  }
+
+  pub fn is_null(&self) -> bool {
+    matches!(self, Self::Null)
+  }
+
+  pub fn as_bool(&self) -> Option<bool> {
+    match self {
+      Self::Bool(b) => Some(*b),
+      _ => None,
+    }
+  }
+
+  pub fn as_f64(&self) -> Option<f64> {
+    match self {
+      Self::Number(n) => Some(*n),
+      _ => None,
+    }
+  }
+
+  pub fn as_str(&self) -> Option<&str> {
+    match self {
+      Self::String(s) => Some(s),
+      _ => None,
+    }
+  }
+
+  pub fn as_array(&self) -> Option<&[JsValue]> {
+    match self {
+      Self::Array(a) => Some(a),
+      _ => None,
+    }
+  }
+
+  /// Value of the first property named `key`
+  pub fn get(&self, key: &str) -> Option<&JsValue> {
+    match self {
+      Self::Object(o) => o.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+      _ => None,
+    }
+  }
+
+  #[cfg(feature = "chrono")]
+  pub fn as_datetime(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+    match self {
+      Self::Date(d) => {
+        chrono::DateTime::parse_from_rfc3339(d)
+          .ok()
+          .map(|d| d.with_timezone(&chrono::Utc))
+      }
+      _ => None,
+    }
+  }
+
+  pub(crate) fn to_protocol(&self) -> Value {
+    fn single(k: &str, v: Value) -> Value {
+      let mut m = Map::new();
+      m.insert(k.into(), v);
+      m.into()
+    }
+    fn go(x: &JsValue, last_id: &mut u32) -> Value {
+      match x {
+        JsValue::Undefined => single("v", "undefined".into()),
+        JsValue::Null => single("v", "null".into()),
+        JsValue::Bool(b) => single("b", (*b).into()),
+        JsValue::Number(n) if n.is_nan() => single("v", "NaN".into()),
+        JsValue::Number(n) if n.is_infinite() && *n > 0. => single("v", "Infinity".into()),
+        JsValue::Number(n) if n.is_infinite() => single("v", "-Infinity".into()),
+        JsValue::Number(n) if *n == 0. && n.is_sign_negative() => single("v", "-0".into()),
+        JsValue::Number(n) => single("n", (*n).into()),
+        JsValue::BigInt(s) => single("bi", s.as_str().into()),
+        JsValue::String(s) => single("s", s.as_str().into()),
+        JsValue::Date(s) => single("d", s.as_str().into()),
+        JsValue::Url(s) => single("u", s.as_str().into()),
+        JsValue::RegExp { source, flags } => {
+          let mut r = Map::new();
+          r.insert("p".into(), source.as_str().into());
+          r.insert("f".into(), flags.as_str().into());
+          single("r", r.into())
+        }
+        JsValue::Error {
+          name,
+          message,
+          stack,
+        } => {
+          let mut e = Map::new();
+          e.insert("n".into(), name.as_str().into());
+          e.insert("m".into(), message.as_str().into());
+          e.insert("s".into(), stack.as_str().into());
+          single("e", e.into())
+        }
+        JsValue::Array(a) => {
+          *last_id += 1;
+          let id = *last_id;
+          let a: Vec<Value> = a.iter().map(|x| go(x, last_id)).collect();
+          let mut m = Map::new();
+          m.insert("a".into(), a.into());
+          m.insert("id".into(), id.into());
+          m.into()
+        }
+        JsValue::Object(o) => {
+          *last_id += 1;
+          let id = *last_id;
+          let o: Vec<Value> = o
+            .iter()
+            .map(|(k, v)| {
+              let mut entry = Map::new();
+              entry.insert("k".into(), k.as_str().into());
+              entry.insert("v".into(), go(v, last_id));
+              Value::from(entry)
+            })
+            .collect();
+          let mut m = Map::new();
+          m.insert("o".into(), o.into());
+          m.insert("id".into(), id.into());
+          m.into()
+        }
+        JsValue::Ref(id) => single("ref", (*id).into()),
+      }
+    }
+    go(self, &mut 0)
+  }
+
+  pub(crate) fn from_protocol(v: &Value) -> Result<Self, Error> {
+    fn str_of<'a>(m: &'a Map<String, Value>, k: &str) -> Result<&'a str, Error> {
+      m.get(k).and_then(|v| v.as_str()).ok_or(Error::TypeMismatch)
+    }
+    // Maps ids assigned by the driver to the depth-first position
+    fn enter(m: &Map<String, Value>, ids: &mut HashMap<u64, u32>, last_id: &mut u32) {
+      *last_id += 1;
+      if let Some(id) = m.get("id").and_then(|v| v.as_u64()) {
+        ids.insert(id, *last_id);
+      }
+    }
+    fn go(v: &Value, ids: &mut HashMap<u64, u32>, last_id: &mut u32) -> Result<JsValue, Error> {
+      let m = v.as_object().ok_or(Error::TypeMismatch)?;
+      let x = if let Some(r) = m.get("ref") {
+        let r = r.as_u64().ok_or(Error::TypeMismatch)?;
+        JsValue::Ref(ids.get(&r).copied().ok_or(Error::TypeMismatch)?)
+      } else if let Some(v) = m.get("v") {
+        match v.as_str().ok_or(Error::TypeMismatch)? {
+          "undefined" => JsValue::Undefined,
+          "null" => JsValue::Null,
+          "NaN" => JsValue::Number(f64::NAN),
+          "Infinity" => JsValue::Number(f64::INFINITY),
+          "-Infinity" => JsValue::Number(f64::NEG_INFINITY),
+          "-0" => JsValue::Number(-0.),
+          _ => return Err(Error::TypeMismatch),
+        }
+      } else if let Some(n) = m.get("n") {
+        JsValue::Number(n.as_f64().ok_or(Error::TypeMismatch)?)
+      } else if let Some(b) = m.get("b") {
+        JsValue::Bool(b.as_bool().ok_or(Error::TypeMismatch)?)
+      } else if m.contains_key("s") {
+        JsValue::String(str_of(m, "s")?.into())
+      } else if m.contains_key("bi") {
+        JsValue::BigInt(str_of(m, "bi")?.into())
+      } else if m.contains_key("d") {
+        JsValue::Date(str_of(m, "d")?.into())
+      } else if m.contains_key("u") {
+        JsValue::Url(str_of(m, "u")?.into())
+      } else if let Some(r) = m.get("r") {
+        let r = r.as_object().ok_or(Error::TypeMismatch)?;
+        JsValue::RegExp {
+          source: str_of(r, "p")?.into(),
+          flags: str_of(r, "f")?.into(),
+        }
+      } else if let Some(e) = m.get("e") {
+        let e = e.as_object().ok_or(Error::TypeMismatch)?;
+        JsValue::Error {
+          name: str_of(e, "n")?.into(),
+          message: str_of(e, "m")?.into(),
+          stack: str_of(e, "s").unwrap_or_default().into(),
+        }
+      } else if let Some(a) = m.get("a") {
+        enter(m, ids, last_id);
+        let a = a.as_array().ok_or(Error::TypeMismatch)?;
+        JsValue::Array(
+          a.iter()
+            .map(|x| go(x, ids, last_id))
+            .collect::<Result<_, _>>()?,
+        )
+      } else if let Some(o) = m.get("o") {
+        enter(m, ids, last_id);
+        let o = o.as_array().ok_or(Error::TypeMismatch)?;
+        JsValue::Object(
+          o.iter()
+            .map(|entry| {
+              let k = entry.get("k").and_then(|k| k.as_str());
+              let v = entry.get("v");
+              match (k, v) {
+                (Some(k), Some(v)) => Ok((k.to_owned(), go(v, ids, last_id)?)),
+                _ => Err(Error::TypeMismatch),
+              }
+            })
+            .collect::<Result<_, _>>()?,
+        )
+      } else if m.contains_key("h") {
+        return Err(Error::NotSupported("handle in JsValue"));
+      } else {
+        return Err(Error::TypeMismatch);
+      };
+      Ok(x)
+    }
+    go(v, &mut HashMap::new(), &mut 0)
+  }
+}
+
+impl From<bool> for JsValue {
+  fn from(b: bool) -> Self {
+    Self::Bool(b)
+  }
+}
+
+impl From<f64> for JsValue {
+  fn from(n: f64) -> Self {
+    Self::Number(n)
+  }
+}
+
+impl From<i32> for JsValue {
+  fn from(n: i32) -> Self {
+    Self::Number(n.into())
+  }
+}
+
+impl From<&str> for JsValue {
+  fn from(s: &str) -> Self {
+    Self::String(s.to_owned())
+  }
+}
+
+impl From<String> for JsValue {
+  fn from(s: String) -> Self {
+    Self::String(s)
+  }
+}
+
+impl<T: Into<JsValue>> From<Vec<T>> for JsValue {
+  fn from(a: Vec<T>) -> Self {
+    Self::Array(a.into_iter().map(Into::into).collect())
+  }
+}
+
+impl<T: Into<JsValue>> From<Option<T>> for JsValue {
+  fn from(x: Option<T>) -> Self {
+    x.map_or(Self::Null, Into::into)
+  }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::DateTime<chrono::Utc>> for JsValue {
+  fn from(d: chrono::DateTime<chrono::Utc>) -> Self {
+    Self::Date(d.to_rfc3339_opts(chrono::SecondsFormat::Millis, true))
+  }
+}
+
+impl Serialize for JsValue {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: ser::Serializer,
+  {
+    serializer.serialize_newtype_struct(JS_VALUE, &self.to_protocol())
+  }
+}
+
+impl<'de> Deserialize<'de> for JsValue {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: de::Deserializer<'de>,
+  {
+    struct Protocol;
+
+    impl<'de> de::Visitor<'de> for Protocol {
+      type Value = Value;
+
+      fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("a serialized JavaScript value")
+      }
+
+      fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+      where
+        D: de::Deserializer<'de>,
+      {
+        Value::deserialize(deserializer)
+      }
+    }
+
+    let v = deserializer.deserialize_newtype_struct(JS_VALUE, Protocol)?;
+    Self::from_protocol(&v).map_err(de::Error::custom)
+  }
+}
+
+/// Helpers for `#[serde(with = "...")]` on fields of `evaluate` arguments and
+/// results that JSON can't carry
+///
+/// ```
+/// use playwright_core::api::js_serde;
+/// use serde::Deserialize;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Sample {
+///   #[serde(with = "js_serde::number")]
+///   ratio: f64,
+///   #[serde(with = "js_serde::date")]
+///   at: chrono::DateTime<chrono::Utc>,
+/// }
+/// ```
+pub mod js_serde {
+  /// An `f64` that keeps `NaN`, the infinities and `-0`
+  pub mod number {
+    use serde::de;
+    use serde::ser;
+    use serde::Deserialize;
+    use serde::Serialize;
+
+    use crate::imp::core::JsValue;
+
+    pub fn serialize<S>(x: &f64, serializer: S) -> Result<S::Ok, S::Error>
+    where
+      S: ser::Serializer,
+    {
+      JsValue::Number(*x).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<f64, D::Error>
+    where
+      D: de::Deserializer<'de>,
+    {
+      match JsValue::deserialize(deserializer)? {
+        JsValue::Number(x) => Ok(x),
+        v => Err(de::Error::custom(format!("expected a number, got {:?}", v))),
+      }
+    }
+  }
+
+  /// A `DateTime<Utc>` passed as a JavaScript `Date`
+  #[cfg(feature = "chrono")]
+  pub mod date {
+    use chrono::DateTime;
+    use chrono::Utc;
+    use serde::de;
+    use serde::ser;
+    use serde::Deserialize;
+    use serde::Serialize;
+
+    use crate::imp::core::JsValue;
+
+    pub fn serialize<S>(d: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+      S: ser::Serializer,
+    {
+      JsValue::from(*d).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+    where
+      D: de::Deserializer<'de>,
+    {
+      let v = JsValue::deserialize(deserializer)?;
+      v.as_datetime()
+        .ok_or_else(|| de::Error::custom(format!("expected a date, got {:?}", v)))
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::imp::core::de::from_value;
+  use crate::imp::core::ser::to_value;
+
+  #[test]
+  fn special_values() {
+    let v = JsValue::Array(vec![
+      JsValue::Undefined,
+      JsValue::Number(f64::INFINITY),
+      JsValue::Number(-0.),
+      JsValue::BigInt("12345678901234567890".into()),
+      JsValue::Date("2021-01-01T00:00:00.000Z".into()),
+      JsValue::RegExp {
+        source: "a+".into(),
+        flags: "gi".into(),
+      },
+    ]);
+    let arg = to_value(&v).unwrap();
+    let expected: Value = serde_json::from_str(
+      r#"{"value": {"a": [
+        {"v": "undefined"}, {"v": "Infinity"}, {"v": "-0"},
+        {"bi": "12345678901234567890"}, {"d": "2021-01-01T00:00:00.000Z"},
+        {"r": {"p": "a+", "f": "gi"}}
+      ], "id": 1}, "handles": []}"#,
+    )
+    .unwrap();
+    assert_eq!(arg, expected);
+    let de: JsValue = from_value(&arg["value"]).unwrap();
+    assert_eq!(de, v);
+    let nan: JsValue = from_value(&serde_json::json!({"v": "NaN"})).unwrap();
+    assert!(nan.as_f64().unwrap().is_nan());
+    let zero: JsValue = from_value(&serde_json::json!({"v": "-0"})).unwrap();
+    assert!(zero.as_f64().unwrap().is_sign_negative());
+  }
+
+  #[test]
+  fn circular() {
+    // const a = {x: [1]}; a.x.push(a)
+    let v: Value = serde_json::from_str(
+      r#"{"o": [{"k": "x", "v": {"a": [{"n": 1}, {"ref": 7}], "id": 8}}], "id": 7}"#,
+    )
+    .unwrap();
+    let de: JsValue = from_value(&v).unwrap();
+    assert_eq!(
+      de,
+      JsValue::Object(vec![(
+        "x".into(),
+        JsValue::Array(vec![JsValue::Number(1.), JsValue::Ref(1)])
+      )])
+    );
+    let e: JsValue =
+      from_value(&serde_json::json!({"e": {"n": "TypeError", "m": "boom", "s": ""}})).unwrap();
+    assert_eq!(e.to_protocol()["e"]["m"], "boom");
+    let json = serde_json::to_string(&de).unwrap();
+    assert_eq!(serde_json::from_str::<JsValue>(&json).unwrap(), de);
+  }
+
+  #[cfg(feature = "chrono")]
+  #[test]
+  fn serde_helpers() {
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Sample {
+      #[serde(with = "js_serde::number")]
+      ratio: f64,
+      #[serde(with = "js_serde::date")]
+      at: chrono::DateTime<chrono::Utc>,
+    }
+    let at = chrono::DateTime::parse_from_rfc3339("2021-01-01T00:00:00.000Z")
+      .unwrap()
+      .with_timezone(&chrono::Utc);
+    let arg = to_value(&Sample {
+      ratio: f64::NEG_INFINITY,
+      at,
+    })
+    .unwrap();
+    let expected = serde_json::json!({"o": [
+      {"k": "ratio", "v": {"v": "-Infinity"}},
+      {"k": "at", "v": {"d": "2021-01-01T00:00:00.000Z"}}
+    ]});
+    assert_eq!(arg["value"], expected);
+    let de: Sample = from_value(&arg["value"]).unwrap();
+    assert_eq!(de.ratio, f64::NEG_INFINITY);
+    assert_eq!(de.at, at);
+  }
+}
@@ -6,6 +6,7 @@ use serde::ser;
 
 use crate::imp::core::Guid;
 use crate::imp::core::OnlyGuid;
+use crate::imp::core::JS_VALUE;
 use crate::imp::prelude::*;
 
 #[derive(Debug, thiserror::Error)]
@@ -161,7 +162,7 @@ impl<'a> ser::Serializer for &'a mut Serializer {
   }
 
   fn serialize_f32(self, v: f32) -> Result<Self::Ok, Self::Error> {
-    Ok(f64::from(v).into())
+    self.serialize_f64(v.into())
   }
 
   fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
@@ -214,12 +215,16 @@ impl<'a> ser::Serializer for &'a mut Serializer {
 
   fn serialize_newtype_struct<T>(
     self,
-    _name: &'static str,
+    name: &'static str,
     value: &T,
   ) -> Result<Self::Ok, Self::Error>
   where
     T: ?Sized + Serialize,
   {
+    if name == JS_VALUE {
+      // already in protocol representation
+      return Ok(value.serialize(serde_json::value::Serializer)?);
+    }
     value.serialize(self)
   }
 
@@ -533,8 +538,9 @@ impl<'a> ser::SerializeMap for &'a mut ObjectM {
       return Err(Error::OddMap);
     }
     let serialized = key.serialize(&mut self.prime)?;
-    let key = match serialized {
-      Value::String(s) => s,
+    let key = match serialized.get("s").or_else(|| serialized.get("n")) {
+      Some(Value::String(s)) => s.clone(),
+      Some(Value::Number(n)) => n.to_string(),
       _ => return Err(Error::InvalidKey),
     };
     self.keys.push(key);
@@ -630,6 +636,23 @@ mod tests {
     assert_eq!(to_value(&test).unwrap(), v);
   }
 
+  #[test]
+  fn map() {
+    let mut m = std::collections::BTreeMap::new();
+    m.insert("a", 0.5f32);
+    m.insert("b", f32::NAN);
+    let expected = r#"{
+            "value":{"o":[{"k":"a","v":{"n":0.5}},{"k":"b","v":{"v":"NaN"}}]},
+            "handles": []}"#;
+    let v: Value = serde_json::from_str(expected).unwrap();
+    assert_eq!(to_value(&m).unwrap(), v);
+    let mut m = std::collections::BTreeMap::new();
+    m.insert(1, true);
+    let expected = r#"{"value":{"o":[{"k":"1","v":{"b":true}}]}, "handles": []}"#;
+    let v: Value = serde_json::from_str(expected).unwrap();
+    assert_eq!(to_value(&m).unwrap(), v);
+  }
+
   #[test]
   fn option() {
     let expected = r#"{
@@ -0,0 +1,53 @@
+//! TCP connections on the runtime chosen by the `rt-*` features, like
+//! [`fs`](super::fs)
+use std::io;
+
+#[cfg(feature = "rt-async-std")]
+use futures::io::AsyncReadExt;
+#[cfg(feature = "rt-async-std")]
+use futures::io::AsyncWriteExt;
+#[cfg(not(feature = "rt-async-std"))]
+use tokio::io::AsyncReadExt;
+#[cfg(not(feature = "rt-async-std"))]
+use tokio::io::AsyncWriteExt;
+
+#[cfg(feature = "rt-async-std")]
+type ReadHalf = async_std::net::TcpStream;
+#[cfg(feature = "rt-async-std")]
+type WriteHalf = async_std::net::TcpStream;
+#[cfg(not(feature = "rt-async-std"))]
+type ReadHalf = tokio::net::tcp::OwnedReadHalf;
+#[cfg(not(feature = "rt-async-std"))]
+type WriteHalf = tokio::net::tcp::OwnedWriteHalf;
+
+pub(crate) struct TcpReader(ReadHalf);
+
+pub(crate) struct TcpWriter(WriteHalf);
+
+/// Connects to `host:port`, split into halves that can be used concurrently
+pub(crate) async fn connect(host: &str, port: u16) -> io::Result<(TcpReader, TcpWriter)> {
+  #[cfg(feature = "rt-async-std")]
+  {
+    let socket = async_std::net::TcpStream::connect((host, port)).await?;
+    Ok((TcpReader(socket.clone()), TcpWriter(socket)))
+  }
+  #[cfg(not(feature = "rt-async-std"))]
+  {
+    let socket = tokio::net::TcpStream::connect((host, port)).await?;
+    let (reader, writer) = socket.into_split();
+    Ok((TcpReader(reader), TcpWriter(writer)))
+  }
+}
+
+impl TcpReader {
+  /// Cancel safe, so it can race other futures
+  pub(crate) async fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+    self.0.read(buf).await
+  }
+}
+
+impl TcpWriter {
+  pub(crate) async fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+    self.0.write_all(buf).await
+  }
+}
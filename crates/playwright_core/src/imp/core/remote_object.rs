@@ -144,6 +144,19 @@ pub(crate) trait RemoteObject: Debug {
     upgrade(&self.channel().ctx)
   }
 
+  /// The current runtime, or else the one the connection was opened in
+  fn runtime(&self) -> Result<tokio::runtime::Handle, Error> {
+    if let Ok(rt) = tokio::runtime::Handle::try_current() {
+      return Ok(rt);
+    }
+    self
+      .context()?
+      .lock()
+      .unwrap()
+      .runtime()
+      .ok_or(Error::NoRuntime)
+  }
+
   fn handle_event(
     &self,
     _ctx: &Context,
@@ -165,18 +178,23 @@ mod remote_enum {
   use crate::imp::browser_type::BrowserType;
   use crate::imp::console_message::ConsoleMessage;
   use crate::imp::dialog::Dialog;
+  use crate::imp::electron::Electron;
+  use crate::imp::electron_application::ElectronApplication;
   use crate::imp::element_handle::ElementHandle;
   use crate::imp::frame::Frame;
   use crate::imp::js_handle::JsHandle;
+  use crate::imp::json_pipe::JsonPipe;
   use crate::imp::page::Page;
   use crate::imp::playwright::Playwright;
   use crate::imp::request::Request;
   use crate::imp::response::Response;
   use crate::imp::route::Route;
   use crate::imp::selectors::Selectors;
+  use crate::imp::socks_support::SocksSupport;
   use crate::imp::stream::Stream;
   use crate::imp::websocket::WebSocket;
   use crate::imp::worker::Worker;
+  use crate::imp::writable_stream::WritableStream;
 
   macro_rules! upgrade {
         ($($t:ident),*) => {
@@ -266,11 +284,12 @@ mod remote_enum {
       // CdpSession
       ConsoleMessage,
       Dialog,
-      // Electron
-      // ElectronApplication
+      Electron,
+      ElectronApplication,
       ElementHandle,
       Frame,
       JsHandle,
+      JsonPipe,
       Page,
       Playwright,
       Request,
@@ -278,8 +297,10 @@ mod remote_enum {
       Route,
       Stream,
       Selectors,
+      SocksSupport,
       WebSocket,
-      Worker
+      Worker,
+      WritableStream
   }
 
   impl RemoteArc {
@@ -296,9 +317,14 @@ mod remote_enum {
         "BrowserType" => RemoteArc::BrowserType(Arc::new(BrowserType::try_new(c)?)),
         "ConsoleMessage" => RemoteArc::ConsoleMessage(Arc::new(ConsoleMessage::try_new(ctx, c)?)),
         "Dialog" => RemoteArc::Dialog(Arc::new(Dialog::new(c))),
+        "Electron" => RemoteArc::Electron(Arc::new(Electron::new(c))),
+        "ElectronApplication" => {
+          RemoteArc::ElectronApplication(Arc::new(ElectronApplication::try_new(ctx, c)?))
+        }
         "ElementHandle" => RemoteArc::ElementHandle(Arc::new(ElementHandle::new(c))),
         "Frame" => RemoteArc::Frame(Arc::new(Frame::try_new(ctx, c)?)),
         "JSHandle" => RemoteArc::JsHandle(Arc::new(JsHandle::try_new(c)?)),
+        "JsonPipe" => RemoteArc::JsonPipe(Arc::new(JsonPipe::new(c))),
         "Page" => RemoteArc::Page(Arc::new(Page::try_new(ctx, c)?)),
         "Playwright" => RemoteArc::Playwright(Arc::new(Playwright::try_new(ctx, c)?)),
         "Request" => RemoteArc::Request(Request::try_new(ctx, c)?),
@@ -306,8 +332,10 @@ mod remote_enum {
         "Route" => RemoteArc::Route(Arc::new(Route::try_new(ctx, c)?)),
         "Stream" => RemoteArc::Stream(Arc::new(Stream::new(c))),
         "Selectors" => RemoteArc::Selectors(Arc::new(Selectors::new(c))),
+        "SocksSupport" => RemoteArc::SocksSupport(Arc::new(SocksSupport::new(c))),
         "WebSocket" => RemoteArc::WebSocket(Arc::new(WebSocket::try_new(c)?)),
         "Worker" => RemoteArc::Worker(Arc::new(Worker::try_new(c)?)),
+        "WritableStream" => RemoteArc::WritableStream(Arc::new(WritableStream::new(c))),
         _ => RemoteArc::Dummy(Arc::new(DummyObject::new(c))),
       };
       Ok(r)
@@ -328,9 +356,15 @@ pub(crate) struct RequestBody {
 
 impl RequestBody {
   pub(crate) fn new(guid: Str<Guid>, method: Str<Method>) -> RequestBody {
-    let mut metadata: crate::protocol::generated::Metadata = Default::default();
-    metadata.stack = Some(vec![]);
-    metadata.api_name = Some("".into());
+    let metadata = match ApiCall::current() {
+      Some(call) => call.metadata(),
+      None => {
+        let mut metadata: crate::protocol::generated::Metadata = Default::default();
+        metadata.stack = Some(vec![]);
+        metadata.api_name = Some("".into());
+        metadata
+      }
+    };
     RequestBody {
       guid,
       method,
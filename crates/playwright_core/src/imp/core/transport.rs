@@ -5,6 +5,7 @@ use std::io::Write;
 use std::process::ChildStdin;
 use std::process::ChildStdout;
 
+use serde_json::value::Value;
 use thiserror::Error;
 
 use crate::imp::core::*;
@@ -17,8 +18,10 @@ pub(super) struct Reader {
 }
 
 #[derive(Debug)]
-pub(super) struct Writer {
-  stdin: ChildStdin,
+pub(super) enum Writer {
+  Stdin(ChildStdin),
+  /// Relays messages to a remote server through a `JsonPipe`
+  Pipe(tokio::sync::mpsc::UnboundedSender<Value>),
 }
 
 #[derive(Error, Debug)]
@@ -27,6 +30,8 @@ pub enum TransportError {
   Serde(#[from] serde_json::error::Error),
   #[error(transparent)]
   Io(#[from] io::Error),
+  #[error("Pipe closed")]
+  PipeClosed,
 }
 
 impl Reader {
@@ -74,16 +79,24 @@ impl Reader {
 
 impl Writer {
   pub(super) fn new(stdin: ChildStdin) -> Self {
-    Self { stdin }
+    Self::Stdin(stdin)
   }
 
   pub(super) fn send(&mut self, req: &Req<'_, '_>) -> Result<(), TransportError> {
     log::debug!("SEND {:?}", &req);
-    let serialized = serde_json::to_vec(&req)?;
-    let length = serialized.len() as u32;
-    let mut bytes = length.to_le_bytes().to_vec();
-    bytes.extend(serialized);
-    self.stdin.write_all(&bytes)?;
+    match self {
+      Self::Stdin(stdin) => {
+        let serialized = serde_json::to_vec(&req)?;
+        let length = serialized.len() as u32;
+        let mut bytes = length.to_le_bytes().to_vec();
+        bytes.extend(serialized);
+        stdin.write_all(&bytes)?;
+      }
+      Self::Pipe(tx) => {
+        let message = serde_json::to_value(req)?;
+        tx.send(message).map_err(|_| TransportError::PipeClosed)?;
+      }
+    }
     Ok(())
   }
 }
@@ -1,6 +1,7 @@
 use crate::imp::artifact::Artifact;
 use crate::imp::core::*;
 use crate::imp::prelude::*;
+use crate::imp::stream::Stream;
 
 #[derive(Debug)]
 pub(crate) struct Download {
@@ -38,6 +39,14 @@ impl Download {
     upgrade(&self.artifact)?.save_as(path).await
   }
 
+  pub(crate) async fn create_read_stream(&self) -> ArcResult<Option<Weak<Stream>>> {
+    upgrade(&self.artifact)?.create_read_stream().await
+  }
+
+  pub(crate) async fn cancel(&self) -> ArcResult<()> {
+    upgrade(&self.artifact)?.cancel().await
+  }
+
   pub(crate) async fn failure(&self) -> ArcResult<Option<String>> {
     upgrade(&self.artifact)?.failure().await
   }
@@ -0,0 +1,80 @@
+use crate::imp::browser_type::RecordVideo;
+use crate::imp::core::*;
+use crate::imp::electron_application::ElectronApplication;
+use crate::imp::prelude::*;
+use crate::imp::utils::ColorScheme;
+use crate::imp::utils::Geolocation;
+use crate::imp::utils::Header;
+use crate::imp::utils::HttpCredentials;
+
+#[derive(Debug)]
+pub(crate) struct Electron {
+  channel: ChannelOwner,
+}
+
+impl Electron {
+  pub(crate) fn new(channel: ChannelOwner) -> Self {
+    Self { channel }
+  }
+
+  pub(crate) async fn launch(
+    &self,
+    args: ElectronLaunchArgs,
+  ) -> ArcResult<Weak<ElectronApplication>> {
+    let res = send_message!(self, "launch", args);
+    let guid = only_guid(&res)?;
+    let app = get_object!(self.context()?.lock().unwrap(), guid, ElectronApplication)?;
+    let context = upgrade(&app)?.browser_context();
+    upgrade(&context)?.set_electron_application(app.clone());
+    Ok(app)
+  }
+}
+
+impl RemoteObject for Electron {
+  fn channel(&self) -> &ChannelOwner {
+    &self.channel
+  }
+
+  fn channel_mut(&mut self) -> &mut ChannelOwner {
+    &mut self.channel
+  }
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ElectronLaunchArgs {
+  #[serde(rename = "executablePath")]
+  pub(crate) executable: Option<PathBuf>,
+  pub(crate) args: Option<Vec<String>>,
+  pub(crate) cwd: Option<PathBuf>,
+  #[serde(serialize_with = "name_values")]
+  pub(crate) env: Option<HashMap<String, String>>,
+  pub(crate) timeout: Option<f64>,
+  pub(crate) accept_downloads: Option<bool>,
+  #[serde(rename = "bypassCSP")]
+  pub(crate) bypass_csp: Option<bool>,
+  pub(crate) color_scheme: Option<ColorScheme>,
+  pub(crate) geolocation: Option<Geolocation>,
+  pub(crate) http_credentials: Option<HttpCredentials>,
+  #[serde(rename = "ignoreHTTPSErrors")]
+  pub(crate) ignore_https_errors: Option<bool>,
+  pub(crate) locale: Option<String>,
+  pub(crate) offline: Option<bool>,
+  pub(crate) record_video: Option<RecordVideo>,
+  pub(crate) timezone_id: Option<String>,
+}
+
+/// The protocol takes environment variables as `NameValue`s
+fn name_values<S>(env: &Option<HashMap<String, String>>, s: S) -> Result<S::Ok, S::Error>
+where
+  S: serde::Serializer,
+{
+  let env: Option<Vec<Header>> = env.as_ref().map(|env| {
+    env
+      .iter()
+      .map(|(k, v)| (k.clone(), v.clone()).into())
+      .collect()
+  });
+  env.serialize(s)
+}
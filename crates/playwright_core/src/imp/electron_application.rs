@@ -0,0 +1,171 @@
+use crate::imp::browser_context::BrowserContext;
+use crate::imp::core::*;
+use crate::imp::js_handle::JsHandle;
+use crate::imp::page::Page;
+use crate::imp::prelude::*;
+
+#[derive(Debug)]
+pub(crate) struct ElectronApplication {
+  channel: ChannelOwner,
+  context: Weak<BrowserContext>,
+  tx: Mutex<Option<broadcast::Sender<Evt>>>,
+  listeners: Listeners<Evt>,
+}
+
+impl ElectronApplication {
+  pub(crate) fn try_new(ctx: &Context, channel: ChannelOwner) -> Result<Self, Error> {
+    let Initializer {
+      context: OnlyGuid { guid },
+    } = serde_json::from_value(channel.initializer.clone())?;
+    let context = get_object!(ctx, &guid, BrowserContext)?;
+    Ok(Self {
+      channel,
+      context,
+      tx: Mutex::default(),
+      listeners: Listeners::default(),
+    })
+  }
+
+  pub(crate) fn browser_context(&self) -> Weak<BrowserContext> {
+    self.context.clone()
+  }
+
+  pub(crate) fn windows(&self) -> Vec<Weak<Page>> {
+    self
+      .context
+      .upgrade()
+      .map(|c| c.pages())
+      .unwrap_or_default()
+  }
+
+  pub(crate) async fn browser_window(&self, page: Weak<Page>) -> ArcResult<Weak<JsHandle>> {
+    #[derive(Serialize)]
+    struct Args {
+      page: OnlyGuid,
+    }
+    let guid = upgrade(&page)?.guid().to_owned();
+    let args = Args {
+      page: OnlyGuid { guid },
+    };
+    let v = send_message!(self, "browserWindow", args);
+    let guid = only_guid(&v)?;
+    let h = get_object!(self.context()?.lock().unwrap(), guid, JsHandle)?;
+    Ok(h)
+  }
+
+  pub(crate) async fn evaluate<T, U>(&self, expression: &str, arg: Option<T>) -> ArcResult<U>
+  where
+    T: Serialize,
+    U: DeserializeOwned,
+  {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+      expression: &'a str,
+      arg: Value,
+    }
+    let arg = ser::to_value(&arg).map_err(Error::SerializationPwJson)?;
+    let args = Args { expression, arg };
+    let v = send_message!(self, "evaluateExpression", args);
+    let first = first(&v).ok_or(Error::ObjectNotFound)?;
+    Ok(de::from_value(first).map_err(Error::DeserializationPwJson)?)
+  }
+
+  pub(crate) async fn evaluate_handle<T>(
+    &self,
+    expression: &str,
+    arg: Option<T>,
+  ) -> ArcResult<Weak<JsHandle>>
+  where
+    T: Serialize,
+  {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+      expression: &'a str,
+      arg: Value,
+    }
+    let arg = ser::to_value(&arg).map_err(Error::SerializationPwJson)?;
+    let args = Args { expression, arg };
+    let v = send_message!(self, "evaluateExpressionHandle", args);
+    let guid = only_guid(&v)?;
+    let h = get_object!(self.context()?.lock().unwrap(), guid, JsHandle)?;
+    Ok(h)
+  }
+
+  pub(crate) async fn close(&self) -> ArcResult<()> {
+    let _ = send_message!(self, "close", Map::new());
+    Ok(())
+  }
+
+  pub(crate) fn on_window(&self, page: Weak<Page>) {
+    self.emit_event(Evt::Window(page));
+  }
+}
+
+impl RemoteObject for ElectronApplication {
+  fn channel(&self) -> &ChannelOwner {
+    &self.channel
+  }
+
+  fn channel_mut(&mut self) -> &mut ChannelOwner {
+    &mut self.channel
+  }
+
+  fn handle_event(
+    &self,
+    _ctx: &Context,
+    method: Str<Method>,
+    _params: Map<String, Value>,
+  ) -> Result<(), Error> {
+    if method.as_str() == "close" {
+      self.emit_event(Evt::Close);
+    }
+    Ok(())
+  }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Initializer {
+  context: OnlyGuid,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum Evt {
+  Close,
+  Window(Weak<Page>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EventType {
+  Close,
+  Window,
+}
+
+impl IsEvent for Evt {
+  type EventType = EventType;
+
+  fn event_type(&self) -> Self::EventType {
+    match self {
+      Self::Close => EventType::Close,
+      Self::Window(_) => EventType::Window,
+    }
+  }
+}
+
+impl EventEmitter for ElectronApplication {
+  type Event = Evt;
+
+  fn tx(&self) -> Option<broadcast::Sender<Self::Event>> {
+    self.tx.lock().unwrap().clone()
+  }
+
+  fn set_tx(&self, tx: broadcast::Sender<Self::Event>) {
+    *self.tx.lock().unwrap() = Some(tx);
+  }
+
+  fn listeners(&self) -> &Listeners<Self::Event> {
+    &self.listeners
+  }
+}
@@ -1,5 +1,9 @@
+use crate::imp::browser_context::BrowserContext;
+use crate::imp::browser_context::InputFilePaths;
 use crate::imp::core::*;
 use crate::imp::frame::Frame;
+use crate::imp::js_handle::Handle;
+use crate::imp::page::Page;
 use crate::imp::prelude::*;
 use crate::imp::utils::ElementState;
 use crate::imp::utils::File;
@@ -7,6 +11,9 @@ use crate::imp::utils::FloatRect;
 use crate::imp::utils::KeyboardModifier;
 use crate::imp::utils::MouseButton;
 use crate::imp::utils::Position;
+use crate::imp::utils::ScreenshotAnimations;
+use crate::imp::utils::ScreenshotCaret;
+use crate::imp::utils::ScreenshotScale;
 use crate::imp::utils::ScreenshotType;
 use crate::imp::utils::WaitForSelectorState;
 
@@ -152,7 +159,7 @@ impl ElementHandle {
     Ok(())
   }
 
-  pub(crate) async fn fill(&self, args: FillArgs<'_>) -> ArcResult<()> {
+  pub(crate) async fn fill(&self, args: FillArgs) -> ArcResult<()> {
     let _ = send_message!(self, "fill", args);
     Ok(())
   }
@@ -162,12 +169,12 @@ impl ElementHandle {
     Ok(())
   }
 
-  pub(crate) async fn r#type(&self, args: TypeArgs<'_>) -> ArcResult<()> {
+  pub(crate) async fn r#type(&self, args: TypeArgs) -> ArcResult<()> {
     let _ = send_message!(self, "type", args);
     Ok(())
   }
 
-  pub(crate) async fn press(&self, args: PressArgs<'_>) -> ArcResult<()> {
+  pub(crate) async fn press(&self, args: PressArgs) -> ArcResult<()> {
     let _ = send_message!(self, "press", args);
     Ok(())
   }
@@ -206,9 +213,16 @@ impl ElementHandle {
     Ok(Some(f))
   }
 
-  pub(crate) async fn screenshot(&self, args: ScreenshotArgs<'_>) -> ArcResult<Vec<u8>> {
+  pub(crate) async fn screenshot(&self, mut args: ScreenshotArgs) -> ArcResult<Vec<u8>> {
+    let masks = std::mem::take(&mut args.masks);
     let path = args.path.clone();
-    let v = send_message!(self, "screenshot", args);
+    let v = with_masks(&masks, |mask| {
+      async move {
+        args.mask = mask;
+        Ok::<_, Arc<Error>>(send_message!(self, "screenshot", args))
+      }
+    })
+    .await?;
     let b64 = only_str(&v)?;
     let bytes = base64::decode(b64).map_err(Error::InvalidBase64)?;
     may_save(path.as_deref(), &bytes)?;
@@ -234,7 +248,7 @@ impl ElementHandle {
 
   pub(crate) async fn wait_for_selector(
     &self,
-    args: WaitForSelectorArgs<'_>,
+    args: WaitForSelectorArgs,
   ) -> ArcResult<Option<Weak<ElementHandle>>> {
     let v = send_message!(self, "waitForSelector", args);
     let guid = match as_only_guid(&v) {
@@ -278,6 +292,115 @@ impl ElementHandle {
     let _ = send_message!(self, "setInputFiles", args);
     Ok(())
   }
+
+  pub(crate) async fn set_input_file_paths(
+    &self,
+    mut args: SetInputFilePathsArgs,
+  ) -> ArcResult<()> {
+    let files = self
+      .browser_context()?
+      .input_file_paths(&args.paths)
+      .await?;
+    args.files = Some(files);
+    let _ = send_message!(self, "setInputFilePaths", args);
+    Ok(())
+  }
+
+  /// Presses the mouse on this element and releases it on `target`
+  pub(crate) async fn drag_to(&self, target: &ElementHandle, args: DragToArgs) -> ArcResult<()> {
+    let page = self.page()?;
+    let hover = |position| {
+      HoverArgs {
+        position,
+        timeout: args.timeout,
+        force: args.force,
+        trial: args.trial,
+        ..HoverArgs::default()
+      }
+    };
+    let trial = args.trial.unwrap_or_default();
+    self.hover(hover(args.source_position)).await?;
+    if !trial {
+      page.mouse_down(None, None).await?;
+    }
+    target.hover(hover(args.target_position)).await?;
+    if !trial {
+      page.mouse_up(None, None).await?;
+    }
+    Ok(())
+  }
+
+  /// Handles are created in the scope of their frame
+  fn page(&self) -> Result<Arc<Page>, Error> {
+    match &self.channel().parent {
+      Some(RemoteWeak::Frame(f)) => {
+        let page = upgrade(f)?.page().ok_or(Error::ObjectNotFound)?;
+        upgrade(&page)
+      }
+      Some(RemoteWeak::Page(p)) => upgrade(p),
+      _ => Err(Error::ObjectNotFound),
+    }
+  }
+
+  fn browser_context(&self) -> Result<Arc<BrowserContext>, Error> {
+    upgrade(&self.page()?.browser_context())
+  }
+
+  pub(crate) async fn eval_on_selector<T, U>(
+    &self,
+    selector: &str,
+    expression: &str,
+    arg: Option<T>,
+  ) -> ArcResult<U>
+  where
+    T: Serialize,
+    U: DeserializeOwned,
+  {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a, 'b> {
+      selector: &'a str,
+      expression: &'b str,
+      arg: Value,
+    }
+    let arg = ser::to_value(&arg).map_err(Error::SerializationPwJson)?;
+    let args = Args {
+      selector,
+      expression,
+      arg,
+    };
+    let v = send_message!(self, "evalOnSelector", args);
+    let first = first(&v).ok_or(Error::ObjectNotFound)?;
+    Ok(de::from_value(first).map_err(Error::DeserializationPwJson)?)
+  }
+
+  pub(crate) async fn eval_on_selector_all<T, U>(
+    &self,
+    selector: &str,
+    expression: &str,
+    arg: Option<T>,
+  ) -> ArcResult<U>
+  where
+    T: Serialize,
+    U: DeserializeOwned,
+  {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a, 'b> {
+      selector: &'a str,
+      expression: &'b str,
+      arg: Value,
+    }
+    let arg = ser::to_value(&arg).map_err(Error::SerializationPwJson)?;
+    let args = Args {
+      selector,
+      expression,
+      arg,
+    };
+    let v = send_message!(self, "evalOnSelectorAll", args);
+    let first = first(&v).ok_or(Error::ObjectNotFound)?;
+    Ok(de::from_value(first).map_err(Error::DeserializationPwJson)?)
+  }
 }
 
 pub(super) fn may_save(path: Option<&Path>, bytes: &[u8]) -> Result<(), Error> {
@@ -292,7 +415,7 @@ pub(super) fn may_save(path: Option<&Path>, bytes: &[u8]) -> Result<(), Error> {
 }
 
 #[skip_serializing_none]
-#[derive(Serialize, Default)]
+#[derive(Serialize, Default, Clone)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct HoverArgs {
   pub(crate) modifiers: Option<Vec<KeyboardModifier>>,
@@ -303,7 +426,7 @@ pub(crate) struct HoverArgs {
 }
 
 #[skip_serializing_none]
-#[derive(Serialize, Default)]
+#[derive(Serialize, Default, Clone)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct ClickArgs {
   pub(crate) modifiers: Option<Vec<KeyboardModifier>>,
@@ -319,7 +442,7 @@ pub(crate) struct ClickArgs {
 }
 
 #[skip_serializing_none]
-#[derive(Serialize, Default)]
+#[derive(Serialize, Default, Clone)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct CheckArgs {
   pub(crate) position: Option<Position>,
@@ -330,7 +453,7 @@ pub(crate) struct CheckArgs {
 }
 
 #[skip_serializing_none]
-#[derive(Serialize, Default)]
+#[derive(Serialize, Default, Clone)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct TapArgs {
   pub(crate) modifiers: Option<Vec<KeyboardModifier>>,
@@ -342,18 +465,18 @@ pub(crate) struct TapArgs {
 }
 
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct FillArgs<'a> {
-  value: &'a str,
+pub(crate) struct FillArgs {
+  value: String,
   pub(crate) timeout: Option<f64>,
   pub(crate) no_wait_after: Option<bool>,
 }
 
-impl<'a> FillArgs<'a> {
-  pub(crate) fn new(value: &'a str) -> Self {
+impl FillArgs {
+  pub(crate) fn new(value: &str) -> Self {
     Self {
-      value,
+      value: value.to_owned(),
       timeout: None,
       no_wait_after: None,
     }
@@ -363,19 +486,19 @@ impl<'a> FillArgs<'a> {
 macro_rules! type_args {
   ($t:ident, $f:ident) => {
     #[skip_serializing_none]
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone)]
     #[serde(rename_all = "camelCase")]
-    pub(crate) struct $t<'a> {
-      $f: &'a str,
+    pub(crate) struct $t {
+      $f: String,
       pub(crate) delay: Option<f64>,
       pub(crate) timeout: Option<f64>,
       pub(crate) no_wait_after: Option<bool>,
     }
 
-    impl<'a> $t<'a> {
-      pub(crate) fn new($f: &'a str) -> Self {
+    impl $t {
+      pub(crate) fn new($f: &str) -> Self {
         Self {
-          $f,
+          $f: $f.to_owned(),
           delay: None,
           timeout: None,
           no_wait_after: None,
@@ -389,29 +512,122 @@ type_args! {TypeArgs, text}
 type_args! {PressArgs, key}
 
 #[skip_serializing_none]
-#[derive(Serialize, Default)]
+#[derive(Serialize, Default, Clone)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct ScreenshotArgs<'a> {
-  pub(crate) path: Option<&'a Path>,
+pub(crate) struct ScreenshotArgs {
+  pub(crate) path: Option<PathBuf>,
   pub(crate) timeout: Option<f64>,
   pub(crate) r#type: Option<ScreenshotType>,
   pub(crate) quality: Option<i64>,
   pub(crate) omit_background: Option<bool>,
+  pub(crate) animations: Option<ScreenshotAnimations>,
+  pub(crate) caret: Option<ScreenshotCaret>,
+  pub(crate) scale: Option<ScreenshotScale>,
+  #[serde(skip)]
+  pub(crate) masks: Vec<Mask>,
+  pub(crate) mask: Option<Vec<MaskTarget>>,
+}
+
+/// What to cover with a box in a screenshot
+#[derive(Debug, Clone)]
+pub(crate) enum Mask {
+  Selector(Weak<Frame>, String),
+  Element(Weak<ElementHandle>),
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub(crate) struct MaskTarget {
+  frame: OnlyGuid,
+  selector: String,
+}
+
+impl Mask {
+  const ATTRIBUTE: &'static str = "data-playwright-mask";
+
+  /// The protocol masks by selector only, so elements are marked with an
+  /// attribute to be selected by
+  async fn resolve(&self) -> ArcResult<MaskTarget> {
+    match self {
+      Self::Selector(frame, selector) => {
+        Ok(MaskTarget {
+          frame: OnlyGuid {
+            guid: upgrade(frame)?.channel().guid.clone(),
+          },
+          selector: selector.clone(),
+        })
+      }
+      Self::Element(e) => {
+        let element = upgrade(e)?;
+        let frame = element.owner_frame().await?.ok_or(Error::ObjectNotFound)?;
+        let id = element.guid().as_str().to_owned();
+        let expression = format!("(e, id) => e.setAttribute('{}', id)", Self::ATTRIBUTE);
+        Handle::Element(e.clone())
+          .evaluate::<_, JsValue>(&expression, Some(&id))
+          .await?;
+        Ok(MaskTarget {
+          frame: OnlyGuid {
+            guid: upgrade(&frame)?.channel().guid.clone(),
+          },
+          selector: format!("[{}=\"{}\"]", Self::ATTRIBUTE, id),
+        })
+      }
+    }
+  }
+
+  async fn unmark(&self) -> ArcResult<()> {
+    if let Self::Element(e) = self {
+      let expression = format!("e => e.removeAttribute('{}')", Self::ATTRIBUTE);
+      Handle::Element(e.clone())
+        .evaluate::<(), JsValue>(&expression, None)
+        .await?;
+    }
+    Ok(())
+  }
+}
+
+/// Runs `capture` with `masks` as the protocol takes them and then unmarks the
+/// masked elements, whether it succeeded or not
+pub(crate) async fn with_masks<T, F, Fut>(masks: &[Mask], capture: F) -> ArcResult<T>
+where
+  F: FnOnce(Option<Vec<MaskTarget>>) -> Fut,
+  Fut: Future<Output = ArcResult<T>>,
+{
+  let mut targets = Vec::with_capacity(masks.len());
+  let mut resolved = Ok(());
+  for m in masks {
+    match m.resolve().await {
+      Ok(t) => targets.push(t),
+      Err(e) => {
+        resolved = Err(e);
+        break;
+      }
+    }
+  }
+  let res = match resolved {
+    Ok(()) => capture((!targets.is_empty()).then_some(targets)).await,
+    Err(e) => Err(e),
+  };
+  for m in masks {
+    if let Err(e) = m.unmark().await {
+      log::trace!("failed to unmark a mask: {:?}", e);
+    }
+  }
+  res
 }
 
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct WaitForSelectorArgs<'a> {
-  selector: &'a str,
+pub(crate) struct WaitForSelectorArgs {
+  selector: String,
   pub(crate) state: Option<WaitForSelectorState>,
   pub(crate) timeout: Option<f64>,
 }
 
-impl<'a> WaitForSelectorArgs<'a> {
-  pub(crate) fn new(selector: &'a str) -> Self {
+impl WaitForSelectorArgs {
+  pub(crate) fn new(selector: &str) -> Self {
     Self {
-      selector,
+      selector: selector.to_owned(),
       state: None,
       timeout: None,
     }
@@ -429,7 +645,7 @@ impl RemoteObject for ElementHandle {
 }
 
 #[skip_serializing_none]
-#[derive(Serialize, Default)]
+#[derive(Serialize, Default, Clone)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct SelectOptionArgs {
   pub(crate) options: Option<Vec<Opt>>,
@@ -439,7 +655,7 @@ pub(crate) struct SelectOptionArgs {
   pub(crate) no_wait_after: Option<bool>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Clone)]
 pub(crate) enum Opt {
   Value(String),
   Index(usize),
@@ -447,10 +663,31 @@ pub(crate) enum Opt {
 }
 
 #[skip_serializing_none]
-#[derive(Serialize, Default)]
+#[derive(Serialize, Default, Clone)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct SetInputFilesArgs {
   pub(crate) files: Vec<File>,
   pub(crate) timeout: Option<f64>,
   pub(crate) no_wait_after: Option<bool>,
 }
+
+#[derive(Default, Clone)]
+pub(crate) struct DragToArgs {
+  pub(crate) force: Option<bool>,
+  pub(crate) timeout: Option<f64>,
+  pub(crate) trial: Option<bool>,
+  pub(crate) source_position: Option<Position>,
+  pub(crate) target_position: Option<Position>,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SetInputFilePathsArgs {
+  #[serde(skip)]
+  pub(crate) paths: Vec<PathBuf>,
+  #[serde(flatten)]
+  files: Option<InputFilePaths>,
+  pub(crate) timeout: Option<f64>,
+  pub(crate) no_wait_after: Option<bool>,
+}
@@ -1,9 +1,13 @@
 use std::collections::HashSet;
 use std::iter::FromIterator;
+use std::time::Instant;
 
+use crate::imp::browser_context::BrowserContext;
+use crate::imp::browser_context::InputFilePaths;
 use crate::imp::core::*;
 use crate::imp::element_handle::ElementHandle;
 pub(crate) use crate::imp::element_handle::Opt;
+use crate::imp::js_handle::Handle;
 use crate::imp::js_handle::JsHandle;
 use crate::imp::page;
 use crate::imp::page::Page;
@@ -14,14 +18,18 @@ use crate::imp::utils::File;
 use crate::imp::utils::KeyboardModifier;
 use crate::imp::utils::MouseButton;
 use crate::imp::utils::Position;
+use crate::imp::utils::UrlMatcher;
 use crate::protocol::generated::LifecycleEvent;
 
+const DEFAULT_NAVIGATION_TIMEOUT: u32 = 30000;
+
 #[derive(Debug)]
 pub(crate) struct Frame {
   channel: ChannelOwner,
   parent_frame: Option<Weak<Frame>>,
   var: Mutex<Variable>,
   tx: Mutex<Option<broadcast::Sender<Evt>>>,
+  listeners: Listeners<Evt>,
 }
 
 #[derive(Debug)]
@@ -110,6 +118,7 @@ impl Frame {
       parent_frame,
       var,
       tx: Mutex::default(),
+      listeners: Listeners::default(),
     })
   }
 
@@ -120,7 +129,7 @@ impl Frame {
     Ok(())
   }
 
-  pub(crate) async fn goto(&self, args: GotoArgs<'_, '_>) -> ArcResult<Option<Weak<Response>>> {
+  pub(crate) async fn goto(&self, args: GotoArgs) -> ArcResult<Option<Weak<Response>>> {
     let v = send_message!(self, "goto", args);
     let guid = match as_only_guid(&v) {
       Some(g) => g,
@@ -130,22 +139,22 @@ impl Frame {
     Ok(Some(r))
   }
 
-  pub(crate) async fn click(&self, args: ClickArgs<'_>) -> ArcResult<()> {
+  pub(crate) async fn click(&self, args: ClickArgs) -> ArcResult<()> {
     let _ = send_message!(self, "click", args);
     Ok(())
   }
 
-  pub(crate) async fn dblclick(&self, args: ClickArgs<'_>) -> ArcResult<()> {
+  pub(crate) async fn dblclick(&self, args: ClickArgs) -> ArcResult<()> {
     let _ = send_message!(self, "dblclick", args);
     Ok(())
   }
 
-  pub(crate) async fn tap(&self, args: TapArgs<'_>) -> ArcResult<()> {
+  pub(crate) async fn tap(&self, args: TapArgs) -> ArcResult<()> {
     let _ = send_message!(self, "tap", args);
     Ok(())
   }
 
-  pub(crate) async fn fill(&self, args: FillArgs<'_, '_>) -> ArcResult<()> {
+  pub(crate) async fn fill(&self, args: FillArgs) -> ArcResult<()> {
     let _ = send_message!(self, "fill", args);
     Ok(())
   }
@@ -245,7 +254,7 @@ impl Frame {
 
   pub(crate) async fn wait_for_selector(
     &self,
-    args: WaitForSelectorArgs<'_>,
+    args: WaitForSelectorArgs,
   ) -> ArcResult<Option<Weak<ElementHandle>>> {
     let v = send_message!(self, "waitForSelector", args);
     let guid = match as_only_guid(&v) {
@@ -256,51 +265,63 @@ impl Frame {
     Ok(Some(e))
   }
 
+  pub(crate) async fn highlight(&self, selector: &str) -> ArcResult<()> {
+    let mut args = Map::new();
+    args.insert("selector".into(), selector.into());
+    let _ = send_message!(self, "highlight", args);
+    Ok(())
+  }
+
   pub(crate) async fn title(&self) -> ArcResult<String> {
     let v = send_message!(self, "title", Map::new());
     let s = only_str(&v)?;
     Ok(s.to_owned())
   }
 
-  pub(crate) async fn r#type(&self, args: TypeArgs<'_, '_>) -> ArcResult<()> {
+  pub(crate) async fn r#type(&self, args: TypeArgs) -> ArcResult<()> {
     let _ = send_message!(self, "type", args);
     Ok(())
   }
 
-  pub(crate) async fn press(&self, args: PressArgs<'_, '_>) -> ArcResult<()> {
+  pub(crate) async fn press(&self, args: PressArgs) -> ArcResult<()> {
     let _ = send_message!(self, "press", args);
     Ok(())
   }
 
-  pub(crate) async fn hover(&self, args: HoverArgs<'_>) -> ArcResult<()> {
+  pub(crate) async fn hover(&self, args: HoverArgs) -> ArcResult<()> {
     let _ = send_message!(self, "hover", args);
     Ok(())
   }
 
+  pub(crate) async fn drag_and_drop(&self, args: DragAndDropArgs) -> ArcResult<()> {
+    let _ = send_message!(self, "dragAndDrop", args);
+    Ok(())
+  }
+
   pub(crate) async fn content(&self) -> ArcResult<String> {
     let v = send_message!(self, "content", Map::new());
     let s = only_str(&v)?;
     Ok(s.into())
   }
 
-  pub(crate) async fn set_content(&self, args: SetContentArgs<'_>) -> ArcResult<()> {
+  pub(crate) async fn set_content(&self, args: SetContentArgs) -> ArcResult<()> {
     let _ = send_message!(self, "setContent", args);
     Ok(())
   }
 
-  pub(crate) async fn check(&self, args: CheckArgs<'_>) -> ArcResult<()> {
+  pub(crate) async fn check(&self, args: CheckArgs) -> ArcResult<()> {
     let _ = send_message!(self, "check", args);
     Ok(())
   }
 
-  pub(crate) async fn uncheck(&self, args: CheckArgs<'_>) -> ArcResult<()> {
+  pub(crate) async fn uncheck(&self, args: CheckArgs) -> ArcResult<()> {
     let _ = send_message!(self, "uncheck", args);
     Ok(())
   }
 
   pub(crate) async fn add_script_tag(
     &self,
-    args: AddScriptTagArgs<'_, '_, '_>,
+    args: AddScriptTagArgs,
   ) -> ArcResult<Weak<ElementHandle>> {
     let v = send_message!(self, "addScriptTag", args);
     let guid = only_guid(&v)?;
@@ -363,13 +384,7 @@ impl Frame {
     let args = Args { expression, arg };
     let v = send_message!(self, "evaluateExpressionHandle", args);
     let guid = only_guid(&v)?;
-    let e = get_object!(self.context()?.lock().unwrap(), guid, ElementHandle)
-      .ok()
-      .map(Handle::Element);
-    let j = get_object!(self.context()?.lock().unwrap(), guid, JsHandle)
-      .ok()
-      .map(Handle::Js);
-    let h = e.or(j).ok_or(Error::ObjectNotFound)?;
+    let h = Handle::find(&self.context()?.lock().unwrap(), guid)?;
     Ok(h)
   }
 
@@ -455,7 +470,7 @@ impl Frame {
     Ok(())
   }
 
-  pub(crate) async fn select_option(&self, args: SelectOptionArgs<'_>) -> ArcResult<Vec<String>> {
+  pub(crate) async fn select_option(&self, args: SelectOptionArgs) -> ArcResult<Vec<String>> {
     let v = send_message!(self, "selectOption", args);
     let first = first(&v).ok_or(Error::InvalidParams)?;
     let ss = first
@@ -468,20 +483,129 @@ impl Frame {
     Ok(ss)
   }
 
-  pub(crate) async fn set_input_files(&self, args: SetInputFilesArgs<'_>) -> ArcResult<()> {
+  pub(crate) async fn set_input_files(&self, args: SetInputFilesArgs) -> ArcResult<()> {
     let _ = send_message!(self, "setInputFiles", args);
     Ok(())
   }
 
+  pub(crate) async fn set_input_file_paths(
+    &self,
+    mut args: SetInputFilePathsArgs,
+  ) -> ArcResult<()> {
+    let files = self
+      .browser_context()?
+      .input_file_paths(&args.paths)
+      .await?;
+    args.files = Some(files);
+    let _ = send_message!(self, "setInputFilePaths", args);
+    Ok(())
+  }
+
   pub(crate) async fn wait_for_function(
     &self,
-    args: WaitForFunctionArgs<'_>,
+    args: WaitForFunctionArgs,
   ) -> ArcResult<Weak<JsHandle>> {
     let v = send_message!(self, "waitForFunction", args);
     let guid = only_guid(&v)?;
     let h = get_object!(self.context()?.lock().unwrap(), guid, JsHandle)?;
     Ok(h)
   }
+
+  pub(crate) async fn wait_for_load_state(
+    &self,
+    state: DocumentLoadState,
+    timeout: Option<f64>,
+  ) -> ArcResult<()> {
+    let state = LifecycleEvent::from(state);
+    let rx = self.subscribe_event();
+    if self.has_load_state(state) {
+      return Ok(());
+    }
+    let timeout = self.navigation_timeout(timeout);
+    let reached = |e: &Evt| matches!(e, Evt::LoadState(x) if *x == state);
+    wait_for_event(rx, EventType::LoadState, reached, timeout).await?;
+    Ok(())
+  }
+
+  pub(crate) async fn wait_for_url(
+    &self,
+    url: &UrlMatcher,
+    wait_until: DocumentLoadState,
+    timeout: Option<f64>,
+  ) -> ArcResult<()> {
+    let rx = self.subscribe_event();
+    if url.matches(&self.url()) {
+      return self.wait_for_load_state(wait_until, timeout).await;
+    }
+    self
+      .wait_for_navigation(rx, Some(url), wait_until, timeout)
+      .await?;
+    Ok(())
+  }
+
+  /// Waits on `rx` for this frame to navigate to a URL accepted by `url` and
+  /// then to reach `wait_until`. `rx` must be subscribed before the action
+  /// that navigates. Returns the main resource response, which same document
+  /// navigations don't have.
+  pub(crate) async fn wait_for_navigation(
+    &self,
+    rx: broadcast::Receiver<Evt>,
+    url: Option<&UrlMatcher>,
+    wait_until: DocumentLoadState,
+    timeout: Option<f64>,
+  ) -> ArcResult<Option<Weak<Response>>> {
+    let timeout = self.navigation_timeout(timeout);
+    let start = Instant::now();
+    let navigated = |e: &Evt| {
+      match e {
+        Evt::Navigated(x) => url.map_or(true, |u| u.matches(&x.url)),
+        _ => false,
+      }
+    };
+    let event = match wait_for_event(rx, EventType::Navigated, navigated, timeout).await? {
+      Evt::Navigated(x) => x,
+      _ => unreachable!(),
+    };
+    if let Some(message) = event.error {
+      let e = ErrorMessage {
+        name: "Error".into(),
+        message,
+        stack: String::new(),
+        api_name: None,
+        call_site: None,
+      };
+      return Err(Error::ErrorResponded(Arc::new(e)).into());
+    }
+    let remaining = match timeout {
+      0 => 0,
+      t => t.saturating_sub(start.elapsed().as_millis() as u32).max(1),
+    };
+    self
+      .wait_for_load_state(wait_until, Some(remaining.into()))
+      .await?;
+    let request = match event.new_document {
+      Some(Document { request }) if !request.is_null() => request,
+      _ => return Ok(None),
+    };
+    let guid = guid_from_params(&request)?;
+    let request = get_object!(self.context()?.lock().unwrap(), guid, Request)?;
+    let request = upgrade(&request)?;
+    request.response().await
+  }
+
+  fn navigation_timeout(&self, timeout: Option<f64>) -> u32 {
+    match timeout {
+      Some(t) => t as u32,
+      None => {
+        self
+          .page()
+          .and_then(|p| p.upgrade())
+          .map_or(DEFAULT_NAVIGATION_TIMEOUT, |p| {
+            p.default_navigation_timeout()
+          })
+      }
+    }
+  }
 }
 
 // mutable
@@ -498,6 +622,11 @@ impl Frame {
     self.var.lock().unwrap().page.clone()
   }
 
+  pub(crate) fn browser_context(&self) -> Result<Arc<BrowserContext>, Error> {
+    let page = self.page().ok_or(Error::ObjectNotFound)?;
+    upgrade(&upgrade(&page)?.browser_context())
+  }
+
   pub(crate) fn set_page(&self, page: Weak<Page>) {
     self.var.lock().unwrap().page = Some(page);
   }
@@ -506,6 +635,10 @@ impl Frame {
     self.parent_frame.clone()
   }
 
+  pub(crate) fn has_load_state(&self, state: LifecycleEvent) -> bool {
+    self.var.lock().unwrap().load_states.contains(&state)
+  }
+
   pub(crate) fn child_frames(&self) -> Vec<Weak<Frame>> {
     self.var.lock().unwrap().child_frames.clone()
   }
@@ -604,6 +737,10 @@ impl EventEmitter for Frame {
   fn set_tx(&self, tx: broadcast::Sender<Self::Event>) {
     *self.tx.lock().unwrap() = Some(tx);
   }
+
+  fn listeners(&self) -> &Listeners<Self::Event> {
+    &self.listeners
+  }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -623,25 +760,20 @@ impl IsEvent for Evt {
   }
 }
 
-enum Handle {
-  Js(Weak<JsHandle>),
-  Element(Weak<ElementHandle>),
-}
-
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct GotoArgs<'a, 'b> {
-  url: &'a str,
+pub(crate) struct GotoArgs {
+  url: String,
   pub(crate) timeout: Option<f64>,
   pub(crate) wait_until: Option<DocumentLoadState>,
-  pub(crate) referer: Option<&'b str>,
+  pub(crate) referer: Option<String>,
 }
 
-impl<'a> GotoArgs<'a, '_> {
-  pub(crate) fn new(url: &'a str) -> Self {
+impl GotoArgs {
+  pub(crate) fn new(url: &str) -> Self {
     Self {
-      url,
+      url: url.to_owned(),
       timeout: None,
       wait_until: None,
       referer: None,
@@ -650,10 +782,10 @@ impl<'a> GotoArgs<'a, '_> {
 }
 
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct ClickArgs<'a> {
-  selector: &'a str,
+pub(crate) struct ClickArgs {
+  selector: String,
   pub(crate) modifiers: Option<Vec<KeyboardModifier>>,
   pub(crate) position: Option<Position>,
   pub(crate) delay: Option<f64>,
@@ -666,10 +798,10 @@ pub(crate) struct ClickArgs<'a> {
   pub(crate) trial: Option<bool>,
 }
 
-impl<'a> ClickArgs<'a> {
-  pub(crate) fn new(selector: &'a str) -> Self {
+impl ClickArgs {
+  pub(crate) fn new(selector: &str) -> Self {
     Self {
-      selector,
+      selector: selector.to_owned(),
       modifiers: None,
       position: None,
       delay: None,
@@ -685,25 +817,25 @@ impl<'a> ClickArgs<'a> {
 }
 
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct WaitForSelectorArgs<'a> {
-  selector: &'a str,
+pub(crate) struct WaitForSelectorArgs {
+  selector: String,
   pub(crate) timeout: Option<f64>,
   pub(crate) state: Option<FrameState>,
 }
 
-impl<'a> WaitForSelectorArgs<'a> {
-  pub(crate) fn new(selector: &'a str) -> Self {
+impl WaitForSelectorArgs {
+  pub(crate) fn new(selector: &str) -> Self {
     Self {
-      selector,
+      selector: selector.to_owned(),
       timeout: None,
       state: None,
     }
   }
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum FrameState {
   Attached,
@@ -714,21 +846,21 @@ pub enum FrameState {
 
 macro_rules! type_args {
   ($t:ident, $f:ident) => {
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone)]
     #[serde(rename_all = "camelCase")]
-    pub(crate) struct $t<'a, 'b> {
-      selector: &'a str,
-      $f: &'b str,
+    pub(crate) struct $t {
+      selector: String,
+      $f: String,
       pub(crate) delay: Option<f64>,
       pub(crate) timeout: Option<f64>,
       pub(crate) no_wait_after: Option<bool>,
     }
 
-    impl<'a, 'b> $t<'a, 'b> {
-      pub(crate) fn new(selector: &'a str, $f: &'b str) -> Self {
+    impl $t {
+      pub(crate) fn new(selector: &str, $f: &str) -> Self {
         Self {
-          selector,
-          $f,
+          selector: selector.to_owned(),
+          $f: $f.to_owned(),
           delay: None,
           timeout: None,
           no_wait_after: None,
@@ -742,10 +874,10 @@ type_args! {TypeArgs, text}
 type_args! {PressArgs, key}
 
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct HoverArgs<'a> {
-  selector: &'a str,
+pub(crate) struct HoverArgs {
+  selector: String,
   pub(crate) modifiers: Option<Vec<KeyboardModifier>>,
   pub(crate) position: Option<Position>,
   pub(crate) timeout: Option<f64>,
@@ -753,10 +885,10 @@ pub(crate) struct HoverArgs<'a> {
   pub(crate) trial: Option<bool>,
 }
 
-impl<'a> HoverArgs<'a> {
-  pub(crate) fn new(selector: &'a str) -> Self {
+impl HoverArgs {
+  pub(crate) fn new(selector: &str) -> Self {
     Self {
-      selector,
+      selector: selector.to_owned(),
       modifiers: None,
       position: None,
       timeout: None,
@@ -767,18 +899,18 @@ impl<'a> HoverArgs<'a> {
 }
 
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct SetContentArgs<'a> {
-  html: &'a str,
+pub(crate) struct SetContentArgs {
+  html: String,
   pub(crate) timeout: Option<f64>,
   pub(crate) wait_until: Option<DocumentLoadState>,
 }
 
-impl<'a> SetContentArgs<'a> {
-  pub(crate) fn new(html: &'a str) -> Self {
+impl SetContentArgs {
+  pub(crate) fn new(html: &str) -> Self {
     Self {
-      html,
+      html: html.to_owned(),
       timeout: None,
       wait_until: None,
     }
@@ -786,10 +918,10 @@ impl<'a> SetContentArgs<'a> {
 }
 
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct TapArgs<'a> {
-  selector: &'a str,
+pub(crate) struct TapArgs {
+  selector: String,
   pub(crate) modifiers: Option<Vec<KeyboardModifier>>,
   pub(crate) position: Option<Position>,
   pub(crate) timeout: Option<f64>,
@@ -798,10 +930,10 @@ pub(crate) struct TapArgs<'a> {
   pub(crate) trial: Option<bool>,
 }
 
-impl<'a> TapArgs<'a> {
-  pub(crate) fn new(selector: &'a str) -> Self {
+impl TapArgs {
+  pub(crate) fn new(selector: &str) -> Self {
     Self {
-      selector,
+      selector: selector.to_owned(),
       modifiers: None,
       position: None,
       timeout: None,
@@ -813,20 +945,20 @@ impl<'a> TapArgs<'a> {
 }
 
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct FillArgs<'a, 'b> {
-  selector: &'a str,
-  value: &'b str,
+pub(crate) struct FillArgs {
+  selector: String,
+  value: String,
   pub(crate) timeout: Option<f64>,
   pub(crate) no_wait_after: Option<bool>,
 }
 
-impl<'a, 'b> FillArgs<'a, 'b> {
-  pub(crate) fn new(selector: &'a str, value: &'b str) -> Self {
+impl FillArgs {
+  pub(crate) fn new(selector: &str, value: &str) -> Self {
     Self {
-      selector,
-      value,
+      selector: selector.to_owned(),
+      value: value.to_owned(),
       timeout: None,
       no_wait_after: None,
     }
@@ -842,10 +974,10 @@ struct SelectorTimeout<'a> {
 }
 
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct CheckArgs<'a> {
-  selector: &'a str,
+pub(crate) struct CheckArgs {
+  selector: String,
   pub(crate) position: Option<Position>,
   pub(crate) timeout: Option<f64>,
   pub(crate) force: Option<bool>,
@@ -853,10 +985,10 @@ pub(crate) struct CheckArgs<'a> {
   pub(crate) trial: Option<bool>,
 }
 
-impl<'a> CheckArgs<'a> {
-  pub(crate) fn new(selector: &'a str) -> Self {
+impl CheckArgs {
+  pub(crate) fn new(selector: &str) -> Self {
     Self {
-      selector,
+      selector: selector.to_owned(),
       position: None,
       timeout: None,
       force: None,
@@ -867,18 +999,18 @@ impl<'a> CheckArgs<'a> {
 }
 
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct AddScriptTagArgs<'a, 'b, 'c> {
-  content: &'a str,
-  pub(crate) url: Option<&'b str>,
-  pub(crate) r#type: Option<&'c str>,
+pub(crate) struct AddScriptTagArgs {
+  content: String,
+  pub(crate) url: Option<String>,
+  pub(crate) r#type: Option<String>,
 }
 
-impl<'a, 'b, 'c> AddScriptTagArgs<'a, 'b, 'c> {
-  pub(crate) fn new(content: &'a str) -> Self {
+impl AddScriptTagArgs {
+  pub(crate) fn new(content: &str) -> Self {
     Self {
-      content,
+      content: content.to_owned(),
       url: None,
       r#type: None,
     }
@@ -886,10 +1018,10 @@ impl<'a, 'b, 'c> AddScriptTagArgs<'a, 'b, 'c> {
 }
 
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct SelectOptionArgs<'a> {
-  selector: &'a str,
+pub(crate) struct SelectOptionArgs {
+  selector: String,
 
   pub(crate) options: Option<Vec<Opt>>,
   pub(crate) elements: Option<Vec<OnlyGuid>>,
@@ -898,10 +1030,10 @@ pub(crate) struct SelectOptionArgs<'a> {
   pub(crate) no_wait_after: Option<bool>,
 }
 
-impl<'a> SelectOptionArgs<'a> {
-  pub(crate) fn new(selector: &'a str) -> Self {
+impl SelectOptionArgs {
+  pub(crate) fn new(selector: &str) -> Self {
     Self {
-      selector,
+      selector: selector.to_owned(),
       options: None,
       elements: None,
       timeout: None,
@@ -911,20 +1043,20 @@ impl<'a> SelectOptionArgs<'a> {
 }
 
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct SetInputFilesArgs<'a> {
-  selector: &'a str,
+pub(crate) struct SetInputFilesArgs {
+  selector: String,
 
   pub(crate) files: Vec<File>,
   pub(crate) timeout: Option<f64>,
   pub(crate) no_wait_after: Option<bool>,
 }
 
-impl<'a> SetInputFilesArgs<'a> {
-  pub(crate) fn new(selector: &'a str) -> Self {
+impl SetInputFilesArgs {
+  pub(crate) fn new(selector: &str) -> Self {
     Self {
-      selector,
+      selector: selector.to_owned(),
       files: Vec::new(),
       timeout: None,
       no_wait_after: None,
@@ -933,16 +1065,73 @@ impl<'a> SetInputFilesArgs<'a> {
 }
 
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct DragAndDropArgs {
+  source: String,
+  target: String,
+  pub(crate) force: Option<bool>,
+  pub(crate) no_wait_after: Option<bool>,
+  pub(crate) timeout: Option<f64>,
+  pub(crate) trial: Option<bool>,
+  pub(crate) source_position: Option<Position>,
+  pub(crate) target_position: Option<Position>,
+  pub(crate) strict: Option<bool>,
+}
+
+impl DragAndDropArgs {
+  pub(crate) fn new(source: &str, target: &str) -> Self {
+    Self {
+      source: source.to_owned(),
+      target: target.to_owned(),
+      force: None,
+      no_wait_after: None,
+      timeout: None,
+      trial: None,
+      source_position: None,
+      target_position: None,
+      strict: None,
+    }
+  }
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct WaitForFunctionArgs<'a> {
-  expression: &'a str,
+pub(crate) struct SetInputFilePathsArgs {
+  selector: String,
+  #[serde(skip)]
+  pub(crate) paths: Vec<PathBuf>,
+  #[serde(flatten)]
+  files: Option<InputFilePaths>,
+  pub(crate) timeout: Option<f64>,
+  pub(crate) no_wait_after: Option<bool>,
+}
+
+impl SetInputFilePathsArgs {
+  pub(crate) fn new(selector: &str) -> Self {
+    Self {
+      selector: selector.to_owned(),
+      paths: Vec::new(),
+      files: None,
+      timeout: None,
+      no_wait_after: None,
+    }
+  }
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct WaitForFunctionArgs {
+  expression: String,
   pub(crate) timeout: Option<f64>,
   pub(crate) polling: Option<Polling>,
   // XXX
   pub(crate) arg: Option<Value>,
 }
 
+#[derive(Clone)]
 pub enum Polling {
   RequestAnimationFrame,
   Millis(u32),
@@ -960,10 +1149,10 @@ impl Serialize for Polling {
   }
 }
 
-impl<'a> WaitForFunctionArgs<'a> {
-  pub(crate) fn new(expression: &'a str) -> Self {
+impl WaitForFunctionArgs {
+  pub(crate) fn new(expression: &str) -> Self {
     Self {
-      expression,
+      expression: expression.to_owned(),
       timeout: None,
       polling: None,
       arg: None,
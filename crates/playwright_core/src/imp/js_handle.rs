@@ -1,6 +1,7 @@
 use std::fmt;
 
 use crate::imp::core::*;
+use crate::imp::element_handle::ElementHandle;
 use crate::imp::prelude::*;
 
 #[derive(Debug)]
@@ -20,37 +21,73 @@ impl JsHandle {
     let var = Mutex::new(Var { preview });
     Ok(Self { channel, var })
   }
+}
+
+/// Either kind of handle the driver creates for a JavaScript value. DOM
+/// nodes get `ElementHandle`s, which accept every `JSHandle` message too.
+#[derive(Debug, Clone)]
+pub(crate) enum Handle {
+  Js(Weak<JsHandle>),
+  Element(Weak<ElementHandle>),
+}
+
+impl Handle {
+  pub(crate) fn find(ctx: &Context, guid: &S<Guid>) -> Result<Self, Error> {
+    match ctx.find_object(guid) {
+      Some(RemoteWeak::JsHandle(x)) => Ok(Self::Js(x)),
+      Some(RemoteWeak::ElementHandle(x)) => Ok(Self::Element(x)),
+      _ => Err(Error::ObjectNotFound),
+    }
+  }
+
+  pub(crate) fn upgrade(&self) -> Result<RemoteArc, Error> {
+    match self {
+      Self::Js(x) => upgrade(x).map(RemoteArc::JsHandle),
+      Self::Element(x) => upgrade(x).map(RemoteArc::ElementHandle),
+    }
+  }
+
+  pub(crate) fn guid(&self) -> Result<Str<Guid>, Error> {
+    Ok(self.upgrade()?.channel().guid.to_owned())
+  }
+
+  fn context(r: &RemoteArc) -> Result<Arc<Mutex<Context>>, Error> {
+    upgrade(&r.channel().ctx)
+  }
 
-  pub(crate) async fn get_property(&self, name: &str) -> ArcResult<Weak<JsHandle>> {
+  pub(crate) async fn get_property(&self, name: &str) -> ArcResult<Handle> {
+    let r = self.upgrade()?;
     let mut args = HashMap::new();
     args.insert("name", name);
-    let v = send_message!(self, "getProperty", args);
+    let v = send_message!(r, "getProperty", args);
     let guid = only_guid(&v)?;
-    let j = get_object!(self.context()?.lock().unwrap(), guid, JsHandle)?;
-    Ok(j)
+    let h = Handle::find(&Self::context(&r)?.lock().unwrap(), guid)?;
+    Ok(h)
   }
 
-  pub(crate) async fn get_properties(&self) -> ArcResult<HashMap<String, Weak<JsHandle>>> {
-    let v = send_message!(self, "getPropertyList", Map::new());
+  pub(crate) async fn get_properties(&self) -> ArcResult<HashMap<String, Handle>> {
+    let r = self.upgrade()?;
+    let v = send_message!(r, "getPropertyList", Map::new());
     let first = first(&v).ok_or(Error::InvalidParams)?;
     let properties: Vec<Property> =
       serde_json::from_value((*first).clone()).map_err(Error::Serde)?;
+    let ctx = Self::context(&r)?;
+    let ctx = ctx.lock().unwrap();
     let ps = properties
       .into_iter()
       .map(
         |Property {
            name,
            value: OnlyGuid { guid },
-         }| {
-          get_object!(self.context()?.lock().unwrap(), &guid, JsHandle).map(|o| (name, o))
-        },
+         }| Handle::find(&ctx, &guid).map(|h| (name, h)),
       )
       .collect::<Result<HashMap<_, _>, Error>>()?;
     Ok(ps)
   }
 
   pub(crate) async fn dispose(&self) -> ArcResult<()> {
-    let _ = send_message!(self, "dispose", Map::new());
+    let r = self.upgrade()?;
+    let _ = send_message!(r, "dispose", Map::new());
     Ok(())
   }
 
@@ -58,10 +95,53 @@ impl JsHandle {
   where
     U: DeserializeOwned,
   {
-    let v = send_message!(self, "jsonValue", Map::new());
+    let r = self.upgrade()?;
+    let v = send_message!(r, "jsonValue", Map::new());
+    let first = first(&v).ok_or(Error::ObjectNotFound)?;
+    Ok(de::from_value(first).map_err(Error::DeserializationPwJson)?)
+  }
+
+  pub(crate) async fn evaluate<T, U>(&self, expression: &str, arg: Option<T>) -> ArcResult<U>
+  where
+    T: Serialize,
+    U: DeserializeOwned,
+  {
+    let r = self.upgrade()?;
+    let args = EvaluateArgs::new(expression, &arg)?;
+    let v = send_message!(r, "evaluateExpression", args);
     let first = first(&v).ok_or(Error::ObjectNotFound)?;
     Ok(de::from_value(first).map_err(Error::DeserializationPwJson)?)
   }
+
+  pub(crate) async fn evaluate_handle<T>(
+    &self,
+    expression: &str,
+    arg: Option<T>,
+  ) -> ArcResult<Handle>
+  where
+    T: Serialize,
+  {
+    let r = self.upgrade()?;
+    let args = EvaluateArgs::new(expression, &arg)?;
+    let v = send_message!(r, "evaluateExpressionHandle", args);
+    let guid = only_guid(&v)?;
+    let h = Handle::find(&Self::context(&r)?.lock().unwrap(), guid)?;
+    Ok(h)
+  }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct EvaluateArgs<'a> {
+  expression: &'a str,
+  arg: Value,
+}
+
+impl<'a> EvaluateArgs<'a> {
+  fn new<T: Serialize>(expression: &'a str, arg: &Option<T>) -> Result<Self, Error> {
+    let arg = ser::to_value(arg).map_err(Error::SerializationPwJson)?;
+    Ok(Self { expression, arg })
+  }
 }
 
 impl JsHandle {
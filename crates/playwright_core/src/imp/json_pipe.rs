@@ -0,0 +1,102 @@
+use tokio::sync::mpsc;
+
+use crate::imp::core::*;
+use crate::imp::prelude::*;
+
+/// Carries the messages of a connection to a remote playwright server
+#[derive(Debug)]
+pub(crate) struct JsonPipe {
+  channel: ChannelOwner,
+  remote: Mutex<Option<Am<Context>>>,
+}
+
+impl JsonPipe {
+  pub(crate) fn new(channel: ChannelOwner) -> Self {
+    Self {
+      channel,
+      remote: Mutex::default(),
+    }
+  }
+
+  /// Creates the context of the remote connection. The pipe owns it until
+  /// closed.
+  pub(crate) fn open(this: Weak<Self>) -> ArcResult<Wm<Context>> {
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let remote = Context::new_piped(tx);
+    let weak = Arc::downgrade(&remote);
+    *upgrade(&this)?.remote.lock().unwrap() = Some(remote);
+    // Requests of the remote context are written with it locked, so they are
+    // queued and forwarded by a task of their own.
+    spawn(async move {
+      while let Some(message) = rx.recv().await {
+        let pipe = match this.upgrade() {
+          Some(x) => x,
+          None => break,
+        };
+        if let Err(e) = pipe.send(message).await {
+          log::trace!("failed to send through pipe: {:?}", e);
+          break;
+        }
+      }
+    });
+    Ok(weak)
+  }
+
+  async fn send(&self, message: Value) -> ArcResult<()> {
+    let mut args = HashMap::new();
+    args.insert("message", message);
+    let _ = send_message!(self, "send", args);
+    Ok(())
+  }
+
+  pub(crate) async fn close(&self) -> ArcResult<()> {
+    let _ = send_message!(self, "close", Map::new());
+    Ok(())
+  }
+
+  fn on_message(&self, params: Map<String, Value>) -> Result<(), Error> {
+    let message = params.get("message").ok_or(Error::InvalidParams)?;
+    let msg: Res = serde_json::from_value(message.clone())?;
+    let remote = match &*self.remote.lock().unwrap() {
+      Some(x) => x.clone(),
+      None => return Ok(()),
+    };
+    let status = remote.lock().unwrap().dispatch(msg);
+    if let Err(e) = status {
+      log::trace!("Failed with {:?}", e);
+      self.remote.lock().unwrap().take();
+      remote.lock().unwrap().notify_closed(e);
+    }
+    Ok(())
+  }
+
+  fn on_closed(&self) {
+    if let Some(remote) = self.remote.lock().unwrap().take() {
+      remote.lock().unwrap().notify_closed(Error::ReceiverClosed);
+    }
+  }
+}
+
+impl RemoteObject for JsonPipe {
+  fn channel(&self) -> &ChannelOwner {
+    &self.channel
+  }
+
+  fn channel_mut(&mut self) -> &mut ChannelOwner {
+    &mut self.channel
+  }
+
+  fn handle_event(
+    &self,
+    _ctx: &Context,
+    method: Str<Method>,
+    params: Map<String, Value>,
+  ) -> Result<(), Error> {
+    match method.as_str() {
+      "message" => self.on_message(params)?,
+      "closed" => self.on_closed(),
+      _ => {}
+    }
+    Ok(())
+  }
+}
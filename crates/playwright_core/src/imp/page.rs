@@ -1,8 +1,12 @@
+use crate::imp::browser_context;
 use crate::imp::browser_context::BrowserContext;
 use crate::imp::console_message::ConsoleMessage;
 use crate::imp::core::*;
 use crate::imp::download::Download;
 use crate::imp::element_handle::may_save;
+use crate::imp::element_handle::with_masks;
+use crate::imp::element_handle::Mask;
+use crate::imp::element_handle::MaskTarget;
 use crate::imp::file_hooser::FileChooser;
 use crate::imp::frame::Frame;
 use crate::imp::prelude::*;
@@ -12,9 +16,14 @@ use crate::imp::utils::ColorScheme;
 use crate::imp::utils::DocumentLoadState;
 use crate::imp::utils::FloatRect;
 use crate::imp::utils::Header;
+use crate::imp::utils::JsError;
 use crate::imp::utils::Length;
 use crate::imp::utils::MouseButton;
+use crate::imp::utils::PaperFormat;
 use crate::imp::utils::PdfMargins;
+use crate::imp::utils::ScreenshotAnimations;
+use crate::imp::utils::ScreenshotCaret;
+use crate::imp::utils::ScreenshotScale;
 use crate::imp::utils::ScreenshotType;
 use crate::imp::utils::Viewport;
 use crate::imp::video::Video;
@@ -29,6 +38,7 @@ pub(crate) struct Page {
   browser_context: Weak<BrowserContext>,
   var: Mutex<Variable>,
   tx: Mutex<Option<broadcast::Sender<Evt>>>,
+  listeners: Listeners<Evt>,
 }
 
 #[derive(Debug, Default)]
@@ -39,6 +49,8 @@ pub(crate) struct Variable {
   navigation_timeout: Option<u32>,
   workers: Vec<Weak<Worker>>,
   video: Option<Video>,
+  keep_video: bool,
+  is_closed: bool,
 }
 
 macro_rules! navigation {
@@ -113,6 +125,7 @@ impl Page {
       browser_context,
       var,
       tx: Mutex::default(),
+      listeners: Listeners::default(),
     })
   }
 
@@ -207,6 +220,18 @@ impl Page {
     Ok(())
   }
 
+  pub(crate) async fn mouse_wheel(&self, delta_x: f64, delta_y: f64) -> Result<(), Arc<Error>> {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args {
+      delta_x: f64,
+      delta_y: f64,
+    }
+    let args = Args { delta_x, delta_y };
+    let _ = send_message!(self, "mouseWheel", args);
+    Ok(())
+  }
+
   pub(crate) async fn mouse_click(&self, args: MouseClickArgs) -> Result<(), Arc<Error>> {
     let _ = send_message!(self, "mouseClick", args);
     Ok(())
@@ -246,10 +271,7 @@ impl Page {
     Ok(())
   }
 
-  pub(crate) async fn pdf(
-    &self,
-    args: PdfArgs<'_, '_, '_, '_, '_, '_, '_, '_, '_, '_>,
-  ) -> ArcResult<Vec<u8>> {
+  pub(crate) async fn pdf(&self, args: PdfArgs) -> ArcResult<Vec<u8>> {
     let path = args.path.clone();
     let v = send_message!(self, "pdf", args);
     let b64 = only_str(&v)?;
@@ -270,9 +292,16 @@ impl Page {
     Ok(())
   }
 
-  pub(crate) async fn screenshot(&self, args: ScreenshotArgs) -> ArcResult<Vec<u8>> {
+  pub(crate) async fn screenshot(&self, mut args: ScreenshotArgs) -> ArcResult<Vec<u8>> {
+    let masks = std::mem::take(&mut args.masks);
     let path = args.path.clone();
-    let v = send_message!(self, "screenshot", args);
+    let v = with_masks(&masks, |mask| {
+      async move {
+        args.mask = mask;
+        Ok::<_, Arc<Error>>(send_message!(self, "screenshot", args))
+      }
+    })
+    .await?;
     let b64 = only_str(&v)?;
     let bytes = base64::decode(b64).map_err(Error::InvalidBase64)?;
     may_save(path.as_deref(), &bytes)?;
@@ -367,6 +396,15 @@ impl Page {
     Ok(())
   }
 
+  /// While intercepted the native dialog is not shown and the `fileChooser`
+  /// event is emitted instead
+  pub(crate) async fn set_file_chooser_intercepted(&self, intercepted: bool) -> ArcResult<()> {
+    let mut args = Map::new();
+    args.insert("intercepted".into(), intercepted.into());
+    let _ = send_message!(self, "setFileChooserInterceptedNoReply", args);
+    Ok(())
+  }
+
   pub(crate) async fn set_default_navigation_timeout(&self, timeout: u32) -> ArcResult<()> {
     let mut args = Map::new();
     args.insert("timeout".into(), timeout.into());
@@ -406,17 +444,59 @@ impl Page {
     self.var.lock().unwrap().video.clone()
   }
 
+  pub(crate) fn keep_video(&self) {
+    self.var.lock().unwrap().keep_video = true;
+  }
+
+  pub(crate) fn is_closed(&self) -> bool {
+    self.var.lock().unwrap().is_closed
+  }
+
+  pub(crate) async fn wait_for_close(&self) {
+    let mut rx = self.subscribe_event();
+    if self.is_closed() {
+      return;
+    }
+    loop {
+      match rx.recv().await {
+        Ok(Evt::Close) | Err(broadcast::error::RecvError::Closed) => return,
+        Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => {}
+      }
+    }
+  }
+
   fn on_close(&self, ctx: &Context) -> Result<(), Error> {
+    self.var.lock().unwrap().is_closed = true;
     let bc = match self.browser_context().upgrade() {
       None => return Ok(()),
       Some(b) => b,
     };
     let this = get_object!(ctx, self.guid(), Page)?;
     bc.remove_page(&this);
+    if let Some(rt) = bc.video_discarder() {
+      self.discard_unflagged_video(rt);
+    }
     self.emit_event(Evt::Close);
     Ok(())
   }
 
+  fn discard_unflagged_video(&self, rt: tokio::runtime::Handle) {
+    let video = {
+      let var = self.var.lock().unwrap();
+      match (&var.video, var.keep_video) {
+        (Some(v), false) => v.clone(),
+        _ => return,
+      }
+    };
+    // Events are dispatched with the connection locked, so the request is
+    // sent from a task of the runtime of the context.
+    rt.spawn(async move {
+      if let Err(e) = video.delete().await {
+        log::warn!("failed to delete video: {}", e);
+      }
+    });
+  }
+
   fn on_frame_attached(&self, ctx: &Context, guid: Str<Guid>) -> Result<(), Error> {
     let this = get_object!(ctx, self.guid(), Page)?;
     let f = get_object!(ctx, &guid, Frame)?;
@@ -524,12 +604,70 @@ impl Page {
     let v = params.into();
     let guid = only_guid(&v)?;
     let artifact = get_object!(ctx, guid, Artifact)?;
-    let video = Video::new(artifact);
+    let this = get_object!(ctx, self.guid(), Page)?;
+    let video = Video::new(artifact, this);
     self.set_video(video.clone())?;
     self.emit_event(Evt::Video(video));
     Ok(())
   }
 
+  fn on_page_error(&self, ctx: &Context, params: Map<String, Value>) -> Result<(), Error> {
+    #[derive(Deserialize)]
+    struct De {
+      error: SerializedError,
+    }
+    #[derive(Deserialize)]
+    struct SerializedError {
+      error: Option<ErrorDe>,
+      value: Option<Value>,
+    }
+    #[derive(Deserialize)]
+    struct ErrorDe {
+      name: String,
+      message: String,
+      stack: Option<String>,
+    }
+    let De {
+      error: SerializedError { error, value },
+    } = serde_json::from_value(params.into())?;
+    let error = match error {
+      Some(e) => {
+        JsError {
+          name: e.name,
+          message: e.message,
+          stack: e.stack,
+          value: None,
+        }
+      }
+      None => {
+        let value: JsValue = match value {
+          Some(v) => de::from_value(&v).map_err(Error::DeserializationPwJson)?,
+          None => JsValue::Undefined,
+        };
+        let message = match &value {
+          JsValue::Undefined => "undefined".to_owned(),
+          JsValue::Null => "null".to_owned(),
+          JsValue::Bool(b) => b.to_string(),
+          JsValue::Number(n) => n.to_string(),
+          JsValue::String(s) | JsValue::BigInt(s) => s.clone(),
+          v => format!("{:?}", v),
+        };
+        JsError {
+          name: String::new(),
+          message,
+          stack: None,
+          value: Some(value),
+        }
+      }
+    };
+    if let Some(context) = self.browser_context().upgrade() {
+      let this = get_object!(ctx, self.guid(), Page)?;
+      context.emit_event(browser_context::Evt::WebError(this, error.clone()));
+    }
+    self.emit_event(Evt::PageError(error));
+    Ok(())
+  }
+
   fn on_file_chooser(&self, ctx: &Context, params: Map<String, Value>) -> Result<(), Error> {
     #[derive(Deserialize)]
     #[serde(rename_all = "camelCase")]
@@ -544,7 +682,7 @@ impl Page {
     let element = get_object!(ctx, &guid, ElementHandle)?;
     let this = get_object!(ctx, self.guid(), Page)?;
     let file_chooser = FileChooser::new(this, element, is_multiple);
-    // self.emit_event(Evt::FileChooser(file_chooser));
+    self.emit_event(Evt::FileChooser(file_chooser));
     Ok(())
   }
 }
@@ -580,6 +718,7 @@ impl RemoteObject for Page {
       "load" => self.emit_event(Evt::Load),
       "domcontentloaded" => self.emit_event(Evt::DomContentLoaded),
       "crash" => self.emit_event(Evt::Crash),
+      "pageError" => self.on_page_error(ctx, params)?,
       "console" => {
         let first = first_object(&params).ok_or(Error::InvalidParams)?;
         let OnlyGuid { guid } = serde_json::from_value((*first).clone())?;
@@ -620,7 +759,7 @@ impl RemoteObject for Page {
       }
       "download" => self.on_download(ctx, params)?,
       "video" => self.on_video(ctx, params)?,
-      "filechooser" => self.on_file_chooser(ctx, params)?,
+      "fileChooser" => self.on_file_chooser(ctx, params)?,
       _ => {}
     }
     Ok(())
@@ -636,10 +775,9 @@ pub(crate) enum Evt {
   Dialog,
   Download(Arc<Download>),
   /// Not Implemented Yet
-  // FileChooser(FileChooser),
+  FileChooser(FileChooser),
   DomContentLoaded,
-  /// Not Implemented Yet
-  PageError,
+  PageError(JsError),
   Request(Weak<Request>),
   Response(Weak<Response>),
   RequestFailed(Weak<Request>),
@@ -664,6 +802,10 @@ impl EventEmitter for Page {
   fn set_tx(&self, tx: broadcast::Sender<Self::Event>) {
     *self.tx.lock().unwrap() = Some(tx);
   }
+
+  fn listeners(&self) -> &Listeners<Self::Event> {
+    &self.listeners
+  }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -673,7 +815,7 @@ pub enum EventType {
   Console,
   Dialog,
   Download,
-  // FileChooser,
+  FileChooser,
   DomContentLoaded,
   PageError,
   Request,
@@ -700,9 +842,9 @@ impl IsEvent for Evt {
       Self::Console(_) => EventType::Console,
       Self::Dialog => EventType::Dialog,
       Self::Download(_) => EventType::Download,
-      // Self::FileChooser(_) => EventType::FileChooser,
+      Self::FileChooser(_) => EventType::FileChooser,
       Self::DomContentLoaded => EventType::DomContentLoaded,
-      Self::PageError => EventType::PageError,
+      Self::PageError(_) => EventType::PageError,
       Self::Request(_) => EventType::Request,
       Self::Response(_) => EventType::Response,
       Self::RequestFailed(_) => EventType::RequestFailed,
@@ -802,7 +944,7 @@ pub enum Mixed {
 }
 
 #[skip_serializing_none]
-#[derive(Serialize, Default)]
+#[derive(Serialize, Default, Clone)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct ReloadArgs {
   pub(crate) timeout: Option<f64>,
@@ -810,27 +952,27 @@ pub(crate) struct ReloadArgs {
 }
 
 #[skip_serializing_none]
-#[derive(Serialize, Default)]
+#[derive(Serialize, Default, Clone)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct PdfArgs<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j> {
+pub(crate) struct PdfArgs {
   pub(crate) scale: Option<f64>,
   pub(crate) display_header_footer: Option<bool>,
-  pub(crate) header_template: Option<&'a str>,
-  pub(crate) footer_template: Option<&'b str>,
+  pub(crate) header_template: Option<String>,
+  pub(crate) footer_template: Option<String>,
   pub(crate) print_background: Option<bool>,
   pub(crate) landscape: Option<bool>,
-  pub(crate) page_ranges: Option<&'c str>,
-  pub(crate) format: Option<&'d str>,
-  pub(crate) width: Option<Length<'e>>,
-  pub(crate) height: Option<Length<'f>>,
+  pub(crate) page_ranges: Option<String>,
+  pub(crate) format: Option<PaperFormat>,
+  pub(crate) width: Option<Length>,
+  pub(crate) height: Option<Length>,
   #[serde(rename = "preferCSSPageSize")]
   pub(crate) prefer_css_page_size: Option<bool>,
-  pub(crate) margin: Option<PdfMargins<'g, 'h, 'i, 'j>>,
+  pub(crate) margin: Option<PdfMargins>,
   pub(crate) path: Option<PathBuf>,
 }
 
 #[skip_serializing_none]
-#[derive(Serialize, Default)]
+#[derive(Serialize, Default, Clone)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct ScreenshotArgs {
   pub(crate) timeout: Option<f64>,
@@ -840,17 +982,23 @@ pub(crate) struct ScreenshotArgs {
   pub(crate) full_page: Option<bool>,
   pub(crate) clip: Option<FloatRect>,
   pub(crate) path: Option<PathBuf>,
+  pub(crate) animations: Option<ScreenshotAnimations>,
+  pub(crate) caret: Option<ScreenshotCaret>,
+  pub(crate) scale: Option<ScreenshotScale>,
+  #[serde(skip)]
+  pub(crate) masks: Vec<Mask>,
+  pub(crate) mask: Option<Vec<MaskTarget>>,
 }
 
 #[skip_serializing_none]
-#[derive(Serialize, Default)]
+#[derive(Serialize, Default, Clone)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct EmulateMediaArgs {
   pub(crate) media: Option<Media>,
   pub(crate) color_scheme: Option<ColorScheme>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Clone, Copy)]
 #[serde(rename_all = "lowercase")]
 pub enum Media {
   /// Reset emulating
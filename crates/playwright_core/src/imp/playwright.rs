@@ -5,11 +5,14 @@ use serde::Deserialize;
 
 use crate::api::browser::ContextBuilder;
 use crate::api::browser_type::PersistentContextLauncher;
+use crate::imp::browser::Browser;
 use crate::imp::browser_type::BrowserType;
 use crate::imp::core::*;
+use crate::imp::electron::Electron;
 use crate::imp::impl_future::*;
 use crate::imp::prelude::*;
 use crate::imp::selectors::Selectors;
+use crate::imp::socks_support::SocksSupport;
 use crate::imp::utils::Viewport;
 use crate::protocol::generated::playwright as protocol;
 
@@ -19,8 +22,11 @@ pub(crate) struct Playwright {
   chromium: Weak<BrowserType>,
   firefox: Weak<BrowserType>,
   webkit: Weak<BrowserType>,
+  electron: Weak<Electron>,
   selectors: Weak<Selectors>,
   devices: Vec<DeviceDescriptor>,
+  pre_launched_browser: Option<Weak<Browser>>,
+  socks_support: Option<Weak<SocksSupport>>,
 }
 
 impl Playwright {
@@ -41,7 +47,16 @@ impl Playwright {
     let chromium = get_object!(ctx, &chromium.guid, BrowserType)?;
     let firefox = get_object!(ctx, &firefox.guid, BrowserType)?;
     let webkit = get_object!(ctx, &webkit.guid, BrowserType)?;
+    let electron = get_object!(ctx, &electron.guid, Electron)?;
     let selectors = get_object!(ctx, &selectors.guid, Selectors)?;
+    let pre_launched_browser = match pre_launched_browser {
+      Some(b) => Some(get_object!(ctx, &b.guid, Browser)?),
+      None => None,
+    };
+    let socks_support = match socks_support {
+      Some(s) => Some(get_object!(ctx, &s.guid, SocksSupport)?),
+      None => None,
+    };
     let devices = device_descriptors
       .into_iter()
       .map(TryInto::try_into)
@@ -52,8 +67,11 @@ impl Playwright {
       chromium,
       firefox,
       webkit,
+      electron,
       selectors,
       devices,
+      pre_launched_browser,
+      socks_support,
     })
   }
 
@@ -77,12 +95,34 @@ impl Playwright {
     self.webkit.clone()
   }
 
+  pub(crate) fn electron(&self) -> Weak<Electron> {
+    self.electron.clone()
+  }
+
   pub(crate) fn selectors(&self) -> Weak<Selectors> {
     self.selectors.clone()
   }
 
+  pub(crate) async fn hide_highlight(&self) -> ArcResult<()> {
+    let _ = send_message!(self, "hideHighlight", Map::new());
+    Ok(())
+  }
+
+  /// Browser launched by the server when connected to one
+  pub(crate) fn pre_launched_browser(&self) -> Option<Weak<Browser>> {
+    self.pre_launched_browser.clone()
+  }
+
+  pub(crate) fn socks_support(&self) -> Option<Weak<SocksSupport>> {
+    self.socks_support.clone()
+  }
+
   pub(crate) async fn wait_initial_object(conn: &Connection) -> Result<Weak<Self>, Error> {
-    let ctx = upgrade(&conn.context())?;
+    Self::initialize(conn.context()).await
+  }
+
+  pub(crate) async fn initialize(c: Wm<Context>) -> Result<Weak<Self>, Error> {
+    let ctx = upgrade(&c)?;
     let ctx = ctx.lock().unwrap();
     let root = get_object!(ctx, &S::validate("").unwrap(), Root)?;
     let root = upgrade(&root)?;
@@ -98,7 +138,7 @@ impl Playwright {
     let crate::protocol::generated::root::commands::Initialize {
       playwright: crate::protocol::generated::Playwright { guid },
     } = serde_json::from_value(v)?;
-    let ctx = upgrade(&conn.context())?;
+    let ctx = upgrade(&c)?;
     let ctx = ctx.lock().unwrap();
     let p = get_object!(ctx, &guid, Playwright)?;
     Ok(p)
@@ -203,17 +243,14 @@ macro_rules! impl_set_device {
 }
 
 impl DeviceDescriptor {
-  pub(crate) fn set_persistent_context<'source, 'b, 'c, 'd, 'e, 'g, 'h, 'i, 'j, 'k, 'l>(
-    device: &'source Self,
-    builder: PersistentContextLauncher<'b, 'c, 'd, 'e, 'source, 'g, 'h, 'i, 'j, 'k, 'l>,
-  ) -> PersistentContextLauncher<'b, 'c, 'd, 'e, 'source, 'g, 'h, 'i, 'j, 'k, 'l> {
+  pub(crate) fn set_persistent_context(
+    device: &Self,
+    builder: PersistentContextLauncher,
+  ) -> PersistentContextLauncher {
     impl_set_device!(device, builder)
   }
 
-  pub(crate) fn set_context<'source, 'c, 'd, 'e, 'f, 'g, 'h>(
-    device: &'source Self,
-    builder: ContextBuilder<'source, 'c, 'd, 'e, 'f, 'g, 'h>,
-  ) -> ContextBuilder<'source, 'c, 'd, 'e, 'f, 'g, 'h> {
+  pub(crate) fn set_context(device: &Self, builder: ContextBuilder) -> ContextBuilder {
     impl_set_device!(device, builder)
   }
 }
@@ -3,6 +3,7 @@ use crate::imp::frame::Frame;
 use crate::imp::prelude::*;
 use crate::imp::response::Response;
 use crate::imp::utils::Header;
+use crate::imp::utils::RawHeaders;
 use crate::imp::utils::ResponseTiming;
 
 #[derive(Debug)]
@@ -112,6 +113,13 @@ impl Request {
     self.redirected_from.clone()
   }
 
+  pub(crate) async fn raw_headers(&self) -> ArcResult<RawHeaders> {
+    let v = send_message!(self, "rawRequestHeaders", Map::new());
+    let first = first(&v).ok_or(Error::InvalidParams)?;
+    let headers: Vec<Header> = serde_json::from_value((*first).clone()).map_err(Error::Serde)?;
+    Ok(RawHeaders::new(headers))
+  }
+
   pub(crate) async fn response(&self) -> ArcResult<Option<Weak<Response>>> {
     let v = send_message!(self, "response", Map::new());
     let guid = match as_only_guid(&v) {
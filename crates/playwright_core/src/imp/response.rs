@@ -2,7 +2,11 @@ use crate::imp::core::*;
 use crate::imp::prelude::*;
 use crate::imp::request::Request;
 use crate::imp::utils::Header;
+use crate::imp::utils::RawHeaders;
+use crate::imp::utils::RemoteAddr;
+use crate::imp::utils::RequestSizes;
 use crate::imp::utils::ResponseTiming;
+use crate::imp::utils::SecurityDetails;
 
 #[derive(Debug)]
 pub(crate) struct Response {
@@ -10,7 +14,9 @@ pub(crate) struct Response {
   url: String,
   status: i32,
   status_text: String,
+  headers: Vec<Header>,
   request: Weak<Request>,
+  raw_headers: Mutex<Option<RawHeaders>>,
 }
 
 impl Response {
@@ -19,17 +25,23 @@ impl Response {
       url,
       status,
       status_text,
+      mut headers,
       request,
       timing,
     } = serde_json::from_value(channel.initializer.clone())?;
     let request = get_object!(ctx, &request.guid, Request)?;
     upgrade(&request)?.set_response_timing(timing);
+    for h in headers.iter_mut() {
+      h.name.make_ascii_lowercase();
+    }
     Ok(Self {
       channel,
       url,
       status,
       status_text,
+      headers,
       request,
+      raw_headers: Mutex::default(),
     })
   }
 
@@ -70,16 +82,46 @@ impl Response {
     self.request.clone()
   }
 
-  pub(crate) async fn headers(&self) -> ArcResult<Vec<Header>> {
-    let v = send_message!(self, "body", Map::new());
-    let first = first(&v).ok_or(Error::InvalidParams)?;
-    let mut headers: Vec<Header> =
-      serde_json::from_value((*first).clone()).map_err(Error::Serde)?;
-    for h in headers.iter_mut() {
-      h.name.make_ascii_lowercase();
+  pub(crate) fn headers(&self) -> &[Header] {
+    &self.headers
+  }
+
+  pub(crate) async fn raw_headers(&self) -> ArcResult<RawHeaders> {
+    if let Some(h) = &*self.raw_headers.lock().unwrap() {
+      return Ok(h.clone());
     }
+    let v = send_message!(self, "rawResponseHeaders", Map::new());
+    let first = first(&v).ok_or(Error::InvalidParams)?;
+    let headers: Vec<Header> = serde_json::from_value((*first).clone()).map_err(Error::Serde)?;
+    let headers = RawHeaders::new(headers);
+    *self.raw_headers.lock().unwrap() = Some(headers.clone());
     Ok(headers)
   }
+
+  pub(crate) async fn security_details(&self) -> ArcResult<Option<SecurityDetails>> {
+    let v = send_message!(self, "securityDetails", Map::new());
+    let details = match first(&v) {
+      Some(x) => serde_json::from_value((*x).clone()).map_err(Error::Serde)?,
+      None => None,
+    };
+    Ok(details)
+  }
+
+  pub(crate) async fn server_addr(&self) -> ArcResult<Option<RemoteAddr>> {
+    let v = send_message!(self, "serverAddr", Map::new());
+    let addr = match first(&v) {
+      Some(x) => serde_json::from_value((*x).clone()).map_err(Error::Serde)?,
+      None => None,
+    };
+    Ok(addr)
+  }
+
+  pub(crate) async fn sizes(&self) -> ArcResult<RequestSizes> {
+    let v = send_message!(self, "sizes", Map::new());
+    let first = first(&v).ok_or(Error::InvalidParams)?;
+    let sizes = serde_json::from_value((*first).clone()).map_err(Error::Serde)?;
+    Ok(sizes)
+  }
 }
 
 impl RemoteObject for Response {
@@ -98,6 +140,7 @@ struct Initializer {
   url: String,
   status: i32,
   status_text: String,
+  headers: Vec<Header>,
   request: OnlyGuid,
   timing: ResponseTiming,
 }
@@ -0,0 +1,319 @@
+use std::io;
+
+use futures::future::Either;
+use tokio::sync::mpsc;
+
+use crate::imp::core::*;
+use crate::imp::prelude::*;
+
+/// Serves as the SOCKS proxy of the browsers of a remote server, connecting
+/// to the hosts exposed by
+/// [`ConnectBuilder::expose_network`](crate::api::browser_type::ConnectBuilder::expose_network)
+/// from this process
+#[derive(Debug)]
+pub(crate) struct SocksSupport {
+  channel: ChannelOwner,
+  commands: Mutex<Option<mpsc::UnboundedSender<Command>>>,
+}
+
+/// Events of the server, relayed to the task serving the sockets since they
+/// are dispatched with the connection locked and outside of any runtime
+#[derive(Debug)]
+enum Command {
+  Requested {
+    uid: String,
+    host: String,
+    port: u16,
+  },
+  Data {
+    uid: String,
+    data: Vec<u8>,
+  },
+  Closed {
+    uid: String,
+  },
+}
+
+impl SocksSupport {
+  const BUFSIZE: usize = 32767;
+
+  pub(crate) fn new(channel: ChannelOwner) -> Self {
+    Self {
+      channel,
+      commands: Mutex::default(),
+    }
+  }
+
+  /// Starts connecting the requested sockets whose host is matched by
+  /// `expose_network`
+  pub(crate) fn serve(this: Weak<Self>, expose_network: String) -> ArcResult<()> {
+    let (tx, rx) = mpsc::unbounded_channel();
+    *upgrade(&this)?.commands.lock().unwrap() = Some(tx);
+    spawn(Self::run(this, expose_network, rx));
+    Ok(())
+  }
+
+  async fn run(
+    this: Weak<Self>,
+    expose_network: String,
+    mut commands: mpsc::UnboundedReceiver<Command>,
+  ) {
+    // Writes are queued per socket so that a slow peer never blocks the others
+    let mut sockets: HashMap<String, mpsc::UnboundedSender<Vec<u8>>> = HashMap::new();
+    while let Some(command) = commands.recv().await {
+      match command {
+        Command::Requested { uid, host, port } => {
+          if !is_exposed(&expose_network, &host) {
+            spawn(log_failure(Self::refuse(this.clone(), uid)));
+            continue;
+          }
+          let (tx, rx) = mpsc::unbounded_channel();
+          sockets.insert(uid.clone(), tx);
+          spawn(log_failure(Self::relay(this.clone(), uid, host, port, rx)));
+        }
+        Command::Data { uid, data } => {
+          if let Some(tx) = sockets.get(&uid) {
+            let _ = tx.send(data);
+          }
+        }
+        // Dropping the queue ends the relay of the socket
+        Command::Closed { uid } => {
+          sockets.remove(&uid);
+        }
+      }
+    }
+  }
+
+  async fn relay(
+    this: Weak<Self>,
+    uid: String,
+    host: String,
+    port: u16,
+    mut writes: mpsc::UnboundedReceiver<Vec<u8>>,
+  ) -> ArcResult<()> {
+    let target = match host.as_str() {
+      "local.playwright" => "localhost",
+      x => x,
+    };
+    let (mut reader, mut writer) = match net::connect(target, port).await {
+      Ok(x) => x,
+      Err(e) => return upgrade(&this)?.socks_failed(&uid, error_code(&e)).await,
+    };
+    upgrade(&this)?.socks_connected(&uid, &host, port).await?;
+    let mut buf = vec![0; Self::BUFSIZE];
+    loop {
+      // Both are cancel safe, so the one that loses the race is dropped
+      let read = Box::pin(reader.read(&mut buf));
+      let next = match futures::future::select(read, Box::pin(writes.recv())).await {
+        Either::Left((read, _)) => Either::Left(read),
+        Either::Right((data, _)) => Either::Right(data),
+      };
+      match next {
+        Either::Left(read) => {
+          let s = upgrade(&this)?;
+          match read {
+            Ok(0) => return s.socks_end(&uid).await,
+            Ok(n) => s.socks_data(&uid, &buf[..n]).await?,
+            Err(e) => return s.socks_error(&uid, &e.to_string()).await,
+          }
+        }
+        Either::Right(None) => return Ok(()),
+        Either::Right(Some(data)) => {
+          if let Err(e) = writer.write_all(&data).await {
+            return upgrade(&this)?.socks_error(&uid, &e.to_string()).await;
+          }
+        }
+      }
+    }
+  }
+
+  async fn refuse(this: Weak<Self>, uid: String) -> ArcResult<()> {
+    upgrade(&this)?.socks_failed(&uid, "ERULESET").await
+  }
+
+  async fn socks_connected(&self, uid: &str, host: &str, port: u16) -> ArcResult<()> {
+    #[derive(Serialize)]
+    struct Args<'a> {
+      uid: &'a str,
+      host: &'a str,
+      port: u16,
+    }
+    let args = Args { uid, host, port };
+    let _ = send_message!(self, "socksConnected", args);
+    Ok(())
+  }
+
+  async fn socks_failed(&self, uid: &str, error_code: &str) -> ArcResult<()> {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+      uid: &'a str,
+      error_code: &'a str,
+    }
+    let args = Args { uid, error_code };
+    let _ = send_message!(self, "socksFailed", args);
+    Ok(())
+  }
+
+  async fn socks_data(&self, uid: &str, data: &[u8]) -> ArcResult<()> {
+    #[derive(Serialize)]
+    struct Args<'a> {
+      uid: &'a str,
+      data: String,
+    }
+    let args = Args {
+      uid,
+      data: base64::encode(data),
+    };
+    let _ = send_message!(self, "socksData", args);
+    Ok(())
+  }
+
+  async fn socks_error(&self, uid: &str, error: &str) -> ArcResult<()> {
+    #[derive(Serialize)]
+    struct Args<'a> {
+      uid: &'a str,
+      error: &'a str,
+    }
+    let args = Args { uid, error };
+    let _ = send_message!(self, "socksError", args);
+    Ok(())
+  }
+
+  async fn socks_end(&self, uid: &str) -> ArcResult<()> {
+    #[derive(Serialize)]
+    struct Args<'a> {
+      uid: &'a str,
+    }
+    let _ = send_message!(self, "socksEnd", Args { uid });
+    Ok(())
+  }
+
+  fn command(&self, command: Command) {
+    match &*self.commands.lock().unwrap() {
+      Some(tx) => {
+        let _ = tx.send(command);
+      }
+      None => log::trace!("socks proxy is not served: {:?}", command),
+    }
+  }
+
+  fn on_requested(&self, params: Map<String, Value>) -> Result<(), Error> {
+    #[derive(Deserialize)]
+    struct De {
+      uid: String,
+      host: String,
+      port: u16,
+    }
+    let De { uid, host, port } = serde_json::from_value(params.into())?;
+    self.command(Command::Requested { uid, host, port });
+    Ok(())
+  }
+
+  fn on_data(&self, params: Map<String, Value>) -> Result<(), Error> {
+    #[derive(Deserialize)]
+    struct De {
+      uid: String,
+      data: String,
+    }
+    let De { uid, data } = serde_json::from_value(params.into())?;
+    let data = base64::decode(data).map_err(Error::InvalidBase64)?;
+    self.command(Command::Data { uid, data });
+    Ok(())
+  }
+
+  fn on_closed(&self, params: Map<String, Value>) -> Result<(), Error> {
+    #[derive(Deserialize)]
+    struct De {
+      uid: String,
+    }
+    let De { uid } = serde_json::from_value(params.into())?;
+    self.command(Command::Closed { uid });
+    Ok(())
+  }
+}
+
+impl RemoteObject for SocksSupport {
+  fn channel(&self) -> &ChannelOwner {
+    &self.channel
+  }
+
+  fn channel_mut(&mut self) -> &mut ChannelOwner {
+    &mut self.channel
+  }
+
+  fn handle_event(
+    &self,
+    _ctx: &Context,
+    method: Str<Method>,
+    params: Map<String, Value>,
+  ) -> Result<(), Error> {
+    match method.as_str() {
+      "socksRequested" => self.on_requested(params)?,
+      "socksData" => self.on_data(params)?,
+      "socksClosed" => self.on_closed(params)?,
+      _ => {}
+    }
+    Ok(())
+  }
+}
+
+async fn log_failure(relay: impl Future<Output = ArcResult<()>>) {
+  if let Err(e) = relay.await {
+    log::trace!("socks relay failed: {:?}", e);
+  }
+}
+
+fn error_code(e: &io::Error) -> &'static str {
+  match e.kind() {
+    io::ErrorKind::ConnectionRefused => "ECONNREFUSED",
+    io::ErrorKind::ConnectionReset => "ECONNRESET",
+    io::ErrorKind::TimedOut => "ETIMEDOUT",
+    _ => "ENOTFOUND",
+  }
+}
+
+/// Whether `host` is matched by one of the comma separated rules, which are
+/// `*`, `<loopback>`, a `*.domain` wildcard or a host name
+fn is_exposed(rules: &str, host: &str) -> bool {
+  let host = host.to_ascii_lowercase();
+  rules
+    .split(',')
+    .map(|r| r.trim().to_ascii_lowercase())
+    .filter(|r| !r.is_empty())
+    .any(|rule| {
+      match rule.as_str() {
+        "*" => true,
+        "<loopback>" => {
+          matches!(
+            host.as_str(),
+            "localhost" | "local.playwright" | "127.0.0.1" | "::1" | "[::1]"
+          ) || host.ends_with(".localhost")
+        }
+        r => {
+          match r.strip_prefix("*.") {
+            Some(domain) => host.ends_with(&format!(".{}", domain)),
+            None => host == r,
+          }
+        }
+      }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn rules() {
+    assert!(is_exposed("*", "example.com"));
+    assert!(is_exposed("<loopback>", "localhost"));
+    assert!(is_exposed("<loopback>", "127.0.0.1"));
+    assert!(is_exposed("<loopback>", "app.localhost"));
+    assert!(!is_exposed("<loopback>", "example.com"));
+    assert!(is_exposed("example.com, *.test", "a.b.TEST"));
+    assert!(!is_exposed("*.test", "test"));
+    assert!(is_exposed("example.com, *.test", "Example.com"));
+    assert!(!is_exposed("", "localhost"));
+  }
+}
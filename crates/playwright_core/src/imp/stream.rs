@@ -1,7 +1,3 @@
-use std::fs::File;
-use std::io::BufWriter;
-use std::io::Write;
-
 use crate::imp::core::*;
 use crate::imp::prelude::*;
 
@@ -15,28 +11,35 @@ impl Stream {
     Self { channel }
   }
 
+  /// Reads the next chunk of at most `size` bytes. Empty at the end.
+  pub(crate) async fn read(&self, size: Option<usize>) -> ArcResult<Vec<u8>> {
+    let mut args = Map::new();
+    if let Some(size) = size {
+      args.insert("size".into(), size.into());
+    }
+    let v = send_message!(self, "read", args);
+    let b64 = only_str(&v)?;
+    let bytes = base64::decode(b64).map_err(Error::InvalidBase64)?;
+    Ok(bytes)
+  }
+
+  pub(crate) async fn close(&self) -> ArcResult<()> {
+    let _ = send_message!(self, "close", Map::new());
+    Ok(())
+  }
+
   pub(crate) async fn save_as<P: AsRef<Path>>(&self, path: P) -> ArcResult<()> {
-    let file = File::create(path).map_err(Error::from)?;
-    let mut writer = BufWriter::new(file);
+    let mut file = fs::File::create(path.as_ref()).await.map_err(Error::from)?;
     loop {
-      let v = send_message!(self, "read", Map::new());
-      let b64 = only_str(&v)?;
-      if b64.is_empty() {
+      let bytes = self.read(None).await?;
+      if bytes.is_empty() {
         break;
-      } else {
-        let bytes = base64::decode(b64).map_err(Error::InvalidBase64)?;
-        writer.write(&bytes).map_err(Error::from)?;
       }
+      file.write_all(&bytes).await.map_err(Error::from)?;
     }
+    file.flush().await.map_err(Error::from)?;
     Ok(())
   }
-
-  // with open(path, mode="wb") as file:
-  //    while True:
-  //        binary = await self._channel.send("read")
-  //        if not binary:
-  //            break
-  //        file.write(base64.b64decode(binary))
 }
 
 impl RemoteObject for Stream {
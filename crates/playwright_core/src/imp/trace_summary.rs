@@ -0,0 +1,149 @@
+use crate::imp::prelude::*;
+
+/// Figures of a Chromium performance trace recorded by
+/// [`Browser::start_tracing`](crate::api::Browser::start_tracing)
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TraceSummary {
+  /// Tasks of the renderer main threads that blocked them for longer than
+  /// [`TraceSummary::LONG_TASK_THRESHOLD`], ordered by start
+  pub long_tasks: Vec<LongTask>,
+  /// Milliseconds between consecutive frames drawn by the compositor
+  pub frame_times: Vec<f64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LongTask {
+  /// Milliseconds since the trace clock origin
+  pub start: f64,
+  /// Milliseconds
+  pub duration: f64,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum TraceFile {
+  Object {
+    #[serde(rename = "traceEvents")]
+    trace_events: Vec<TraceEvent>,
+  },
+  Array(Vec<TraceEvent>),
+}
+
+#[derive(Deserialize)]
+struct TraceEvent {
+  #[serde(default)]
+  name: String,
+  #[serde(default)]
+  ph: String,
+  /// Microseconds
+  #[serde(default)]
+  ts: f64,
+  /// Microseconds
+  dur: Option<f64>,
+  #[serde(default)]
+  pid: Value,
+  #[serde(default)]
+  tid: Value,
+  #[serde(default)]
+  args: Value,
+}
+
+impl TraceSummary {
+  /// Milliseconds, the same as the Long Tasks API
+  pub const LONG_TASK_THRESHOLD: f64 = 50.;
+
+  /// Parses the JSON trace returned by
+  /// [`Browser::stop_tracing`](crate::api::Browser::stop_tracing)
+  pub fn parse(trace: &[u8]) -> Result<Self, serde_json::Error> {
+    let events = match serde_json::from_slice(trace)? {
+      TraceFile::Object { trace_events } => trace_events,
+      TraceFile::Array(events) => events,
+    };
+    let main_threads: Vec<(&Value, &Value)> = events
+      .iter()
+      .filter(|e| e.ph == "M" && e.name == "thread_name")
+      .filter(|e| e.args.get("name").and_then(Value::as_str) == Some("CrRendererMain"))
+      .map(|e| (&e.pid, &e.tid))
+      .collect();
+    let mut long_tasks: Vec<LongTask> = events
+      .iter()
+      .filter(|e| e.ph == "X" && (e.name == "RunTask" || e.name == "ThreadControllerImpl::RunTask"))
+      .filter(|e| main_threads.is_empty() || main_threads.contains(&(&e.pid, &e.tid)))
+      .filter_map(|e| {
+        let duration = e.dur? / 1000.;
+        (duration > Self::LONG_TASK_THRESHOLD).then(|| {
+          LongTask {
+            start: e.ts / 1000.,
+            duration,
+          }
+        })
+      })
+      .collect();
+    long_tasks.sort_by(|a, b| a.start.total_cmp(&b.start));
+    let mut frames: Vec<f64> = events
+      .iter()
+      .filter(|e| e.name == "DrawFrame")
+      .map(|e| e.ts / 1000.)
+      .collect();
+    frames.sort_by(f64::total_cmp);
+    frames.dedup();
+    let frame_times = frames.windows(2).map(|w| w[1] - w[0]).collect();
+    Ok(Self {
+      long_tasks,
+      frame_times,
+    })
+  }
+
+  /// Sum of the time beyond [`TraceSummary::LONG_TASK_THRESHOLD`] of every
+  /// long task in milliseconds
+  pub fn total_blocking_time(&self) -> f64 {
+    self
+      .long_tasks
+      .iter()
+      .map(|t| t.duration - Self::LONG_TASK_THRESHOLD)
+      .sum()
+  }
+
+  pub fn average_frame_time(&self) -> Option<f64> {
+    if self.frame_times.is_empty() {
+      return None;
+    }
+    Some(self.frame_times.iter().sum::<f64>() / self.frame_times.len() as f64)
+  }
+
+  pub fn max_frame_time(&self) -> Option<f64> {
+    self.frame_times.iter().copied().reduce(f64::max)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parse() {
+    let trace = br#"{"traceEvents": [
+      {"name": "thread_name", "ph": "M", "pid": 1, "tid": 2, "args": {"name": "CrRendererMain"}},
+      {"name": "thread_name", "ph": "M", "pid": 1, "tid": 3, "args": {"name": "Compositor"}},
+      {"name": "RunTask", "ph": "X", "pid": 1, "tid": 2, "ts": 100000, "dur": 80000},
+      {"name": "RunTask", "ph": "X", "pid": 1, "tid": 2, "ts": 1000, "dur": 10000},
+      {"name": "RunTask", "ph": "X", "pid": 1, "tid": 3, "ts": 1000, "dur": 90000},
+      {"name": "DrawFrame", "ph": "I", "pid": 1, "tid": 3, "ts": 16000},
+      {"name": "DrawFrame", "ph": "I", "pid": 1, "tid": 3, "ts": 0},
+      {"name": "DrawFrame", "ph": "I", "pid": 1, "tid": 3, "ts": 48000}
+    ]}"#;
+    let s = TraceSummary::parse(trace).unwrap();
+    assert_eq!(
+      s.long_tasks,
+      vec![LongTask {
+        start: 100.,
+        duration: 80.
+      }]
+    );
+    assert_eq!(s.total_blocking_time(), 30.);
+    assert_eq!(s.frame_times, vec![16., 32.]);
+    assert_eq!(s.average_frame_time(), Some(24.));
+    assert_eq!(s.max_frame_time(), Some(32.));
+    assert_eq!(TraceSummary::parse(b"[]").unwrap(), TraceSummary::default());
+  }
+}
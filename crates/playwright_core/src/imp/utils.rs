@@ -1,3 +1,7 @@
+use crate::imp::core::fs;
+use crate::imp::core::ArcResult;
+use crate::imp::core::Error;
+use crate::imp::core::JsValue;
 use crate::imp::prelude::*;
 
 #[derive(Debug, Deserialize, Clone, Serialize, PartialEq, Eq)]
@@ -44,12 +48,29 @@ pub enum ColorScheme {
 }
 
 #[skip_serializing_none]
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 pub struct StorageState {
   pub cookies: Option<Vec<Cookie>>,
   pub origins: Option<Vec<OriginState>>,
 }
 
+impl StorageState {
+  /// Reads a file saved by `storageState({ path })` of any Playwright language
+  pub(crate) async fn read(path: &Path) -> ArcResult<Self> {
+    let bytes = fs::read(path).await.map_err(Error::from)?;
+    Ok(serde_json::from_slice(&bytes).map_err(Error::Serde)?)
+  }
+
+  pub(crate) async fn write(&self, path: &Path) -> ArcResult<()> {
+    if let Some(dir) = path.parent() {
+      fs::create_dir_all(dir).await.map_err(Error::from)?;
+    }
+    let bytes = serde_json::to_vec_pretty(self).map_err(Error::Serde)?;
+    fs::write(path, &bytes).await.map_err(Error::from)?;
+    Ok(())
+  }
+}
+
 #[skip_serializing_none]
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
@@ -125,6 +146,109 @@ pub enum DocumentLoadState {
   NetworkIdle,
 }
 
+impl From<DocumentLoadState> for crate::protocol::generated::LifecycleEvent {
+  fn from(s: DocumentLoadState) -> Self {
+    match s {
+      DocumentLoadState::DomContentLoaded => Self::Domcontentloaded,
+      DocumentLoadState::Load => Self::Load,
+      DocumentLoadState::NetworkIdle => Self::Networkidle,
+    }
+  }
+}
+
+/// Matches a URL by glob pattern, regular expression or predicate.
+///
+/// In globs `*` matches any characters except `/`, `**` matches any
+/// characters, `?` matches a literal `?` and `{a,b}` matches either
+/// alternative.
+#[derive(Clone)]
+pub enum UrlMatcher {
+  Glob(String),
+  Regex(regex::Regex),
+  Predicate(Arc<dyn Fn(&str) -> bool + Send + Sync>),
+}
+
+impl UrlMatcher {
+  pub fn predicate<F>(f: F) -> Self
+  where
+    F: Fn(&str) -> bool + Send + Sync + 'static,
+  {
+    Self::Predicate(Arc::new(f))
+  }
+
+  pub fn matches(&self, url: &str) -> bool {
+    match self {
+      Self::Glob(g) => {
+        regex::Regex::new(&glob_to_regex(g))
+          .map(|r| r.is_match(url))
+          .unwrap_or_default()
+      }
+      Self::Regex(r) => r.is_match(url),
+      Self::Predicate(f) => f(url),
+    }
+  }
+}
+
+impl std::fmt::Debug for UrlMatcher {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Glob(g) => f.debug_tuple("Glob").field(g).finish(),
+      Self::Regex(r) => f.debug_tuple("Regex").field(r).finish(),
+      Self::Predicate(_) => f.write_str("Predicate"),
+    }
+  }
+}
+
+impl From<&str> for UrlMatcher {
+  fn from(glob: &str) -> Self {
+    Self::Glob(glob.to_owned())
+  }
+}
+
+impl From<String> for UrlMatcher {
+  fn from(glob: String) -> Self {
+    Self::Glob(glob)
+  }
+}
+
+impl From<regex::Regex> for UrlMatcher {
+  fn from(r: regex::Regex) -> Self {
+    Self::Regex(r)
+  }
+}
+
+fn glob_to_regex(glob: &str) -> String {
+  let mut re = String::from("^");
+  let mut in_group = false;
+  let mut chars = glob.chars().peekable();
+  while let Some(c) = chars.next() {
+    match c {
+      '\\' => {
+        if let Some(next) = chars.next() {
+          re.push_str(&regex::escape(&next.to_string()));
+        }
+      }
+      '*' if chars.peek() == Some(&'*') => {
+        chars.next();
+        re.push_str(".*");
+      }
+      '*' => re.push_str("[^/]*"),
+      '{' => {
+        in_group = true;
+        re.push('(');
+      }
+      '}' if in_group => {
+        in_group = false;
+        re.push(')');
+      }
+      ',' if in_group => re.push('|'),
+      c => re.push_str(&regex::escape(&c.to_string())),
+    }
+  }
+  re.push('$');
+  re
+}
+
 #[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
 pub enum KeyboardModifier {
   Alt,
@@ -170,6 +294,32 @@ pub enum ScreenshotType {
   Png,
 }
 
+/// Whether to stop CSS animations and transitions while taking a screenshot
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum ScreenshotAnimations {
+  /// Finite animations are fast-forwarded to completion and infinite ones are
+  /// canceled to their initial state
+  Disabled,
+  Allow,
+}
+
+/// Whether to hide the text caret while taking a screenshot
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum ScreenshotCaret {
+  Hide,
+  Initial,
+}
+
+/// Whether a screenshot has a pixel per CSS pixel or per device pixel
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum ScreenshotScale {
+  Css,
+  Device,
+}
+
 #[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
 #[serde(rename_all = "lowercase")]
 pub enum ElementState {
@@ -208,35 +358,131 @@ impl From<(String, String)> for Header {
   }
 }
 
-#[derive(Debug, Serialize, PartialEq, Clone)]
-#[serde(untagged)]
-pub enum Length<'a> {
+/// Sent as a string since the protocol takes lengths labeled with units
+#[derive(Debug, PartialEq, Clone)]
+pub enum Length {
+  /// Pixels
   Value(f64),
-  WithUnit(&'a str),
+  WithUnit(String),
+}
+
+impl Serialize for Length {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer,
+  {
+    match self {
+      Self::Value(x) => serializer.serialize_str(&format!("{}px", x)),
+      Self::WithUnit(x) => serializer.serialize_str(x),
+    }
+  }
 }
 
-impl<'a> From<f64> for Length<'a> {
+impl Length {
+  /// Pixels
+  pub fn px(x: f64) -> Self {
+    Self::with_unit(x, "px")
+  }
+
+  /// Inches
+  pub fn inches(x: f64) -> Self {
+    Self::with_unit(x, "in")
+  }
+
+  /// Centimeters
+  pub fn cm(x: f64) -> Self {
+    Self::with_unit(x, "cm")
+  }
+
+  /// Millimeters
+  pub fn mm(x: f64) -> Self {
+    Self::with_unit(x, "mm")
+  }
+
+  fn with_unit(x: f64, unit: &str) -> Self {
+    Self::WithUnit(format!("{}{}", x, unit))
+  }
+}
+
+impl From<f64> for Length {
   fn from(x: f64) -> Self {
     Self::Value(x)
   }
 }
 
-impl<'a> From<&'a str> for Length<'a> {
-  fn from(x: &'a str) -> Self {
+impl From<&str> for Length {
+  fn from(x: &str) -> Self {
+    Self::WithUnit(x.to_owned())
+  }
+}
+
+impl From<String> for Length {
+  fn from(x: String) -> Self {
     Self::WithUnit(x)
   }
 }
 
+/// An uncaught exception thrown by a script in a page
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsError {
+  /// Empty when the thrown value is not an `Error`
+  pub name: String,
+  pub message: String,
+  /// JavaScript stack trace including the name and the message
+  pub stack: Option<String>,
+  /// The thrown value when it is not an `Error`, e.g. `throw 42`
+  pub value: Option<JsValue>,
+}
+
+impl std::fmt::Display for JsError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match (&self.stack, self.name.is_empty()) {
+      (Some(stack), _) => f.write_str(stack),
+      (None, true) => f.write_str(&self.message),
+      (None, false) => write!(f, "{}: {}", self.name, self.message),
+    }
+  }
+}
+
+impl std::error::Error for JsError {}
+
+/// Paper format of [`crate::api::page::PdfBuilder`]
+#[derive(Debug, Serialize, PartialEq, Eq, Clone, Copy)]
+pub enum PaperFormat {
+  /// 8.5in x 11in
+  Letter,
+  /// 8.5in x 14in
+  Legal,
+  /// 11in x 17in
+  Tabloid,
+  /// 17in x 11in
+  Ledger,
+  /// 33.1in x 46.8in
+  A0,
+  /// 23.4in x 33.1in
+  A1,
+  /// 16.54in x 23.4in
+  A2,
+  /// 11.7in x 16.54in
+  A3,
+  /// 8.27in x 11.7in
+  A4,
+  /// 5.83in x 8.27in
+  A5,
+  /// 4.13in x 5.83in
+  A6,
+}
+
 #[skip_serializing_none]
-#[derive(Debug, Serialize, PartialEq, Clone)]
-pub struct PdfMargins<'a, 'b, 'c, 'd> {
-  pub top: Option<Length<'a>>,
-  pub right: Option<Length<'b>>,
-  pub bottom: Option<Length<'c>>,
-  pub left: Option<Length<'d>>,
+#[derive(Debug, Serialize, PartialEq, Clone, Default)]
+pub struct PdfMargins {
+  pub top: Option<Length>,
+  pub right: Option<Length>,
+  pub bottom: Option<Length>,
+  pub left: Option<Length>,
 }
 
-#[derive(Debug, Serialize, PartialEq)]
+#[derive(Debug, Serialize, PartialEq, Clone)]
 pub struct File {
   pub name: String,
   pub mime: String,
@@ -310,3 +556,143 @@ pub struct ResponseTiming {
   /// relative to `startTime`, -1 if not available.
   pub response_start: f64,
 }
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SecurityDetails {
+  /// Common Name component of the Issuer field from the certificate.
+  pub issuer: Option<String>,
+  /// The specific TLS protocol used. (e.g. `TLS 1.3`).
+  pub protocol: Option<String>,
+  /// Common Name component of the Subject field from the certificate.
+  pub subject_name: Option<String>,
+  /// Unix timestamp (in seconds) specifying when this cert becomes valid.
+  pub valid_from: Option<f64>,
+  /// Unix timestamp (in seconds) specifying when this cert becomes invalid.
+  pub valid_to: Option<f64>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoteAddr {
+  /// IPv4 or IPV6 address of the server.
+  pub ip_address: String,
+  pub port: u16,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestSizes {
+  /// Size of the request body (POST data payload) in bytes. Set to 0 if there
+  /// was no body.
+  pub request_body_size: u64,
+  /// Total number of bytes from the start of the HTTP request message until
+  /// (and including) the double CRLF before the body.
+  pub request_headers_size: u64,
+  /// Size of the received response body (encoded) in bytes.
+  pub response_body_size: u64,
+  /// Total number of bytes from the start of the HTTP response message until
+  /// (and including) the double CRLF before the body.
+  pub response_headers_size: u64,
+}
+
+/// Headers as sent over the network, in order and with duplicates such as
+/// multiple `set-cookie`s kept. Lookups are case-insensitive.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RawHeaders(Vec<Header>);
+
+impl RawHeaders {
+  pub(crate) fn new(headers: Vec<Header>) -> Self {
+    Self(headers)
+  }
+
+  /// All headers with their original names.
+  pub fn headers_array(&self) -> &[Header] {
+    &self.0
+  }
+
+  /// All values of the header `name`.
+  pub fn get_all(&self, name: &str) -> Vec<&str> {
+    self
+      .0
+      .iter()
+      .filter(|h| h.name.eq_ignore_ascii_case(name))
+      .map(|h| h.value.as_str())
+      .collect()
+  }
+
+  /// The values of the header `name` joined with `, `, or with `\n` for
+  /// `set-cookie`.
+  pub fn get(&self, name: &str) -> Option<String> {
+    let values = self.get_all(name);
+    if values.is_empty() {
+      return None;
+    }
+    let sep = if name.eq_ignore_ascii_case("set-cookie") {
+      "\n"
+    } else {
+      ", "
+    };
+    Some(values.join(sep))
+  }
+
+  /// Headers with lower-case names, duplicates joined as in
+  /// [`RawHeaders::get`].
+  pub fn to_map(&self) -> HashMap<String, String> {
+    let mut map = HashMap::new();
+    for h in &self.0 {
+      let name = h.name.to_ascii_lowercase();
+      if !map.contains_key(&name) {
+        let value = self.get(&name).unwrap_or_default();
+        map.insert(name, value);
+      }
+    }
+    map
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn url_matcher() {
+    let m = UrlMatcher::from("**/*.{png,jpg}");
+    assert!(m.matches("https://localhost/a/b.png"));
+    assert!(m.matches("https://localhost/c.jpg"));
+    assert!(!m.matches("https://localhost/c.gif"));
+    let m = UrlMatcher::from("https://localhost/empty.html?foo=*");
+    assert!(m.matches("https://localhost/empty.html?foo=bar"));
+    assert!(!m.matches("https://localhost/emptyxhtml?foo=bar"));
+    let m = UrlMatcher::from(regex::Regex::new("empty").unwrap());
+    assert!(m.matches("https://localhost/empty.html"));
+    let m = UrlMatcher::predicate(|u| u.ends_with(".html"));
+    assert!(m.matches("https://localhost/empty.html"));
+  }
+
+  #[test]
+  fn length() {
+    let v =
+      serde_json::to_value(&[Length::from(10.), Length::cm(2.5), Length::inches(1.)]).unwrap();
+    assert_eq!(v, serde_json::json!(["10px", "2.5cm", "1in"]));
+    let v = serde_json::to_value(&PaperFormat::A4).unwrap();
+    assert_eq!(v, serde_json::json!("A4"));
+  }
+
+  #[test]
+  fn raw_headers() {
+    let h = RawHeaders::new(vec![
+      ("Set-Cookie".to_owned(), "a=1".to_owned()).into(),
+      ("Accept".to_owned(), "text/html".to_owned()).into(),
+      ("set-cookie".to_owned(), "b=2".to_owned()).into(),
+      ("accept".to_owned(), "*/*".to_owned()).into(),
+    ]);
+    assert_eq!(h.get_all("SET-COOKIE"), vec!["a=1", "b=2"]);
+    assert_eq!(h.get("set-cookie").as_deref(), Some("a=1\nb=2"));
+    assert_eq!(h.get("accept").as_deref(), Some("text/html, */*"));
+    assert_eq!(h.get("foo"), None);
+    let map = h.to_map();
+    assert_eq!(map.len(), 2);
+    assert_eq!(map["set-cookie"], "a=1\nb=2");
+  }
+}
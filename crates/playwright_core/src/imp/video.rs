@@ -1,15 +1,17 @@
 use crate::imp::artifact::Artifact;
 use crate::imp::core::*;
+use crate::imp::page::Page;
 use crate::imp::prelude::*;
 
 #[derive(Debug, Clone)]
 pub(crate) struct Video {
   artifact: Weak<Artifact>,
+  page: Weak<Page>,
 }
 
 impl Video {
-  pub(crate) fn new(artifact: Weak<Artifact>) -> Self {
-    Self { artifact }
+  pub(crate) fn new(artifact: Weak<Artifact>, page: Weak<Page>) -> Self {
+    Self { artifact, page }
   }
 
   pub(crate) fn path(&self) -> Result<PathBuf, Error> {
@@ -17,10 +19,19 @@ impl Video {
   }
 
   pub(crate) async fn save_as<P: AsRef<Path>>(&self, path: P) -> ArcResult<()> {
+    self.wait_for_page_close().await;
     upgrade(&self.artifact)?.save_as(path).await
   }
 
   pub(crate) async fn delete(&self) -> ArcResult<()> {
+    self.wait_for_page_close().await;
     upgrade(&self.artifact)?.delete().await
   }
+
+  /// The recording is complete only after its page has closed
+  async fn wait_for_page_close(&self) {
+    if let Some(page) = self.page.upgrade() {
+      page.wait_for_close().await;
+    }
+  }
 }
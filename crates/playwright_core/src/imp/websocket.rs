@@ -7,6 +7,7 @@ pub(crate) struct WebSocket {
   url: String,
   var: Mutex<Variable>,
   tx: Mutex<Option<broadcast::Sender<Evt>>>,
+  listeners: Listeners<Evt>,
 }
 
 #[derive(Debug, Default)]
@@ -22,6 +23,7 @@ impl WebSocket {
       url,
       var: Mutex::default(),
       tx: Mutex::default(),
+      listeners: Listeners::default(),
     })
   }
 
@@ -120,6 +122,10 @@ impl EventEmitter for WebSocket {
   fn set_tx(&self, tx: broadcast::Sender<Self::Event>) {
     *self.tx.lock().unwrap() = Some(tx);
   }
+
+  fn listeners(&self) -> &Listeners<Self::Event> {
+    &self.listeners
+  }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
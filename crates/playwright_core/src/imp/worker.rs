@@ -10,6 +10,7 @@ pub(crate) struct Worker {
   url: String,
   var: Mutex<Variable>,
   tx: Mutex<Option<broadcast::Sender<Evt>>>,
+  listeners: Listeners<Evt>,
 }
 
 #[derive(Debug, Default)]
@@ -26,6 +27,7 @@ impl Worker {
       url,
       var: Mutex::default(),
       tx: Mutex::default(),
+      listeners: Listeners::default(),
     })
   }
 
@@ -90,10 +92,9 @@ impl Worker {
     self.var.lock().unwrap().page = Some(page);
   }
 
-  // pub(crate) fn set_browser_context(&self, browser_context:
-  // Weak<BrowserContext>) {    self.var.lock().unwrap().browser_context =
-  // Some(browser_context);
-  //}
+  pub(crate) fn set_browser_context(&self, browser_context: Weak<BrowserContext>) {
+    self.var.lock().unwrap().browser_context = Some(browser_context);
+  }
 
   fn on_close(&self, ctx: &Context) -> Result<(), Error> {
     let this = get_object!(ctx, self.guid(), Worker)?;
@@ -101,7 +102,9 @@ impl Worker {
     if let Some(page) = var.page.as_ref().and_then(Weak::upgrade) {
       page.remove_worker(&this);
     }
-    // var.context.remove_service_worker(&this)
+    if let Some(context) = var.browser_context.as_ref().and_then(Weak::upgrade) {
+      context.remove_service_worker(&this);
+    }
     self.emit_event(Evt::Close);
     Ok(())
   }
@@ -165,4 +168,8 @@ impl EventEmitter for Worker {
   fn set_tx(&self, tx: broadcast::Sender<Self::Event>) {
     *self.tx.lock().unwrap() = Some(tx);
   }
+
+  fn listeners(&self) -> &Listeners<Self::Event> {
+    &self.listeners
+  }
 }
@@ -0,0 +1,35 @@
+use crate::imp::core::*;
+use crate::imp::prelude::*;
+
+#[derive(Debug)]
+pub(crate) struct WritableStream {
+  channel: ChannelOwner,
+}
+
+impl WritableStream {
+  pub(crate) fn new(channel: ChannelOwner) -> Self {
+    Self { channel }
+  }
+
+  pub(crate) async fn write(&self, bytes: &[u8]) -> ArcResult<()> {
+    let mut args = Map::new();
+    args.insert("binary".into(), base64::encode(bytes).into());
+    let _ = send_message!(self, "write", args);
+    Ok(())
+  }
+
+  pub(crate) async fn close(&self) -> ArcResult<()> {
+    let _ = send_message!(self, "close", Map::new());
+    Ok(())
+  }
+}
+
+impl RemoteObject for WritableStream {
+  fn channel(&self) -> &ChannelOwner {
+    &self.channel
+  }
+
+  fn channel_mut(&mut self) -> &mut ChannelOwner {
+    &mut self.channel
+  }
+}
@@ -5,12 +5,18 @@ extern crate serde;
 extern crate serde_with;
 
 pub mod api;
+#[cfg(feature = "rt-tokio")]
+pub mod blocking;
 mod imp;
 
 pub use api::playwright::Playwright;
+pub use playwright_macros::js;
 
 pub use crate::imp::core::Driver;
 pub use crate::imp::core::Error;
+pub use crate::imp::core::ErrorKind;
+pub use crate::imp::core::ErrorMessage;
+pub use crate::imp::core::StackFrame;
 
 #[doc(hidden)]
 #[macro_export]
@@ -43,6 +49,50 @@ macro_rules! runtime_test {
   };
 }
 
+#[doc(hidden)]
+pub mod __private {
+  use std::sync::Arc;
+
+  use serde::de::DeserializeOwned;
+  use serde::Serialize;
+
+  use crate::api::Frame;
+  use crate::api::Page;
+  pub use crate::Error;
+
+  /// What a `#[js]` function evaluates in
+  pub trait EvaluateTarget {
+    fn frame(&self) -> Frame;
+  }
+
+  impl EvaluateTarget for Page {
+    fn frame(&self) -> Frame {
+      self.main_frame()
+    }
+  }
+
+  impl EvaluateTarget for Frame {
+    fn frame(&self) -> Frame {
+      self.clone()
+    }
+  }
+
+  impl<T: EvaluateTarget + ?Sized> EvaluateTarget for &T {
+    fn frame(&self) -> Frame {
+      (**self).frame()
+    }
+  }
+
+  pub async fn evaluate<T, A, U>(target: &T, expression: &str, args: A) -> Result<U, Arc<Error>>
+  where
+    T: EvaluateTarget + ?Sized,
+    A: Serialize,
+    U: DeserializeOwned,
+  {
+    target.frame().evaluate(expression, args).await
+  }
+}
+
 pub(crate) mod protocol {
   pub(crate) mod generated;
 }
@@ -0,0 +1,49 @@
+use playwright_core::api::page::Event;
+use playwright_core::blocking::Events;
+use playwright_core::blocking::Playwright;
+
+fn assert_send<T: Send>() {}
+
+#[test]
+fn events_are_send() {
+  assert_send::<Events<Event>>();
+  assert_send::<Events<playwright_core::api::browser_context::Event>>();
+  assert_send::<Events<playwright_core::api::frame::Event>>();
+  assert_send::<Events<playwright_core::api::browser::Event>>();
+}
+
+#[test]
+fn launch() {
+  let p = Playwright::initialize().unwrap();
+  p.prepare().unwrap();
+  let browser = p.chromium().launch_with(|l| l.headless(true)).unwrap();
+  let browser_events = browser.events().unwrap();
+  let context = browser.new_context().unwrap();
+  let page = context.new_page().unwrap();
+  let events = page.events().unwrap();
+  // The iterator is waited on from another thread
+  let waiter = std::thread::spawn(move || {
+    events.filter_map(Result::ok).find_map(|e| {
+      match e {
+        Event::Console(m) => m.text().ok(),
+        _ => None,
+      }
+    })
+  });
+  page.set_content("<div id='a'>hello</div>").unwrap();
+  let text = page.text_content("#a").unwrap();
+  assert_eq!(text.as_deref(), Some("hello"));
+  let n: i32 = page.eval("() => 1 + 1").unwrap();
+  assert_eq!(n, 2);
+  page.eval::<()>("() => console.log('ready')").unwrap();
+  assert_eq!(waiter.join().unwrap().as_deref(), Some("ready"));
+  // Clones share the remote objects
+  let closed = browser.clone();
+  closed.close().unwrap();
+  assert!(!browser.exists());
+  assert!(
+    browser_events
+      .filter_map(Result::ok)
+      .any(|e| matches!(e, playwright_core::api::browser::Event::Disconnected))
+  );
+}
@@ -1,21 +1,31 @@
 use playwright_core::api::Browser;
 use playwright_core::api::BrowserType;
+use playwright_core::api::TraceSummary;
 
 use super::Which;
 
 pub async fn all(t: &BrowserType, which: Which) -> Browser {
   launch_close_browser(t).await;
-  let b = launch(t).await;
+  let b = launch(t, which).await;
   assert!(b.exists());
   version_should_work(&b, which);
   contexts_should_work(&b).await;
+  if which == Which::Chromium {
+    tracing_should_work(&b).await;
+  }
   b
 }
 
-async fn launch(t: &BrowserType) -> Browser {
+async fn launch(t: &BrowserType, which: Which) -> Browser {
+  let args = match which {
+    // gc() lets the page tests check that dropped handles are released
+    Which::Chromium => vec!["--js-flags=--expose-gc".to_owned()],
+    _ => Vec::new(),
+  };
   t.launcher()
     .headless(false)
     .clear_headless()
+    .args(args)
     .launch()
     .await
     .unwrap()
@@ -61,3 +71,36 @@ async fn contexts_should_work(b: &Browser) {
   context.close().await.unwrap();
   assert_eq!(b.contexts().unwrap().len(), len);
 }
+
+async fn tracing_should_work(b: &Browser) {
+  let context = b.context_builder().build().await.unwrap();
+  let page = context.new_page().await.unwrap();
+  b.start_tracing(Some(&page), Some(true), None)
+    .await
+    .unwrap();
+  page
+    .set_content_builder("<div id=a></div>")
+    .set_content()
+    .await
+    .unwrap();
+  // Blocks the main thread for 200ms in a task of its own
+  page
+    .eval::<()>(
+      "() => new Promise(r => setTimeout(() => {
+        const start = performance.now();
+        while (performance.now() - start < 200);
+        r();
+      }))",
+    )
+    .await
+    .unwrap();
+  let trace = b.stop_tracing().await.unwrap();
+  let summary = TraceSummary::parse(&trace).unwrap();
+  assert!(
+    summary.long_tasks.iter().any(|t| t.duration >= 200.),
+    "{:?}",
+    summary.long_tasks
+  );
+  assert!(summary.total_blocking_time() >= 150.);
+  context.close().await.unwrap();
+}
@@ -1,4 +1,5 @@
 use playwright_core::api::browser::RecordVideo;
+use playwright_core::api::browser_context;
 use playwright_core::api::Browser;
 use playwright_core::api::BrowserContext;
 use playwright_core::api::BrowserType;
@@ -13,13 +14,18 @@ pub async fn all(
   browser: &Browser,
   persistent: &BrowserContext,
   port: u16,
-  _which: Which,
+  which: Which,
 ) -> BrowserContext {
   let c = launch(browser).await;
   assert_ne!(persistent, &c);
   assert!(c.browser().unwrap().is_some());
   storage_state(&c, port).await;
+  storage_state_path_should_work(browser, &c).await;
+  if which == Which::Chromium {
+    service_workers_should_work(&c, port).await;
+  }
   set_offline_should_work(browser, port).await;
+  keep_flagged_videos_only_should_work(browser).await;
   set_timeout(&c).await;
   cookies_should_work(&c).await;
   add_init_script_should_work(&c).await;
@@ -27,7 +33,8 @@ pub async fn all(
   c
 }
 
-pub async fn persistent(t: &BrowserType, _port: u16, which: Which) -> BrowserContext {
+pub async fn persistent(t: &BrowserType, port: u16, which: Which) -> BrowserContext {
+  persistent_storage_state_path_should_work(t, port).await;
   let c = launch_persistent_context(t).await;
   if Which::Firefox != which {
     // XXX: launch with permissions not work on firefox
@@ -40,11 +47,11 @@ async fn launch(b: &Browser) -> BrowserContext {
   let c = b
     .context_builder()
     .user_agent("asdf")
-    .permissions(&["geolocation".into()])
+    .permissions(vec!["geolocation".to_owned()])
     .accept_downloads(true)
     .has_touch(true)
     .record_video(RecordVideo {
-      dir: &super::temp_dir().join("video"),
+      dir: super::temp_dir().join("video"),
       size: None,
     })
     .storage_state(StorageState {
@@ -73,7 +80,7 @@ async fn launch(b: &Browser) -> BrowserContext {
 async fn launch_persistent_context(t: &BrowserType) -> BrowserContext {
   t.persistent_context_launcher("./target".as_ref())
     .user_agent("asdf")
-    .permissions(&["geolocation".into()])
+    .permissions(vec!["geolocation".to_owned()])
     .launch()
     .await
     .unwrap()
@@ -200,3 +207,129 @@ async fn storage_state(c: &BrowserContext, port: u16) {
     ]
   );
 }
+
+async fn storage_state_path_should_work(browser: &Browser, c: &BrowserContext) {
+  let path = super::temp_dir().join("storage").join("state.json");
+  let storage = c.storage_state_to_path(&path).await.unwrap();
+  let saved: serde_json::Value = serde_json::from_slice(&std::fs::read(&path).unwrap()).unwrap();
+  assert!(saved["cookies"].is_array());
+  assert_eq!(
+    saved["origins"][0]["localStorage"][0]["name"].as_str(),
+    Some("name1")
+  );
+  let restored = browser
+    .context_builder()
+    .storage_state_path(path)
+    .build()
+    .await
+    .unwrap();
+  assert_eq!(restored.storage_state().await.unwrap(), storage);
+  restored.close().await.unwrap();
+}
+
+async fn keep_flagged_videos_only_should_work(browser: &Browser) {
+  let c = browser
+    .context_builder()
+    .record_video(RecordVideo {
+      dir: super::temp_dir().join("flagged-video"),
+      size: None,
+    })
+    .keep_flagged_videos_only(true)
+    .build()
+    .await
+    .unwrap();
+  let flagged = c.new_page().await.unwrap();
+  flagged.keep_video().unwrap();
+  let unflagged = c.new_page().await.unwrap();
+  let kept = flagged.video().unwrap().unwrap().path().unwrap();
+  let discarded = unflagged.video().unwrap().unwrap().path().unwrap();
+  flagged.close(None).await.unwrap();
+  unflagged.close(None).await.unwrap();
+  c.close().await.unwrap();
+  for _ in 0..50 {
+    if !discarded.exists() {
+      break;
+    }
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+  }
+  assert!(kept.is_file());
+  assert!(!discarded.exists());
+}
+
+async fn persistent_storage_state_path_should_work(t: &BrowserType, port: u16) {
+  let path = super::temp_dir().join("storage").join("persistent.json");
+  std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+  let state = serde_json::json!({
+    "cookies": [{
+      "name": "name1",
+      "value": "value1",
+      "domain": "localhost",
+      "path": "/",
+      "expires": -1,
+      "httpOnly": false,
+      "secure": false,
+      "sameSite": "Lax"
+    }],
+    "origins": [{
+      "origin": super::origin(port),
+      "localStorage": [{ "name": "name1", "value": "value1" }]
+    }]
+  });
+  std::fs::write(&path, serde_json::to_vec(&state).unwrap()).unwrap();
+  let c = t
+    .persistent_context_launcher(&super::temp_dir().join("persistent-storage"))
+    .storage_state_path(path)
+    .launch()
+    .await
+    .unwrap();
+  let cookies = c.cookies(&[]).await.unwrap();
+  assert!(
+    cookies
+      .iter()
+      .any(|c| c.name == "name1" && c.value == "value1")
+  );
+  let page = c.new_page().await.unwrap();
+  page
+    .goto_builder(&super::url_static(port, "/empty.html"))
+    .goto()
+    .await
+    .unwrap();
+  let get = "() => localStorage.getItem('name1')";
+  assert_eq!(
+    page.eval::<Option<String>>(get).await.unwrap().as_deref(),
+    Some("value1")
+  );
+  // Restoring leaves no trace in the storage of the page
+  let session: i32 = page.eval("() => sessionStorage.length").await.unwrap();
+  assert_eq!(session, 0);
+  // Removed entries stay removed within the document and come back with the
+  // next one
+  page
+    .eval::<()>("() => localStorage.removeItem('name1')")
+    .await
+    .unwrap();
+  assert_eq!(page.eval::<Option<String>>(get).await.unwrap(), None);
+  page.reload_builder().reload().await.unwrap();
+  assert_eq!(
+    page.eval::<Option<String>>(get).await.unwrap().as_deref(),
+    Some("value1")
+  );
+  c.close().await.unwrap();
+}
+
+async fn service_workers_should_work(c: &BrowserContext, port: u16) {
+  let page = c.new_page().await.unwrap();
+  let url = super::url_static(port, "/sw.html");
+  let (evt, _) = tokio::join!(
+    c.expect_event(browser_context::EventType::ServiceWorker),
+    page.goto_builder(&url).goto()
+  );
+  let worker = match evt.unwrap() {
+    browser_context::Event::ServiceWorker(w) => w,
+    _ => unreachable!(),
+  };
+  assert!(worker.url().unwrap().ends_with("/sw.js"));
+  assert_eq!(c.service_workers().unwrap(), vec![worker]);
+  assert!(c.background_pages().unwrap().is_empty());
+  page.close(None).await.unwrap();
+}
@@ -42,6 +42,8 @@ async fn should_handle_timeout(t: &BrowserType) {
       unreachable!();
     }
   }
+  assert!(err.is_timeout());
+  assert_eq!(err.api_name(), Some("browser_type.launch"));
 }
 
 // 'should fire close event for all contexts'
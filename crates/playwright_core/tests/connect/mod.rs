@@ -1,13 +1,109 @@
+use std::process::Command;
+use std::time::Duration;
+
 use playwright_core::api::page;
 use playwright_core::api::Browser;
 use playwright_core::api::BrowserType;
+use playwright_core::api::Page;
 use serde::Deserialize;
 
 use super::free_local_port;
 use super::install_browser;
 use super::playwright_with_driver;
+use super::start_test_server;
+use super::temp_dir;
+use super::url_static;
 use super::Which;
 
+pub(super) async fn connect(which: Which) {
+  let port = free_local_port().unwrap();
+  start_test_server(port).await;
+  let mut playwright = playwright_with_driver().await;
+  install_browser(&playwright, which);
+  let server_port = free_local_port().unwrap();
+  let mut server = Command::new(playwright.driver().executable())
+    .args(["run-server", "--port", &server_port.to_string()])
+    .spawn()
+    .unwrap();
+  let browser_type = match which {
+    Which::Webkit => playwright.webkit(),
+    Which::Firefox => playwright.firefox(),
+    Which::Chromium => playwright.chromium(),
+  };
+  let ws_endpoint = format!("ws://127.0.0.1:{}/", server_port);
+  let mut browser = None;
+  for _ in 0..50 {
+    let connected = browser_type
+      .connect_builder(&ws_endpoint)
+      .expose_network("local.playwright")
+      .connect()
+      .await;
+    if let Ok(b) = connected {
+      browser = Some(b);
+      break;
+    }
+    tokio::time::sleep(Duration::from_millis(100)).await;
+  }
+  let browser = browser.unwrap();
+  let context = browser.context_builder().build().await.unwrap();
+  let page = context.new_page().await.unwrap();
+  // Only resolved by this process, through the tunnel of the server
+  let url = format!("http://local.playwright:{}/static/empty.html", port);
+  page.goto_builder(&url).goto().await.unwrap();
+  assert_eq!(page.url().unwrap(), url);
+  // Not exposed, so refused by the tunnel although the server could reach it
+  let refused = page
+    .goto_builder(&url_static(port, "/empty.html"))
+    .goto()
+    .await;
+  assert!(refused.is_err());
+  upload(&page).await;
+  browser.close().await.unwrap();
+  server.kill().unwrap();
+}
+
+// The browser is remote, so the files are streamed to it instead of passed by
+// path. Bigger than one chunk to cover the chunked writes.
+async fn upload(page: &Page) {
+  page
+    .set_content_builder("<input type=file multiple>")
+    .set_content()
+    .await
+    .unwrap();
+  let dir = temp_dir().join("remote-upload");
+  std::fs::create_dir_all(&dir).unwrap();
+  let large = vec![b'a'; 1024 * 1024 * 2 + 1];
+  std::fs::write(dir.join("large.txt"), &large).unwrap();
+  std::fs::write(dir.join("small.txt"), "bb").unwrap();
+  page
+    .set_input_file_paths_builder("input", dir.join("large.txt"))
+    .add_path(dir.join("small.txt"))
+    .set_input_file_paths()
+    .await
+    .unwrap();
+  let files: Vec<String> = page
+    .evaluate_on_selector(
+      "input",
+      "e => Array.from(e.files).map(f => `${f.name}:${f.size}`)",
+      None::<()>,
+    )
+    .await
+    .unwrap();
+  assert_eq!(
+    files,
+    vec![format!("large.txt:{}", large.len()), "small.txt:2".into()]
+  );
+  let tail: String = page
+    .evaluate_on_selector(
+      "input",
+      "async e => (await e.files[0].text()).slice(-2)",
+      None::<()>,
+    )
+    .await
+    .unwrap();
+  assert_eq!(tail, "aa");
+}
+
 pub(super) async fn connect_over_cdp(which: Which) {
   let playwright = playwright_with_driver().await;
   install_browser(&playwright, which);
@@ -24,7 +120,7 @@ async fn http(browser_type: &BrowserType) {
   let port = free_local_port().unwrap();
   let browser = browser_type
     .launcher()
-    .args(&[format!("--remote-debugging-port={}", port)])
+    .args(vec![format!("--remote-debugging-port={}", port)])
     .launch()
     .await
     .unwrap();
@@ -65,7 +161,7 @@ async fn ws(browser_type: &BrowserType) {
   let port = free_local_port().unwrap();
   let browser = browser_type
     .launcher()
-    .args(&[format!("--remote-debugging-port={}", port)])
+    .args(vec![format!("--remote-debugging-port={}", port)])
     .launch()
     .await
     .unwrap();
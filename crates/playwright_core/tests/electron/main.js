@@ -0,0 +1,8 @@
+const { app, BrowserWindow } = require('electron');
+
+app.whenReady().then(() => {
+  const window = new BrowserWindow({ show: false });
+  window.loadURL('data:text/html,<title>first</title>');
+});
+
+app.on('window-all-closed', () => app.quit());
@@ -0,0 +1,87 @@
+use playwright_core::api::electron_application::Event;
+use playwright_core::api::electron_application::EventType;
+use playwright_core::api::ElectronApplication;
+use playwright_core::api::Playwright;
+
+use super::temp_dir;
+
+/// Set to an Electron executable to run the tests against
+/// `tests/electron/main.js`
+const EXECUTABLE: &str = "PLAYWRIGHT_ELECTRON_EXECUTABLE";
+
+pub async fn all(playwright: &Playwright) {
+  launch_should_fail_without_electron(playwright).await;
+  let executable = match std::env::var_os(EXECUTABLE) {
+    Some(x) => x,
+    None => {
+      eprintln!("skipping electron tests, {} is not set", EXECUTABLE);
+      return;
+    }
+  };
+  let app = playwright
+    .electron()
+    .launcher()
+    .executable(executable)
+    .args(vec!["tests/electron/main.js".to_owned()])
+    .launch()
+    .await
+    .unwrap();
+  windows_should_work(&app).await;
+  evaluate_should_work(&app).await;
+  close_should_work(&app).await;
+}
+
+async fn launch_should_fail_without_electron(playwright: &Playwright) {
+  let result = playwright
+    .electron()
+    .launcher()
+    .executable(temp_dir().join("not-electron"))
+    .timeout(5000.)
+    .launch()
+    .await;
+  assert!(result.is_err());
+}
+
+async fn windows_should_work(app: &ElectronApplication) {
+  let first = app.first_window().await.unwrap();
+  assert_eq!(first.title().await.unwrap(), "first");
+  assert_eq!(app.windows().unwrap(), vec![first.clone()]);
+  let (event, _) = futures::join!(
+    app.expect_event(EventType::Window),
+    app.evaluate::<(), ()>(
+      "({ BrowserWindow }) => {
+        const window = new BrowserWindow({ show: false });
+        window.loadURL('data:text/html,<title>second</title>');
+      }",
+      None
+    )
+  );
+  let second = match event.unwrap() {
+    Event::Window(p) => p,
+    e => panic!("{:?}", e),
+  };
+  assert_eq!(second.title().await.unwrap(), "second");
+  assert_eq!(app.windows().unwrap().len(), 2);
+  let window = app.browser_window(&second).await.unwrap();
+  let shown: bool = window
+    .evaluate::<(), _>("w => w.isVisible()", None)
+    .await
+    .unwrap();
+  assert!(!shown);
+}
+
+async fn evaluate_should_work(app: &ElectronApplication) {
+  let ready: bool = app.eval("({ app }) => app.isReady()").await.unwrap();
+  assert!(ready);
+  let sum: i32 = app
+    .evaluate("(_, [a, b]) => a + b", Some((1, 2)))
+    .await
+    .unwrap();
+  assert_eq!(sum, 3);
+}
+
+async fn close_should_work(app: &ElectronApplication) {
+  let (event, closed) = futures::join!(app.expect_event(EventType::Close), app.close());
+  closed.unwrap();
+  assert_eq!(event.unwrap(), Event::Close);
+}
@@ -0,0 +1,5 @@
+#[test]
+fn js_errors() {
+  let t = trybuild::TestCases::new();
+  t.compile_fail("tests/ui/js_*.rs");
+}
@@ -1,9 +1,15 @@
+use futures::io::AsyncReadExt;
 use futures::stream::StreamExt;
 use playwright_core::api::page;
+use playwright_core::api::with_handles;
 use playwright_core::api::BrowserContext;
+use playwright_core::api::DocumentLoadState;
+use playwright_core::api::ElementHandle;
 use playwright_core::api::File;
 use playwright_core::api::Geolocation;
+use playwright_core::api::JsValue;
 use playwright_core::api::Page;
+use playwright_core::api::Playwright;
 use playwright_core::api::Viewport;
 
 use super::Which;
@@ -18,7 +24,7 @@ macro_rules! concurrent {
     }
 }
 
-pub async fn all(c: &BrowserContext, port: u16, which: Which) {
+pub async fn all(playwright: &Playwright, c: &BrowserContext, port: u16, which: Which) {
   let page = c.new_page().await.unwrap();
   eq_context_close(c, &page).await;
   ensure_timeout(&page).await;
@@ -43,14 +49,26 @@ pub async fn all(c: &BrowserContext, port: u16, which: Which) {
     workers_should_work(c, port, which),
     accessibility(c),
     query_selector_and_eval(c),
-    input(c)
+    input(c),
+    handlers_should_work(c),
+    expect_helpers_should_work(c),
+    expect_network_helpers_should_work(c, port),
+    navigation_waits_should_work(c, port),
+    js_values_should_work(c),
+    handle_disposal_should_work(c, which),
+    typed_js_should_work(c),
+    set_input_file_paths_should_work(c),
+    drag_and_wheel_should_work(c),
+    page_error_should_work(c),
+    highlight_should_work(playwright, c),
+    api_names_should_work(c)
   );
   // TODO
   // file_chooser(c, port).await;
   if which != Which::Firefox {
     pdf_should_work(&page).await;
   }
-  video(&page).await;
+  video(c).await;
   emulate_media(&page).await;
 }
 
@@ -149,7 +167,7 @@ async fn navigations(page: &Page, port: u16) {
   let url1 = super::url_static(port, "/empty.html");
   let url2 = super::url_static(port, "/empty2.html");
   page.goto_builder(&url1).goto().await.unwrap();
-  page.goto_builder(&url2).goto().await.unwrap();
+  page.goto_builder(&url2).timeout(5000.0).await.unwrap();
   {
     let response = page.go_back_builder().go_back().await.unwrap().unwrap();
     assert!(response.ok().unwrap());
@@ -305,19 +323,25 @@ async fn download(c: &BrowserContext, port: u16) {
   assert!(download.path().await.unwrap().is_some());
   assert_eq!(download.failure().await.unwrap(), None);
   let tmp = super::temp_dir().join(download.suggested_filename());
-  download.save_as(tmp).await.unwrap();
+  download.save_as(&tmp).await.unwrap();
+  let mut stream = download.create_read_stream().await.unwrap().unwrap();
+  let mut content = Vec::new();
+  stream.read_to_end(&mut content).await.unwrap();
+  assert_eq!(content, std::fs::read(&tmp).unwrap());
+  download.cancel().await.unwrap();
   download.delete().await.unwrap();
   close(&p).await;
 }
 
-async fn video(p: &Page) {
+async fn video(c: &BrowserContext) {
+  let p = new(c).await;
   let video = p.video().unwrap().unwrap();
   dbg!(video.path().unwrap());
-  // TODO
-  // let path = super::temp_dir().join("video.webm");
-  // video.save_as(&path).await.unwrap();
-  // assert!(path.is_file());
-  // video.delete().await.unwrap();
+  close(&p).await;
+  let path = super::temp_dir().join("video.webm");
+  video.save_as(&path).await.unwrap();
+  assert!(path.is_file());
+  video.delete().await.unwrap();
 }
 
 async fn accessibility(c: &BrowserContext) {
@@ -386,6 +410,9 @@ async fn accessibility(c: &BrowserContext) {
 }
 
 async fn screenshot_should_work(p: &Page) {
+  use playwright_core::api::ScreenshotAnimations;
+  use playwright_core::api::ScreenshotCaret;
+  use playwright_core::api::ScreenshotScale;
   use playwright_core::api::ScreenshotType;
   let path = super::temp_dir().join("screenshot.jpg");
   p.screenshot_builder()
@@ -396,12 +423,73 @@ async fn screenshot_should_work(p: &Page) {
     .await
     .unwrap();
   assert!(path.is_file());
+  let body = p.query_selector("body").await.unwrap().unwrap();
+  let bytes = p
+    .screenshot_builder()
+    .animations(ScreenshotAnimations::Disabled)
+    .caret(ScreenshotCaret::Initial)
+    .scale(ScreenshotScale::Css)
+    .mask_selector(&p.main_frame(), "div")
+    .mask_element(&body)
+    .screenshot()
+    .await
+    .unwrap();
+  assert!(!bytes.is_empty());
+  let bytes = body
+    .screenshot_builder()
+    .scale(ScreenshotScale::Device)
+    .mask_element(&body)
+    .screenshot()
+    .await
+    .unwrap();
+  let pixel: Vec<u8> = p
+    .evaluate(
+      "async b64 => {
+        const img = new Image();
+        img.src = 'data:image/png;base64,' + b64;
+        await img.decode();
+        const canvas = document.createElement('canvas');
+        canvas.width = img.width;
+        canvas.height = img.height;
+        const ctx = canvas.getContext('2d');
+        ctx.drawImage(img, 0, 0);
+        return Array.from(ctx.getImageData(0, 0, 1, 1).data.slice(0, 3));
+      }",
+      Some(base64::encode(&bytes)),
+    )
+    .await
+    .unwrap();
+  assert_eq!(pixel, vec![0xff, 0x00, 0xff]);
+  let unmarked: bool = p
+    .eval("() => document.querySelector('[data-playwright-mask]') === null")
+    .await
+    .unwrap();
+  assert!(unmarked);
 }
 
 async fn pdf_should_work(p: &Page) {
+  use playwright_core::api::Length;
+  use playwright_core::api::PaperFormat;
+  use playwright_core::api::PdfMargins;
   let path = super::temp_dir().join("pdf.pdf");
-  p.pdf_builder().path(path.clone()).pdf().await.unwrap();
-  assert!(path.is_file());
+  let bytes = p.pdf_builder().path(path.clone()).pdf().await.unwrap();
+  assert!(bytes.starts_with(b"%PDF"));
+  assert_eq!(std::fs::read(&path).unwrap(), bytes);
+  let bytes = p
+    .pdf_builder()
+    .format(PaperFormat::A4)
+    .margin(PdfMargins {
+      top: Some(Length::cm(1.)),
+      left: Some(Length::px(10.)),
+      ..PdfMargins::default()
+    })
+    .display_header_footer(true)
+    .header_template("<span class=\"title\"></span>".to_owned())
+    .footer_template("<span class=\"pageNumber\"></span>".to_owned())
+    .pdf()
+    .await
+    .unwrap();
+  assert!(bytes.starts_with(b"%PDF"));
 }
 
 async fn emulate_media(p: &Page) {
@@ -491,6 +579,122 @@ async fn new(c: &BrowserContext) -> Page {
   page
 }
 
+async fn handlers_should_work(c: &BrowserContext) {
+  use futures::channel::mpsc;
+  let p = new(c).await;
+  let (tx, mut every) = mpsc::unbounded();
+  let id = p
+    .on(page::EventType::Load, move |_| {
+      let tx = tx.clone();
+      async move {
+        tx.unbounded_send(()).unwrap();
+      }
+    })
+    .unwrap();
+  let (tx, mut first) = mpsc::unbounded();
+  p.once(page::EventType::Load, move |_| {
+    async move {
+      tx.unbounded_send(()).unwrap();
+    }
+  })
+  .unwrap();
+  done!(p.set_content_builder("<div>a</div>").set_content());
+  done!(p.set_content_builder("<div>b</div>").set_content());
+  assert_eq!(every.next().await, Some(()));
+  assert_eq!(every.next().await, Some(()));
+  assert_eq!(first.next().await, Some(()));
+  // the once handler is dropped after running
+  assert_eq!(first.next().await, None);
+  p.off(id).unwrap();
+  close(&p).await;
+}
+
+async fn expect_helpers_should_work(c: &BrowserContext) {
+  let p = new(c).await;
+  let message = p
+    .expect_console_message(p.eval::<Option<i32>>("() => console.log('hello')"))
+    .await
+    .unwrap();
+  assert_eq!(message.text().unwrap(), "hello");
+  let popup = p
+    .expect_popup(p.eval::<Option<i32>>("() => { window.open('about:blank') }"))
+    .await
+    .unwrap();
+  close(&popup).await;
+  let err = p
+    .wait_for_event(page::EventType::Load, |_| true, Some(10.))
+    .await
+    .err()
+    .unwrap();
+  assert!(err.is_timeout());
+  close(&p).await;
+}
+
+async fn expect_network_helpers_should_work(c: &BrowserContext, port: u16) {
+  let p = new(c).await;
+  let url = super::url_static(port, "/empty2.html");
+  let request = p
+    .expect_request("**/empty2.html", p.goto_builder(&url).goto())
+    .await
+    .unwrap();
+  assert_eq!(request.url().unwrap(), url);
+  assert_eq!(request.method().unwrap(), "GET");
+  let response = p
+    .expect_response(
+      "**/empty.html",
+      p.goto_builder(&super::url_static(port, "/empty.html"))
+        .goto(),
+    )
+    .await
+    .unwrap();
+  assert!(response.url().unwrap().ends_with("/empty.html"));
+  assert_eq!(response.status().unwrap(), 200);
+  p.set_content_builder(&format!(
+    r#"<a href="{}">download</a><input type="file">"#,
+    super::url_download(port, "/worker.html")
+  ))
+  .set_content()
+  .await
+  .unwrap();
+  let download = p
+    .expect_download(p.click_builder("a").click())
+    .await
+    .unwrap();
+  assert_eq!(download.suggested_filename(), "worker.html");
+  let chooser = p
+    .expect_file_chooser(p.click_builder("input").click())
+    .await
+    .unwrap();
+  assert!(!chooser.is_multiple());
+  assert_eq!(chooser.page(), p);
+  close(&p).await;
+}
+
+async fn navigation_waits_should_work(c: &BrowserContext, port: u16) {
+  let p = new(c).await;
+  let url = super::url_static(port, "/empty.html");
+  let response = p
+    .expect_navigation(p.goto_builder(&url).goto())
+    .await
+    .unwrap()
+    .unwrap();
+  assert_eq!(response.url().unwrap(), url);
+  done!(p.wait_for_load_state(Some(DocumentLoadState::DomContentLoaded), None));
+  let (waited, _) = tokio::join!(
+    p.wait_for_url("**/spa/*", None, None),
+    p.eval::<Option<i32>>("() => { setTimeout(() => history.pushState({}, '', '/spa/1'), 10) }")
+  );
+  waited.unwrap();
+  assert!(p.url().unwrap().ends_with("/spa/1"));
+  let err = p
+    .wait_for_url("**/never", None, Some(10.))
+    .await
+    .err()
+    .unwrap();
+  assert!(err.is_timeout());
+  close(&p).await;
+}
+
 async fn close(p: &Page) {
   p.close(None).await.unwrap()
 }
@@ -520,7 +724,7 @@ async fn set_extra_http_headers(c: &BrowserContext, port: u16) {
     .await
     .unwrap();
   let url = super::url_static(port, "/empty.html");
-  let (maybe_request, _) = tokio::join!(
+  let (maybe_request, response) = tokio::join!(
     p.expect_event(page::EventType::Request),
     p.goto_builder(&url).goto()
   );
@@ -531,6 +735,21 @@ async fn set_extra_http_headers(c: &BrowserContext, port: u16) {
   let headers = req.headers().unwrap();
   assert_eq!(headers.get("foo").unwrap(), "bar"); // set by BrowserContext
   assert_eq!(headers.get("hoge").unwrap(), "hoge");
+  let all = done!(req.all_headers());
+  assert_eq!(all.get("hoge").unwrap(), "hoge");
+  assert_eq!(done!(req.header_value("HOGE")).as_deref(), Some("hoge"));
+  let response = response.unwrap().unwrap();
+  assert!(done!(response.all_headers()).contains_key("content-type"));
+  assert!(
+    done!(response.headers())
+      .iter()
+      .any(|h| h.name == "content-type")
+  );
+  assert_eq!(done!(response.header_values("content-type")).len(), 1);
+  let addr = done!(response.server_addr()).unwrap();
+  assert_eq!(addr.port, port);
+  assert_eq!(done!(response.security_details()), None);
+  assert!(done!(req.sizes()).response_body_size > 0);
   close(&p).await;
 }
 
@@ -587,6 +806,215 @@ async fn query_selector_and_eval(c: &BrowserContext) {
       .unwrap(),
     found.inner_html().await.unwrap()
   );
+  assert_eq!(
+    done!(found.evaluate::<i32, String>("(e, x) => e.innerHTML + x", Some(1))),
+    "not blank1"
+  );
+  assert_eq!(
+    done!(divs[0].eval_on_selector::<(), String>("h1", "e => e.innerHTML", None)),
+    "foo"
+  );
+  assert_eq!(
+    done!(divs[0].eval_on_selector_all::<(), i32>("div", "es => es.length", None)),
+    2
+  );
+  let body = done!(divs[0].evaluate_handle::<()>("e => e.parentElement", None));
+  assert!(body.as_element().is_some());
+  let obj = done!(p.evaluate_js_handle::<()>("() => ({a: 1})", None));
+  assert!(obj.as_element().is_none());
+  assert_eq!(
+    done!(obj.evaluate::<i32, i32>("(o, x) => o.a + x", Some(2))),
+    3
+  );
+  close(&p).await;
+}
+
+async fn js_values_should_work(c: &BrowserContext) {
+  let p = new(c).await;
+  let v: JsValue = done!(p.evaluate(
+    "([d, r, x]) => [d.getTime(), r.flags, x, -0, NaN, undefined]",
+    JsValue::Array(vec![
+      JsValue::Date("2021-01-01T00:00:00.000Z".into()),
+      JsValue::RegExp {
+        source: "a+".into(),
+        flags: "g".into()
+      },
+      JsValue::Number(f64::NEG_INFINITY)
+    ])
+  ));
+  let a = v.as_array().unwrap();
+  assert_eq!(a[0], JsValue::Number(1609459200000.));
+  assert_eq!(a[1], JsValue::from("g"));
+  assert_eq!(a[2], JsValue::Number(f64::NEG_INFINITY));
+  assert!(a[3].as_f64().unwrap().is_sign_negative());
+  assert!(a[4].as_f64().unwrap().is_nan());
+  assert!(a[5].is_undefined());
+  let v: JsValue = done!(p.evaluate("() => { const a = {}; a.self = a; return a }", ()));
+  assert_eq!(v.get("self"), Some(&JsValue::Ref(1)));
+  close(&p).await;
+}
+
+async fn handle_disposal_should_work(c: &BrowserContext, which: Which) {
+  let p = new(c).await;
+  let h = done!(p.evaluate_js_handle::<()>("() => ({a: 1})", None));
+  let h2 = h.clone();
+  drop(h);
+  let v: JsValue = done!(h2.json_value());
+  assert_eq!(v.get("a"), Some(&JsValue::Number(1.)));
+  let kept = with_handles(async {
+    let h = p
+      .evaluate_js_handle::<()>("() => ({})", None)
+      .await
+      .unwrap();
+    h.keep();
+    h
+  })
+  .await;
+  assert!(kept.json_value::<JsValue>().await.is_err());
+  // Arguments of console messages belong to the message
+  let message = done!(p.expect_console_message(p.eval::<Option<i32>>("() => console.log({a: 2})")));
+  with_handles(async { message.args().unwrap() }).await;
+  let arg: JsValue = done!(message.args().unwrap()[0].json_value());
+  assert_eq!(arg.get("a"), Some(&JsValue::Number(2.)));
+  if which == Which::Chromium {
+    dropped_handles_should_be_released(&p).await;
+  }
+  close(&p).await;
+}
+
+async fn dropped_handles_should_be_released(p: &Page) {
+  let object = done!(p.evaluate_js_handle::<()>(
+    "() => {
+      window.collected = [];
+      window.registry = new FinalizationRegistry(name => window.collected.push(name));
+      const div = document.createElement('div');
+      div.id = 'released';
+      document.body.appendChild(div);
+      window.registry.register(div, 'element');
+      const object = {};
+      window.registry.register(object, 'object');
+      return object;
+    }",
+    None
+  ));
+  let element = done!(p.query_selector("#released")).unwrap();
+  done!(p.eval::<()>("() => document.querySelector('#released').remove()"));
+  drop(object);
+  drop(element);
+  let mut collected = Vec::new();
+  for _ in 0..50 {
+    collected = done!(p.eval::<Vec<String>>(
+      "async () => {
+        gc();
+        await new Promise(r => setTimeout(r, 100));
+        return window.collected;
+      }"
+    ));
+    if collected.len() == 2 {
+      break;
+    }
+  }
+  collected.sort();
+  assert_eq!(collected, vec!["element", "object"]);
+}
+
+#[playwright_core::js("(a, b) => a + b")]
+async fn add(page: &Page, a: i32, b: i32) -> i32;
+
+#[playwright_core::js("(e, suffix) => e.innerHTML + suffix")]
+async fn inner_html_with(page: &Page, e: &ElementHandle, suffix: &str) -> String;
+
+#[playwright_core::js("() => document.title")]
+async fn title(page: &Page) -> String;
+
+async fn typed_js_should_work(c: &BrowserContext) {
+  let p = new(c).await;
+  done!(
+    p.set_content_builder("<title>t</title><div>foo</div>")
+      .set_content()
+  );
+  assert_eq!(done!(add(&p, 1, 2)), 3);
+  let div = done!(p.query_selector("div")).unwrap();
+  assert_eq!(done!(inner_html_with(&p, &div, "bar")), "foobar");
+  assert_eq!(done!(title(&p)), "t");
+  close(&p).await;
+}
+
+async fn set_input_file_paths_should_work(c: &BrowserContext) {
+  let p = new(c).await;
+  done!(
+    p.set_content_builder("<input type=file multiple>")
+      .set_content()
+  );
+  let dir = super::temp_dir().join("upload");
+  std::fs::create_dir_all(&dir).unwrap();
+  std::fs::write(dir.join("a.txt"), "a").unwrap();
+  std::fs::write(dir.join("b.txt"), "bb").unwrap();
+  done!(
+    p.set_input_file_paths_builder("input", dir.join("a.txt"))
+      .add_path(dir.join("b.txt"))
+      .set_input_file_paths()
+  );
+  let files: Vec<String> = done!(p.evaluate_on_selector(
+    "input",
+    "e => Array.from(e.files).map(f => `${f.name}:${f.size}`)",
+    None::<()>
+  ));
+  assert_eq!(files, vec!["a.txt:1", "b.txt:2"]);
+  close(&p).await;
+}
+
+async fn drag_and_wheel_should_work(c: &BrowserContext) {
+  let p = new(c).await;
+  done!(
+    p.set_content_builder(
+      r#"<div id=a style="height:50px">a</div><div id=b style="height:50px">b</div>
+<script>
+window.log = [];
+a.addEventListener('mousedown', () => log.push('down a'));
+b.addEventListener('mouseup', () => log.push('up b'));
+window.addEventListener('wheel', e => log.push('wheel ' + e.deltaY));
+</script>"#
+    )
+    .set_content()
+  );
+  done!(p.drag_and_drop_builder("#a", "#b").drag_and_drop());
+  let a = done!(p.query_selector("#a")).unwrap();
+  let b = done!(p.query_selector("#b")).unwrap();
+  done!(a.drag_to_builder(&b).trial(true).drag_to());
+  done!(a.drag_to_builder(&b).drag_to());
+  done!(p.mouse.wheel(0.0, 100.0));
+  done!(
+    p.wait_for_function_builder("() => log.includes('wheel 100')")
+      .wait_for_function()
+  );
+  let log: Vec<String> = done!(p.eval("() => log"));
+  assert_eq!(log, vec!["down a", "up b", "down a", "up b", "wheel 100"]);
+  close(&p).await;
+}
+
+async fn page_error_should_work(c: &BrowserContext) {
+  use playwright_core::api::browser_context;
+  let p = new(c).await;
+  let (page_error, web_error, _) = tokio::join!(
+    p.expect_event(page::EventType::PageError),
+    c.expect_event(browser_context::EventType::WebError),
+    p.eval::<JsValue>("() => { setTimeout(() => { throw new TypeError('boom') }, 0) }")
+  );
+  let error = match page_error.unwrap() {
+    page::Event::PageError(e) => e,
+    _ => unreachable!(),
+  };
+  assert_eq!(error.name, "TypeError");
+  assert_eq!(error.message, "boom");
+  assert!(error.stack.as_deref().unwrap().contains("boom"));
+  match web_error.unwrap() {
+    browser_context::Event::WebError(e) => {
+      assert_eq!(e.error(), &error);
+      assert_eq!(e.page(), &p);
+    }
+    _ => unreachable!(),
+  }
   close(&p).await;
 }
 
@@ -625,3 +1053,50 @@ async fn query_selector_and_eval(c: &BrowserContext) {
 //    tokio::time::sleep(std::time::Duration::from_secs(5)).await;
 //    close(&p).await;
 //}
+
+async fn highlight_should_work(playwright: &Playwright, c: &BrowserContext) {
+  let p = new(c).await;
+  p.set_content_builder("<div>highlighted</div>")
+    .set_content()
+    .await
+    .unwrap();
+  p.highlight("div").await.unwrap();
+  let glass: bool = p
+    .eval("() => !!document.querySelector('x-pw-glass')")
+    .await
+    .unwrap();
+  assert!(glass);
+  playwright.hide_highlight().await.unwrap();
+  let glass: bool = p
+    .eval("() => !!document.querySelector('x-pw-glass')")
+    .await
+    .unwrap();
+  assert!(!glass);
+  close(&p).await;
+}
+
+async fn api_names_should_work(c: &BrowserContext) {
+  let p = new(c).await;
+  let err = p
+    .click_builder("#missing")
+    .timeout(100.)
+    .click()
+    .await
+    .unwrap_err();
+  assert_eq!(err.api_name(), Some("page.click"));
+  let err = p
+    .main_frame()
+    .click_builder("#missing")
+    .timeout(100.)
+    .click()
+    .await
+    .unwrap_err();
+  assert_eq!(err.api_name(), Some("frame.click"));
+  // The action reports its own call rather than the enclosing expect_*
+  let err = p
+    .expect_popup(p.fill_builder("#missing", "x").timeout(100.).fill())
+    .await
+    .unwrap_err();
+  assert_eq!(err.api_name(), Some("page.fill"));
+  close(&p).await;
+}
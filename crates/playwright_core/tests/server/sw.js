@@ -0,0 +1 @@
+self.addEventListener('install', () => self.skipWaiting());
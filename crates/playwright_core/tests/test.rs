@@ -2,6 +2,7 @@ mod browser;
 mod browser_context;
 mod browser_type;
 mod devices;
+mod electron;
 mod page;
 mod selectors;
 
@@ -12,9 +13,7 @@ use std::path::PathBuf;
 #[cfg(feature = "rt-async-std")]
 use async_std::task::spawn;
 use playwright_core::Playwright;
-#[cfg(feature = "rt-actix")]
-use tokio::task::spawn;
-#[cfg(feature = "rt-tokio")]
+#[cfg(any(feature = "rt-tokio", feature = "rt-actix"))]
 use tokio::task::spawn;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -41,6 +40,10 @@ playwright::runtime_test!(
   connect::connect_over_cdp(Which::Chromium).await
 );
 
+playwright::runtime_test!(connect, connect::connect(Which::Chromium).await);
+
+playwright::runtime_test!(electron, electron().await);
+
 async fn page(which: Which) {
   std::fs::create_dir_all(temp_dir()).unwrap();
   let port = free_local_port().unwrap();
@@ -51,7 +54,7 @@ async fn page(which: Which) {
   let browser = browser::all(&browser_type, which).await;
   let persistent = browser_context::persistent(&browser_type, port, which).await;
   let browser_context = browser_context::all(&browser, &persistent, port, which).await;
-  page::all(&browser_context, port, which).await;
+  page::all(&playwright, &browser_context, port, which).await;
 }
 
 async fn selectors(which: Which) {
@@ -68,6 +71,11 @@ async fn devices(which: Which) {
   devices::all(&playwright, port, which).await;
 }
 
+async fn electron() {
+  let playwright = playwright_with_driver().await;
+  electron::all(&playwright).await;
+}
+
 fn install_browser(p: &Playwright, which: Which) {
   match which {
     Which::Webkit => p.install_webkit(),
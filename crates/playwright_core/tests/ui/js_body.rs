@@ -0,0 +1,4 @@
+#[playwright_core::js("() => 1")]
+async fn one(page: &playwright_core::api::Page) -> i32 { 1 }
+
+fn main() {}
@@ -0,0 +1,4 @@
+#[playwright_core::js("(a) => a")]
+fn id(page: &playwright_core::api::Page, a: i32) -> i32;
+
+fn main() {}
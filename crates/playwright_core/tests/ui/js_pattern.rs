@@ -0,0 +1,4 @@
+#[playwright_core::js("(a, b) => a + b")]
+async fn sum(page: &playwright_core::api::Page, (a, b): (i32, i32)) -> i32;
+
+fn main() {}
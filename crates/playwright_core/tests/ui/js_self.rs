@@ -0,0 +1,4 @@
+#[playwright_core::js("() => 1")]
+async fn one(self) -> i32;
+
+fn main() {}
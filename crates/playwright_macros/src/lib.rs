@@ -0,0 +1,161 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::parse::Parse;
+use syn::parse::ParseStream;
+use syn::parse_macro_input;
+use syn::spanned::Spanned;
+use syn::Attribute;
+use syn::Block;
+use syn::FnArg;
+use syn::LitStr;
+use syn::Pat;
+use syn::Path;
+use syn::ReturnType;
+use syn::Signature;
+use syn::Token;
+use syn::Visibility;
+
+/// Turns an async function signature into a typed call of a JavaScript
+/// function in the page.
+///
+/// The first parameter is the `Page` or `Frame` to evaluate in. The others
+/// are serialized and spread into the JavaScript function, so
+/// `ElementHandle`s and `JsHandle`s can be passed as well. The declared return
+/// type is wrapped in `Result<_, Arc<playwright_core::Error>>`.
+///
+/// The function is declared without a body, or with an empty one.
+///
+/// ```ignore
+/// #[playwright_core::js("(a, b) => a + b")]
+/// async fn add(page: &Page, a: i32, b: i32) -> i32;
+///
+/// let three = add(&page, 1, 2).await?;
+/// ```
+///
+/// The expansion refers to `::playwright_core`. A crate that re-exports it
+/// under another path passes that path like `#[js("...", crate =
+/// "::my_crate::playwright_core")]`.
+#[proc_macro_attribute]
+pub fn js(attr: TokenStream, item: TokenStream) -> TokenStream {
+  let attr = parse_macro_input!(attr as JsAttr);
+  let item = parse_macro_input!(item as JsFn);
+  expand(attr, item)
+    .unwrap_or_else(syn::Error::into_compile_error)
+    .into()
+}
+
+/// The JavaScript expression, optionally followed by `crate = "path"`
+struct JsAttr {
+  expression: LitStr,
+  krate: Path,
+}
+
+impl Parse for JsAttr {
+  fn parse(input: ParseStream) -> syn::Result<Self> {
+    let expression = input.parse()?;
+    let mut krate: Path = syn::parse_quote!(::playwright_core);
+    if input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+      input.parse::<Token![crate]>()?;
+      input.parse::<Token![=]>()?;
+      krate = input.parse::<LitStr>()?.parse()?;
+      input.parse::<Option<Token![,]>>()?;
+    }
+    Ok(Self { expression, krate })
+  }
+}
+
+/// A function signature followed by `;` or an empty block
+struct JsFn {
+  attrs: Vec<Attribute>,
+  vis: Visibility,
+  sig: Signature,
+}
+
+impl Parse for JsFn {
+  fn parse(input: ParseStream) -> syn::Result<Self> {
+    let attrs = input.call(Attribute::parse_outer)?;
+    let vis = input.parse()?;
+    let sig = input.parse()?;
+    if input.peek(Token![;]) {
+      input.parse::<Token![;]>()?;
+    } else {
+      let body: Block = input.parse()?;
+      if !body.stmts.is_empty() {
+        return Err(syn::Error::new(
+          body.span(),
+          "#[js] functions can't have a body",
+        ));
+      }
+    }
+    Ok(Self { attrs, vis, sig })
+  }
+}
+
+fn expand(attr: JsAttr, item: JsFn) -> syn::Result<TokenStream2> {
+  let JsAttr { expression, krate } = attr;
+  let JsFn { attrs, vis, sig } = item;
+  if sig.asyncness.is_none() {
+    return Err(syn::Error::new(
+      sig.fn_token.span(),
+      "#[js] functions must be async",
+    ));
+  }
+  let mut inputs = sig.inputs.iter();
+  let target = match inputs.next() {
+    Some(FnArg::Typed(x)) => ident_of(&x.pat)?,
+    Some(FnArg::Receiver(x)) => {
+      return Err(syn::Error::new(x.span(), "#[js] functions can't take self"));
+    }
+    None => {
+      return Err(syn::Error::new(
+        sig.inputs.span(),
+        "#[js] functions take the Page or Frame to evaluate in first",
+      ));
+    }
+  };
+  let args = inputs
+    .map(|arg| {
+      match arg {
+        FnArg::Typed(x) => ident_of(&x.pat),
+        FnArg::Receiver(x) => Err(syn::Error::new(x.span(), "#[js] functions can't take self")),
+      }
+    })
+    .collect::<syn::Result<Vec<_>>>()?;
+  let output = match &sig.output {
+    ReturnType::Default => quote!(()),
+    ReturnType::Type(_, t) => quote!(#t),
+  };
+  let wrapped = format!("(args) => ({})(...args)", expression.value());
+  let wrapped = LitStr::new(&wrapped, expression.span());
+  let ident = &sig.ident;
+  let generics = &sig.generics;
+  let where_clause = &sig.generics.where_clause;
+  let inputs = &sig.inputs;
+  let args = if args.is_empty() {
+    quote!(::std::vec::Vec::<()>::new())
+  } else {
+    quote!((#(&#args,)*))
+  };
+  Ok(quote! {
+    #(#attrs)*
+    #vis async fn #ident #generics(#inputs)
+      -> ::std::result::Result<#output, ::std::sync::Arc<#krate::__private::Error>>
+    #where_clause
+    {
+      #krate::__private::evaluate(&#target, #wrapped, #args).await
+    }
+  })
+}
+
+fn ident_of(pat: &Pat) -> syn::Result<&syn::Ident> {
+  match pat {
+    Pat::Ident(x) if x.subpat.is_none() => Ok(&x.ident),
+    _ => {
+      Err(syn::Error::new(
+        pat.span(),
+        "#[js] parameters must be plain identifiers",
+      ))
+    }
+  }
+}